@@ -0,0 +1,84 @@
+//! The `#[non_reentrant]` attribute, re-exported by `reentrancy-guard-stylus`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Error, ImplItemFn, ReturnType};
+
+/// Guards a contract method with the contract's `ReentrancyGuard`.
+///
+/// The contract must hold the guard in a `#[borrow]` field, and the method must return a
+/// `Result` whose error type converts from `ReentrancyGuardErrors`:
+///
+/// ```ignore
+/// #[public]
+/// #[inherit(ReentrancyGuard)]
+/// impl Vault {
+///     #[non_reentrant]
+///     pub fn withdraw(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// On `&mut self` methods the guard is entered before the body runs and left after it, on every
+/// return path: the end of the body, `return`, and `?` or any other `Err`. On `&self` methods the
+/// call only fails if the guard is already entered, like OpenZeppelin's `nonReentrantView`.
+#[proc_macro_attribute]
+pub fn non_reentrant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return Error::new(attr.span(), "#[non_reentrant] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let method = parse_macro_input!(item as ImplItemFn);
+    match expand(method) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(mut method: ImplItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(receiver) = method.sig.receiver() else {
+        return Err(Error::new(
+            method.sig.span(),
+            "#[non_reentrant] methods must take `&self` or `&mut self`",
+        ));
+    };
+    if receiver.reference.is_none() {
+        return Err(Error::new(
+            receiver.span(),
+            "#[non_reentrant] methods must take `&self` or `&mut self`",
+        ));
+    }
+    let mutable = receiver.mutability.is_some();
+
+    let ReturnType::Type(_, output) = &method.sig.output else {
+        return Err(Error::new(
+            method.sig.span(),
+            "#[non_reentrant] methods must return a `Result`",
+        ));
+    };
+
+    let guard = quote!(::reentrancy_guard_stylus::ReentrancyGuard);
+    let body = &method.block;
+    let block = if mutable {
+        // The body runs in a closure so that `return` and `?` come back here to leave the guard
+        quote!({
+            ::core::borrow::BorrowMut::<#guard>::borrow_mut(self)._non_reentrant_before()?;
+            #[allow(clippy::redundant_closure_call)]
+            let result = (|| -> #output #body)();
+            ::core::borrow::BorrowMut::<#guard>::borrow_mut(self)._non_reentrant_after();
+            result
+        })
+    } else {
+        quote!({
+            ::core::borrow::Borrow::<#guard>::borrow(self)._non_reentrant_view()?;
+            #body
+        })
+    };
+
+    method.block = syn::parse2(block)?;
+    Ok(quote!(#method))
+}
@@ -0,0 +1,88 @@
+//! Transient storage (EIP-1153) used by the guard with the `transient` feature
+//!
+//! Transient storage is cleared at the end of every transaction, so the guard never pays for
+//! persistent storage writes, like OpenZeppelin's `ReentrancyGuardTransient`. The Stylus SDK has
+//! no wrapper for it yet, so on chain this calls the `transient_load_bytes32` and
+//! `transient_store_bytes32` hostios directly.
+//!
+//! Off chain the values live in a thread local map keyed by contract address, since `TestVM` has
+//! no transient storage. Tests end a transaction with [`clear_transient_storage`].
+
+use stylus_sdk::{
+    alloy_primitives::{b256, B256},
+    stylus_core::Host,
+};
+
+/// Slot of the guard's status, the same one `ReentrancyGuardTransient` uses:
+/// `keccak256(abi.encode(uint256(keccak256("openzeppelin.storage.ReentrancyGuard")) - 1)) & ~bytes32(uint256(0xff))`
+pub const REENTRANCY_GUARD_STORAGE: B256 =
+    b256!("9b779b17422d0df92223018b32b4d1fa46e071723d6817e2486d003becc55f00");
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "vm_hooks")]
+extern "C" {
+    /// Reads a 32-byte value from transient storage, like the EVM's `TLOAD` opcode.
+    fn transient_load_bytes32(key: *const u8, dest: *mut u8);
+
+    /// Writes a 32-byte value to transient storage, like the EVM's `TSTORE` opcode.
+    fn transient_store_bytes32(key: *const u8, value: *const u8);
+}
+
+/// Reads `slot` from the current contract's transient storage.
+#[cfg(target_arch = "wasm32")]
+pub fn load(_host: &dyn Host, slot: B256) -> B256 {
+    let mut value = B256::ZERO;
+    unsafe { transient_load_bytes32(slot.as_ptr(), value.as_mut_ptr()) };
+    value
+}
+
+/// Writes `value` to `slot` in the current contract's transient storage.
+#[cfg(target_arch = "wasm32")]
+pub fn store(_host: &dyn Host, slot: B256, value: B256) {
+    unsafe { transient_store_bytes32(slot.as_ptr(), value.as_ptr()) };
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+#[cfg(not(target_arch = "wasm32"))]
+std::thread_local! {
+    static TRANSIENT: core::cell::RefCell<
+        std::collections::HashMap<(stylus_sdk::alloy_primitives::Address, B256), B256>,
+    > = Default::default();
+}
+
+/// Reads `slot` from the current contract's transient storage.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(host: &dyn Host, slot: B256) -> B256 {
+    let key = (host.contract_address(), slot);
+    TRANSIENT.with(|values| values.borrow().get(&key).copied().unwrap_or_default())
+}
+
+/// Writes `value` to `slot` in the current contract's transient storage.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn store(host: &dyn Host, slot: B256, value: B256) {
+    let key = (host.contract_address(), slot);
+    TRANSIENT.with(|values| values.borrow_mut().insert(key, value));
+}
+
+/// Clears the transient storage of every contract on this thread, as the end of a transaction
+/// does on chain.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn clear_transient_storage() {
+    TRANSIENT.with(|values| values.borrow_mut().clear());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use stylus_sdk::alloy_primitives::{keccak256, U256};
+
+    #[test]
+    fn test_slot_matches_erc7201_formula() {
+        let namespace = U256::from_be_bytes(keccak256("openzeppelin.storage.ReentrancyGuard").0);
+        let slot = keccak256((namespace - U256::from(1)).to_be_bytes::<32>());
+        let slot = U256::from_be_bytes(slot.0) & !U256::from(0xff);
+        assert_eq!(REENTRANCY_GUARD_STORAGE, B256::from(slot));
+    }
+}
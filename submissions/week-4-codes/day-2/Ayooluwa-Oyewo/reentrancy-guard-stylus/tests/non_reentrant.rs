@@ -0,0 +1,131 @@
+//! Tests for the `#[non_reentrant]` attribute on a contract that embeds the guard.
+
+extern crate alloc;
+
+use reentrancy_guard_stylus::{
+    non_reentrant, ReentrancyGuard, ReentrancyGuardErrors, ReentrancyGuardReentrantCall,
+    NOT_ENTERED,
+};
+use stylus_sdk::{alloy_primitives::U256, prelude::*, storage::StorageU256, testing::*};
+
+#[storage]
+#[entrypoint]
+pub struct Counter {
+    #[borrow]
+    guard: ReentrancyGuard,
+    count: StorageU256,
+}
+
+#[public]
+#[inherit(ReentrancyGuard)]
+impl Counter {
+    #[non_reentrant]
+    pub fn increment(&mut self) -> Result<U256, Vec<u8>> {
+        assert!(self.guard.is_guard_active());
+        let count = self.count.get() + U256::from(1);
+        self.count.set(count);
+        Ok(count)
+    }
+
+    #[non_reentrant]
+    pub fn increment_or_fail(&mut self, fail: bool) -> Result<U256, Vec<u8>> {
+        self.count.set(self.count.get() + U256::from(1));
+        if fail {
+            return Err(b"failed".to_vec());
+        }
+        Ok(self.count.get())
+    }
+
+    #[non_reentrant]
+    pub fn increment_with_early_return(&mut self, early: bool) -> Result<U256, Vec<u8>> {
+        if early {
+            return Ok(U256::ZERO);
+        }
+        self.increment_unguarded();
+        Ok(self.count.get())
+    }
+
+    /// Calls another guarded method, as a reentrant call would
+    #[non_reentrant]
+    pub fn reenter(&mut self) -> Result<U256, Vec<u8>> {
+        self.increment()
+    }
+
+    /// Reads through a guarded view while the guard is entered
+    #[non_reentrant]
+    pub fn read_while_entered(&mut self) -> Result<U256, Vec<u8>> {
+        self.count()
+    }
+
+    #[non_reentrant]
+    pub fn count(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.count.get())
+    }
+}
+
+impl Counter {
+    fn increment_unguarded(&mut self) {
+        self.count.set(self.count.get() + U256::from(1));
+    }
+}
+
+fn reentrant_call() -> Vec<u8> {
+    ReentrancyGuardErrors::ReentrancyGuardReentrantCall(ReentrancyGuardReentrantCall {}).into()
+}
+
+#[test]
+fn test_guard_is_left_after_ok() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.increment(), Ok(U256::from(1)));
+    assert_eq!(contract.increment(), Ok(U256::from(2)));
+    assert!(!contract.guard.is_guard_active());
+    assert_eq!(contract.guard.get_status(), U256::from(NOT_ENTERED));
+}
+
+#[test]
+fn test_guard_is_left_after_err() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.increment_or_fail(true), Err(b"failed".to_vec()));
+    assert!(!contract.guard.is_guard_active());
+    assert_eq!(contract.increment_or_fail(false), Ok(U256::from(2)));
+}
+
+#[test]
+fn test_guard_is_left_after_early_return() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.increment_with_early_return(true), Ok(U256::ZERO));
+    assert!(!contract.guard.is_guard_active());
+    assert_eq!(
+        contract.increment_with_early_return(false),
+        Ok(U256::from(1))
+    );
+    assert!(!contract.guard.is_guard_active());
+}
+
+#[test]
+fn test_nested_call_is_rejected() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.reenter(), Err(reentrant_call()));
+    assert_eq!(contract.count(), Ok(U256::ZERO));
+    // The outer call still leaves the guard
+    assert!(!contract.guard.is_guard_active());
+    assert_eq!(contract.increment(), Ok(U256::from(1)));
+}
+
+#[test]
+fn test_view_is_rejected_while_entered() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.read_while_entered(), Err(reentrant_call()));
+    assert_eq!(contract.count(), Ok(U256::ZERO));
+    assert!(!contract.guard.is_guard_active());
+}
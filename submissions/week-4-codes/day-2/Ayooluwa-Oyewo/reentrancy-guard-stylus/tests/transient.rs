@@ -0,0 +1,89 @@
+//! Tests for the guard with the `transient` feature: `cargo test --features transient`.
+
+#![cfg(feature = "transient")]
+
+extern crate alloc;
+
+use reentrancy_guard_stylus::{
+    non_reentrant, transient::clear_transient_storage, ReentrancyGuard, ENTERED,
+};
+use stylus_sdk::{
+    alloy_primitives::{address, B256, U256},
+    prelude::*,
+    storage::StorageU256,
+    testing::*,
+};
+
+#[storage]
+#[entrypoint]
+pub struct Counter {
+    #[borrow]
+    guard: ReentrancyGuard,
+    count: StorageU256,
+}
+
+#[public]
+#[inherit(ReentrancyGuard)]
+impl Counter {
+    #[non_reentrant]
+    pub fn increment(&mut self) -> Result<U256, Vec<u8>> {
+        let count = self.count.get() + U256::from(1);
+        self.count.set(count);
+        Ok(count)
+    }
+}
+
+#[test]
+fn test_guard_writes_no_storage() {
+    let vm = TestVM::default();
+    let mut contract = Counter::from(&vm);
+
+    assert_eq!(contract.increment(), Ok(U256::from(1)));
+    contract.guard._non_reentrant_before().unwrap();
+    assert!(contract.guard.is_guard_active());
+
+    // Only the counter is in persistent storage, the guard's slot is never written
+    let storage = vm.snapshot().storage;
+    assert_eq!(storage.len(), 1);
+    assert_eq!(vm.get_storage(U256::ZERO), B256::ZERO);
+    assert_eq!(vm.get_storage(U256::from(1)), B256::from(U256::from(1)));
+}
+
+#[test]
+fn test_guard_resets_between_transactions() {
+    let vm = TestVM::default();
+    let mut guard = ReentrancyGuard::from(&vm);
+
+    // A transaction that enters the guard and never leaves it
+    guard._non_reentrant_before().unwrap();
+    assert_eq!(guard.get_status(), U256::from(ENTERED));
+    assert!(guard._non_reentrant_before().is_err());
+
+    // Stays entered for the rest of the transaction
+    let same_transaction = ReentrancyGuard::from(&vm);
+    assert!(same_transaction.is_guard_active());
+
+    clear_transient_storage();
+
+    // The next transaction starts with the guard left
+    assert!(!guard.is_guard_active());
+    assert_eq!(guard.get_status(), U256::ZERO);
+    assert!(guard._non_reentrant_before().is_ok());
+}
+
+#[test]
+fn test_guard_is_per_contract() {
+    let vm = TestVM::default();
+    let mut first = ReentrancyGuard::from(&vm);
+    first._non_reentrant_before().unwrap();
+
+    let other_vm = TestVM::default();
+    other_vm.set_contract_address(address!("0x00000000000000000000000000000000000000aa"));
+    let mut second = ReentrancyGuard::from(&other_vm);
+    assert!(!second.is_guard_active());
+    assert!(second._non_reentrant_before().is_ok());
+
+    first._non_reentrant_after();
+    assert!(!first.is_guard_active());
+    assert!(second.is_guard_active());
+}
@@ -0,0 +1,127 @@
+//! ReentrancyVault - Ether vault with a vulnerable and a guarded withdrawal
+//!
+//! Accounts deposit ether and withdraw all of it at once. Both `withdraw` and `withdraw_safe`
+//! send the ether before clearing the balance, the classic reentrancy bug: a receiver that calls
+//! back into `withdraw` from its receive hook is paid its balance again and again, until the
+//! vault is empty. `withdraw_safe` runs the same code under `#[non_reentrant]`, so the call back
+//! reverts with `ReentrancyGuardReentrantCall()` and takes the whole withdrawal with it.
+//!
+//! Stylus contracts can't be re-entered at all unless built with the stylus-sdk `reentrant`
+//! feature, so on chain the guard matters for contracts that enable it. The vault's own
+//! `reentrant` feature turns it on.
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use reentrancy_guard_stylus::{non_reentrant, ReentrancyGuard};
+use stylus_sdk::{
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+#[storage]
+#[entrypoint]
+pub struct Vault {
+    #[borrow]
+    reentrancy_guard: ReentrancyGuard,
+    balances: StorageMap<Address, StorageU256>,
+}
+
+sol! {
+    #[derive(Debug)]
+    error VaultInsufficientBalance(address account);
+
+    event Deposit(address indexed account, uint256 value);
+    event Withdrawal(address indexed account, uint256 value);
+}
+
+#[derive(SolidityError, Debug)]
+pub enum VaultError {
+    InsufficientBalance(VaultInsufficientBalance),
+}
+
+#[public]
+#[inherit(ReentrancyGuard)]
+impl Vault {
+    /// Adds the ether sent to the caller's balance
+    #[payable]
+    pub fn deposit(&mut self) {
+        let account = self.vm().msg_sender();
+        let value = self.vm().msg_value();
+        let balance = self.balances.get(account);
+        self.balances.setter(account).set(balance + value);
+        log(self.vm(), Deposit { account, value });
+    }
+
+    pub fn balance_of(&self, account: Address) -> U256 {
+        self.balances.get(account)
+    }
+
+    /// Sends the caller's whole balance. Vulnerable to reentrancy
+    pub fn withdraw(&mut self) -> Result<(), Vec<u8>> {
+        self._withdraw()
+    }
+
+    /// Same as `withdraw`, but reverts if re-entered
+    #[non_reentrant]
+    pub fn withdraw_safe(&mut self) -> Result<(), Vec<u8>> {
+        self._withdraw()
+    }
+}
+
+impl Vault {
+    // Pays out before clearing the balance on purpose, so only the guard stops reentrancy.
+    // Errors from the receiver are passed back as they are.
+    fn _withdraw(&mut self) -> Result<(), Vec<u8>> {
+        let account = self.vm().msg_sender();
+        let value = self.balances.get(account);
+        if value.is_zero() {
+            return Err(
+                VaultError::InsufficientBalance(VaultInsufficientBalance { account }).into(),
+            );
+        }
+
+        self._transfer_eth(account, value)?;
+        self.balances.setter(account).set(U256::ZERO);
+        log(self.vm(), Withdrawal { account, value });
+        Ok(())
+    }
+
+    #[cfg(not(feature = "reentrant"))]
+    fn _transfer_eth(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.vm().transfer_eth(to, value)
+    }
+
+    // With `reentrant` the host's `transfer_eth` also takes `&mut self`, as proof that no storage
+    // reference is held across the call, and `self.vm()` can't be borrowed alongside it. The
+    // transfer is made the way the host makes it instead: clear the storage cache, then make a
+    // raw call carrying the value.
+    #[cfg(feature = "reentrant")]
+    fn _transfer_eth(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        let value = alloy_primitives::B256::from(value);
+        let mut return_data_len = 0;
+        self.vm().flush_cache(true);
+        // SAFETY: the cache was just cleared and no storage reference is held across the call
+        let status = unsafe {
+            self.vm().call_contract(
+                to.as_ptr(),
+                [].as_ptr(),
+                0,
+                value.as_ptr(),
+                u64::MAX,
+                &mut return_data_len,
+            )
+        };
+        match status {
+            0 => Ok(()),
+            _ => Err(self.vm().read_return_data(0, None)),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! A reentrancy attack on the vault, against `withdraw` and `withdraw_safe`.
+//!
+//! The world lets the vault and attacker be re-entered, as they would be if built with the
+//! `reentrant` feature, so the attack runs with or without it.
+
+extern crate alloc;
+
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::{sol, SolCall, SolError};
+use reentrancy_guard_stylus::ReentrancyGuardReentrantCall;
+use reentrancy_vault_stylus::{Vault, VaultInsufficientBalance};
+use stylus_sdk::{
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageU256},
+};
+use stylus_testkit::{Deployed, World};
+
+const VAULT: Address = address!("0x7a07000000000000000000000000000000000000");
+const ATTACKER: Address = address!("0xbad0000000000000000000000000000000000000");
+const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+const BOB: Address = address!("0x3333333333333333333333333333333333333333");
+const MALLORY: Address = address!("0x6666666666666666666666666666666666666666");
+
+fn ether(amount: u64) -> U256 {
+    U256::from(amount) * U256::from(10).pow(U256::from(18))
+}
+
+sol! {
+    interface IVault {
+        function deposit() external payable;
+        function balanceOf(address account) external view returns (uint256);
+        function withdraw() external;
+        function withdrawSafe() external;
+        function isGuardActive() external view returns (bool);
+    }
+
+    interface IAttacker {
+        function attack(address vault, bool safe) external;
+    }
+}
+
+/// Withdraws its deposit, and withdraws again from its receive hook while the vault can pay
+#[storage]
+#[entrypoint]
+pub struct Attacker {
+    vault: StorageAddress,
+    safe: StorageBool,
+    stake: StorageU256,
+    reentries: StorageU256,
+}
+
+#[public]
+impl Attacker {
+    pub fn attack(&mut self, vault: Address, safe: bool) -> Result<(), Vec<u8>> {
+        self.vault.set(vault);
+        self.safe.set(safe);
+
+        let account = self.vm().contract_address();
+        let balance_of = IVault::balanceOfCall { account }.abi_encode();
+        let stake = self.vm().static_call(&self, vault, &balance_of)?;
+        self.stake.set(U256::from_be_slice(&stake));
+        self.withdraw()
+    }
+
+    pub fn reentries(&self) -> U256 {
+        self.reentries.get()
+    }
+
+    #[receive]
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        if self.vm().balance(self.vault.get()) < self.stake.get() {
+            return Ok(());
+        }
+        self.reentries.set(self.reentries.get() + U256::from(1));
+        self.withdraw()
+    }
+}
+
+impl Attacker {
+    fn withdraw(&mut self) -> Result<(), Vec<u8>> {
+        let withdraw = if self.safe.get() {
+            IVault::withdrawSafeCall {}.abi_encode()
+        } else {
+            IVault::withdrawCall {}.abi_encode()
+        };
+        self.vm().call(&self, self.vault.get(), &withdraw)?;
+        Ok(())
+    }
+}
+
+// Vault holding 1 ether each from ALICE, BOB and the attacker, which is ready to re-enter it
+fn setup() -> (World, Deployed<Vault>, Deployed<Attacker>) {
+    let world = World::new();
+    let vault = world.deploy::<Vault>(VAULT);
+    let attacker = world.deploy::<Attacker>(ATTACKER);
+    vault.allow_reentrancy();
+    attacker.allow_reentrancy();
+
+    for user in [ALICE, BOB, ATTACKER] {
+        world.set_balance(user, ether(1));
+        world.set_sender(user);
+        world.set_value(ether(1));
+        world.call_sol(VAULT, &IVault::depositCall {}).unwrap();
+    }
+    world.set_value(U256::ZERO);
+    (world, vault, attacker)
+}
+
+fn attack(world: &World, safe: bool) -> Result<(), Vec<u8>> {
+    world.set_sender(MALLORY);
+    let attack = IAttacker::attackCall { vault: VAULT, safe };
+    world.call_sol(ATTACKER, &attack).map(|_| ())
+}
+
+#[test]
+fn test_withdraw() {
+    let (world, vault, _) = setup();
+    assert_eq!(world.balance(VAULT), ether(3));
+    assert_eq!(vault.balance_of(ALICE), ether(1));
+
+    world.set_sender(ALICE);
+    world.call_sol(VAULT, &IVault::withdrawCall {}).unwrap();
+    assert_eq!(world.balance(ALICE), ether(1));
+    assert_eq!(vault.balance_of(ALICE), U256::ZERO);
+
+    // Nothing left to withdraw
+    let withdraw = IVault::withdrawCall {}.abi_encode();
+    let err = world.call(VAULT, &withdraw).unwrap_err();
+    assert_eq!(
+        err,
+        VaultInsufficientBalance { account: ALICE }.abi_encode()
+    );
+
+    world.set_sender(BOB);
+    world.call_sol(VAULT, &IVault::withdrawSafeCall {}).unwrap();
+    assert_eq!(world.balance(BOB), ether(1));
+    assert_eq!(world.balance(VAULT), ether(1));
+}
+
+#[test]
+fn test_attack_drains_unguarded_withdraw() {
+    let (world, vault, attacker) = setup();
+    attack(&world, false).unwrap();
+
+    // The attacker's 1 ether came back three times: its own deposit, then ALICE's and BOB's
+    assert_eq!(attacker.reentries(), U256::from(2));
+    assert_eq!(world.balance(ATTACKER), ether(3));
+    assert_eq!(world.balance(VAULT), U256::ZERO);
+
+    // The vault still owes ALICE and BOB, but can't pay them
+    assert_eq!(vault.balance_of(ALICE), ether(1));
+    assert_eq!(vault.balance_of(BOB), ether(1));
+    world.set_sender(ALICE);
+    assert!(world.call_sol(VAULT, &IVault::withdrawCall {}).is_err());
+}
+
+#[test]
+fn test_attack_reverts_on_guarded_withdraw() {
+    let (world, vault, attacker) = setup();
+    let err = attack(&world, true).unwrap_err();
+    assert_eq!(err, ReentrancyGuardReentrantCall {}.abi_encode());
+
+    // The whole attack was rolled back
+    assert_eq!(attacker.reentries(), U256::ZERO);
+    assert_eq!(world.balance(ATTACKER), U256::ZERO);
+    assert_eq!(vault.balance_of(ATTACKER), ether(1));
+    assert_eq!(world.balance(VAULT), ether(3));
+
+    // The guard is not left entered, so honest withdrawals still work
+    let active = world
+        .call_sol(VAULT, &IVault::isGuardActiveCall {})
+        .unwrap();
+    assert!(!active._0);
+    world.set_sender(ALICE);
+    world.call_sol(VAULT, &IVault::withdrawSafeCall {}).unwrap();
+    assert_eq!(world.balance(ALICE), ether(1));
+}
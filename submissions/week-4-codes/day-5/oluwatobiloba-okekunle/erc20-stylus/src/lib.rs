@@ -6,9 +6,13 @@
 extern crate alloc;
 
 use alloc::{string::String, vec::Vec};
-use alloy_primitives::{Address, U256};
-use alloy_sol_types::sol;
-use stylus_sdk::prelude::*;
+use alloy_primitives::{
+    address,
+    aliases::{U16, U208, U48},
+    Address, B256, U256,
+};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{abi::Bytes, keccak_const::Keccak256, prelude::*, storage::StorageVec};
 
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
@@ -22,9 +26,58 @@ sol_storage! {
        uint8 decimals;
        mapping(address => uint256) balances;
        mapping(address => mapping(address => uint256)) allowances;
+       mapping(address => uint256) nonces;
+       mapping(address => address) delegates;
+       mapping(address => Checkpoint[]) checkpoints;
+       Checkpoint[] total_supply_checkpoints;
+       uint16 flash_fee_bps;
+    }
+
+    // Value from block `key` until the next checkpoint
+    pub struct Checkpoint {
+       uint48 key;
+       uint208 value;
     }
 }
 
+// Precompile that recovers the signer of a secp256k1 signature
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+
+// Version used in the EIP-712 domain
+const VERSION: &str = "1";
+
+const DOMAIN_TYPEHASH: [u8; 32] = Keccak256::new()
+    .update(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+    .finalize();
+
+const PERMIT_TYPEHASH: [u8; 32] = Keccak256::new()
+    .update(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+    .finalize();
+
+const DELEGATION_TYPEHASH: [u8; 32] = Keccak256::new()
+    .update(b"Delegation(address delegatee,uint256 nonce,uint256 expiry)")
+    .finalize();
+
+// Checkpoints are keyed by block number (EIP-6372)
+const CLOCK_MODE: &str = "mode=blocknumber&from=default";
+
+// Largest supply a checkpoint can hold (2^208 - 1)
+const MAX_SUPPLY: U256 = U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0xFFFF]);
+
+// Value a borrower's `onFlashLoan` must return
+const FLASH_LOAN_CALLBACK: [u8; 32] = Keccak256::new()
+    .update(b"ERC3156FlashBorrower.onFlashLoan")
+    .finalize();
+
+// Flash mint fees are capped at the loan itself (100%)
+const MAX_FLASH_FEE_BPS: u16 = 10_000;
+
+// Upper bound for `s` (secp256k1n / 2), signatures above it are malleable
+const MAX_S: U256 = U256::from_be_bytes([
+    0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D, 0xDF, 0xE9, 0x2F, 0x46, 0x68, 0x1B, 0x20, 0xA0,
+]);
+
 sol! {
     #[derive(Debug)]
     error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
@@ -38,8 +91,55 @@ sol! {
     #[derive(Debug)]
     error ERC20InsufficientAllowance(address owner, uint256 allowance, uint256 needed);
 
+    #[derive(Debug)]
+    error ERC2612ExpiredSignature(uint256 deadline);
+
+    #[derive(Debug)]
+    error ERC2612InvalidSigner(address signer, address owner);
+
+    #[derive(Debug)]
+    error ECDSAInvalidSignature();
+
+    #[derive(Debug)]
+    error ECDSAInvalidSignatureS(bytes32 s);
+
+    #[derive(Debug)]
+    error ERC20ExceededSafeSupply(uint256 increased_supply, uint256 cap);
+
+    #[derive(Debug)]
+    error ERC5805FutureLookup(uint256 timepoint, uint48 clock);
+
+    #[derive(Debug)]
+    error VotesExpiredSignature(uint256 expiry);
+
+    #[derive(Debug)]
+    error InvalidAccountNonce(address account, uint256 current_nonce);
+
+    #[derive(Debug)]
+    error ERC3156UnsupportedToken(address token);
+
+    #[derive(Debug)]
+    error ERC3156ExceededMaxLoan(uint256 max_loan);
+
+    #[derive(Debug)]
+    error ERC3156InvalidReceiver(address receiver);
+
+    #[derive(Debug)]
+    error ERC3156InvalidFee(uint16 fee_bps);
+
+    #[derive(Debug)]
+    error OwnableInvalidOwner(address owner);
+
     event Transfer(address indexed from, address indexed to, uint256 value);
     event Approval(address indexed owner, address indexed spender, uint256 value);
+    event DelegateChanged(address indexed delegator, address indexed from_delegate, address indexed to_delegate);
+    event DelegateVotesChanged(address indexed delegate, uint256 previous_votes, uint256 new_votes);
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event FlashFeeUpdated(uint16 previous_fee_bps, uint16 new_fee_bps);
+
+    interface IERC3156FlashBorrower {
+        function onFlashLoan(address initiator, address token, uint256 amount, uint256 fee, bytes calldata data) external returns (bytes32);
+    }
 }
 
 // Define the Rust-equivalent of the Solidity errors
@@ -49,10 +149,85 @@ pub enum ERC20Error {
     ERC20InvalidSender(ERC20InvalidSender),
     ERC20InvalidReciver(ERC20InvalidReciver),
     ERC20InsufficientAllowance(ERC20InsufficientAllowance),
+    ERC2612ExpiredSignature(ERC2612ExpiredSignature),
+    ERC2612InvalidSigner(ERC2612InvalidSigner),
+    ECDSAInvalidSignature(ECDSAInvalidSignature),
+    ECDSAInvalidSignatureS(ECDSAInvalidSignatureS),
+    ERC20ExceededSafeSupply(ERC20ExceededSafeSupply),
+    ERC5805FutureLookup(ERC5805FutureLookup),
+    VotesExpiredSignature(VotesExpiredSignature),
+    InvalidAccountNonce(InvalidAccountNonce),
+    ERC3156UnsupportedToken(ERC3156UnsupportedToken),
+    ERC3156ExceededMaxLoan(ERC3156ExceededMaxLoan),
+    ERC3156InvalidReceiver(ERC3156InvalidReceiver),
+    ERC3156InvalidFee(ERC3156InvalidFee),
+    OwnableInvalidOwner(OwnableInvalidOwner),
+}
+
+// Latest value in a list of checkpoints, zero if there are none
+fn latest_checkpoint(checkpoints: &StorageVec<Checkpoint>) -> U256 {
+    match checkpoints.len() {
+        0 => U256::ZERO,
+        len => U256::from(checkpoints.get(len - 1).unwrap().value.get()),
+    }
+}
+
+// Value of the last checkpoint at or before `key`
+fn upper_lookup(checkpoints: &StorageVec<Checkpoint>, key: U48) -> U256 {
+    let (mut low, mut high) = (0, checkpoints.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if checkpoints.get(mid).unwrap().key.get() > key {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    match high {
+        0 => U256::ZERO,
+        _ => U256::from(checkpoints.get(high - 1).unwrap().value.get()),
+    }
+}
+
+// Record `value` at `key`, overwriting the last checkpoint if it has the same key
+fn push_checkpoint(checkpoints: &mut StorageVec<Checkpoint>, key: U48, value: U256) {
+    let len = checkpoints.len();
+    if len > 0 {
+        let mut last = checkpoints.setter(len - 1).unwrap();
+        if last.key.get() == key {
+            last.value.set(U208::from(value));
+            return;
+        }
+    }
+
+    let mut checkpoint = checkpoints.grow();
+    checkpoint.key.set(key);
+    checkpoint.value.set(U208::from(value));
 }
 
 impl ERC20 {
     fn _update(&mut self, from: Address, to: Address, value: U256) -> Result<(), ERC20Error> {
+        self._update_balances(from, to, value)?;
+
+        // Mints and burns move the total supply, everything moves voting units
+        if from.is_zero() || to.is_zero() {
+            let supply = self.total_supply.get();
+            if supply > MAX_SUPPLY {
+                return Err(ERC20Error::ERC20ExceededSafeSupply(ERC20ExceededSafeSupply {
+                    increased_supply: supply,
+                    cap: MAX_SUPPLY,
+                }));
+            }
+            let clock = self._clock();
+            push_checkpoint(&mut self.total_supply_checkpoints, clock, supply);
+        }
+
+        self._move_delegate_votes(self.delegates.get(from), self.delegates.get(to), value);
+        Ok(())
+    }
+
+    fn _update_balances(&mut self, from: Address, to: Address, value: U256) -> Result<(), ERC20Error> {
         if from.is_zero() {
             if to.is_zero() {
                 return Err(ERC20Error::ERC20InvalidReciver(ERC20InvalidReciver {
@@ -156,7 +331,7 @@ impl ERC20 {
       if allowance < value {
         return Err(ERC20Error::ERC20InsufficientAllowance(ERC20InsufficientAllowance {
           owner,
-          allowance: allowance,
+          allowance,
           needed: value,
         }));
       }
@@ -184,6 +359,103 @@ impl ERC20 {
 
       self._update(from, Address::ZERO, value)
     }
+
+    fn _domain_separator(&self) -> B256 {
+        let name_hash = self.vm().native_keccak256(self.name.get_string().as_bytes());
+        let version_hash = self.vm().native_keccak256(VERSION.as_bytes());
+
+        let mut encoded = Vec::with_capacity(160);
+        encoded.extend_from_slice(&DOMAIN_TYPEHASH);
+        encoded.extend_from_slice(name_hash.as_slice());
+        encoded.extend_from_slice(version_hash.as_slice());
+        encoded.extend_from_slice(&U256::from(self.vm().chain_id()).to_be_bytes::<32>());
+        encoded.extend_from_slice(self.vm().contract_address().into_word().as_slice());
+        self.vm().native_keccak256(&encoded)
+    }
+
+    // EIP-712 digest of a struct hash under this token's domain
+    fn _hash_typed_data(&self, struct_hash: B256) -> B256 {
+        let mut message = Vec::with_capacity(66);
+        message.extend_from_slice(b"\x19\x01");
+        message.extend_from_slice(self._domain_separator().as_slice());
+        message.extend_from_slice(struct_hash.as_slice());
+        self.vm().native_keccak256(&message)
+    }
+
+    // Recover the signer of `hash` through the ecrecover precompile
+    fn _recover(&self, hash: B256, v: u8, r: B256, s: B256) -> Result<Address, ERC20Error> {
+        if U256::from_be_bytes(s.0) > MAX_S {
+            return Err(ERC20Error::ECDSAInvalidSignatureS(ECDSAInvalidSignatureS { s }));
+        }
+
+        let mut input = Vec::with_capacity(128);
+        input.extend_from_slice(hash.as_slice());
+        input.extend_from_slice(&U256::from(v).to_be_bytes::<32>());
+        input.extend_from_slice(r.as_slice());
+        input.extend_from_slice(s.as_slice());
+
+        let output = self
+            .vm()
+            .static_call(&self, ECRECOVER, &input)
+            .map_err(|_| ERC20Error::ECDSAInvalidSignature(ECDSAInvalidSignature {}))?;
+        if output.len() != 32 {
+            return Err(ERC20Error::ECDSAInvalidSignature(ECDSAInvalidSignature {}));
+        }
+
+        let signer = Address::from_slice(&output[12..]);
+        if signer.is_zero() {
+            return Err(ERC20Error::ECDSAInvalidSignature(ECDSAInvalidSignature {}));
+        }
+        Ok(signer)
+    }
+
+    fn _clock(&self) -> U48 {
+        U48::from(self.vm().block_number())
+    }
+
+    // Reject lookups at or after the current block, whose votes can still change
+    fn _check_timepoint(&self, timepoint: U256) -> Result<U48, ERC20Error> {
+        let clock = self._clock();
+        if timepoint >= U256::from(clock) {
+            return Err(ERC20Error::ERC5805FutureLookup(ERC5805FutureLookup { timepoint, clock }));
+        }
+        Ok(U48::from(timepoint))
+    }
+
+    fn _delegate(&mut self, account: Address, delegatee: Address) {
+        let old_delegate = self.delegates.get(account);
+        self.delegates.insert(account, delegatee);
+        log(self.vm(), DelegateChanged {
+            delegator: account,
+            from_delegate: old_delegate,
+            to_delegate: delegatee,
+        });
+
+        let units = self.balances.get(account);
+        self._move_delegate_votes(old_delegate, delegatee, units);
+    }
+
+    fn _move_delegate_votes(&mut self, from: Address, to: Address, amount: U256) {
+        if from == to || amount.is_zero() {
+            return;
+        }
+
+        let clock = self._clock();
+        if !from.is_zero() {
+            let mut checkpoints = self.checkpoints.setter(from);
+            let previous_votes = latest_checkpoint(&checkpoints);
+            let new_votes = previous_votes - amount;
+            push_checkpoint(&mut checkpoints, clock, new_votes);
+            log(self.vm(), DelegateVotesChanged { delegate: from, previous_votes, new_votes });
+        }
+        if !to.is_zero() {
+            let mut checkpoints = self.checkpoints.setter(to);
+            let previous_votes = latest_checkpoint(&checkpoints);
+            let new_votes = previous_votes + amount;
+            push_checkpoint(&mut checkpoints, clock, new_votes);
+            log(self.vm(), DelegateVotesChanged { delegate: to, previous_votes, new_votes });
+        }
+    }
 }
 
 /// Declare that `Counter` is a contract with the following external methods.
@@ -205,7 +477,7 @@ impl ERC20 {
     }
 
     fn decimals(&self) -> u8 {
-        u8::from(18)
+        18
     }
 
     fn total_supply(&self) -> U256 {
@@ -221,8 +493,7 @@ impl ERC20 {
     }
 
     fn allowance(&self, owner: Address, spender: Address) -> U256 {
-        let allowance = self.allowances.getter(owner).get(spender);
-        allowance
+        self.allowances.getter(owner).get(spender)
     }
 
     fn approve(&mut self, spender: Address, value: U256) -> Result<(), ERC20Error> {
@@ -246,6 +517,236 @@ impl ERC20 {
     fn burn(&mut self, value: U256) -> Result<(), ERC20Error> {
         self._burn(self.vm().msg_sender(), value)
     }
+
+    fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Hand minting and fee control to `new_owner` (owner only)
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), ERC20Error> {
+        let previous_owner = self.owner.get();
+        if self.vm().msg_sender() != previous_owner {
+            return Err(ERC20Error::ERC20InvalidSender(ERC20InvalidSender {
+                sender: self.vm().msg_sender(),
+            }));
+        }
+        if new_owner.is_zero() {
+            return Err(ERC20Error::OwnableInvalidOwner(OwnableInvalidOwner { owner: new_owner }));
+        }
+        self.owner.set(new_owner);
+        log(self.vm(), OwnershipTransferred { previous_owner, new_owner });
+        Ok(())
+    }
+
+    /// Approve `spender` with an EIP-712 signature from `owner` (EIP-2612)
+    #[allow(clippy::too_many_arguments)]
+    fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), ERC20Error> {
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err(ERC20Error::ERC2612ExpiredSignature(ERC2612ExpiredSignature {
+                deadline,
+            }));
+        }
+
+        let nonce = self.nonces.get(owner);
+        let mut encoded = Vec::with_capacity(192);
+        encoded.extend_from_slice(&PERMIT_TYPEHASH);
+        encoded.extend_from_slice(owner.into_word().as_slice());
+        encoded.extend_from_slice(spender.into_word().as_slice());
+        encoded.extend_from_slice(&value.to_be_bytes::<32>());
+        encoded.extend_from_slice(&nonce.to_be_bytes::<32>());
+        encoded.extend_from_slice(&deadline.to_be_bytes::<32>());
+        let struct_hash = self.vm().native_keccak256(&encoded);
+        let digest = self._hash_typed_data(struct_hash);
+
+        let signer = self._recover(digest, v, r, s)?;
+        if signer != owner {
+            return Err(ERC20Error::ERC2612InvalidSigner(ERC2612InvalidSigner {
+                signer,
+                owner,
+            }));
+        }
+
+        self.nonces.setter(owner).set(nonce + U256::from(1));
+        self._approve(owner, spender, value)
+    }
+
+    /// Next nonce `owner` must sign a permit with
+    fn nonces(&self, owner: Address) -> U256 {
+        self.nonces.get(owner)
+    }
+
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    fn domain_separator(&self) -> B256 {
+        self._domain_separator()
+    }
+
+    /// Largest amount that can be flash minted (zero for other tokens)
+    fn max_flash_loan(&self, token: Address) -> U256 {
+        if token != self.vm().contract_address() {
+            return U256::ZERO;
+        }
+        MAX_SUPPLY - self.total_supply.get()
+    }
+
+    /// Fee charged on a flash mint of `value`, burned with the loan
+    fn flash_fee(&self, token: Address, value: U256) -> Result<U256, ERC20Error> {
+        if token != self.vm().contract_address() {
+            return Err(ERC20Error::ERC3156UnsupportedToken(ERC3156UnsupportedToken { token }));
+        }
+
+        // Loans are capped below 2^208, so the fee can't overflow
+        let max_loan = self.max_flash_loan(token);
+        if value > max_loan {
+            return Err(ERC20Error::ERC3156ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan }));
+        }
+        Ok(value * U256::from(self.flash_fee_bps.get()) / U256::from(10_000))
+    }
+
+    /// Set the flash mint fee in basis points, at most 10_000 (owner only)
+    fn set_flash_fee(&mut self, fee_bps: u16) -> Result<(), ERC20Error> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(ERC20Error::ERC20InvalidSender(ERC20InvalidSender {
+                sender: self.vm().msg_sender(),
+            }));
+        }
+        if fee_bps > MAX_FLASH_FEE_BPS {
+            return Err(ERC20Error::ERC3156InvalidFee(ERC3156InvalidFee { fee_bps }));
+        }
+        let previous_fee_bps = self.flash_fee_bps.get().to::<u16>();
+        self.flash_fee_bps.set(U16::from(fee_bps));
+        log(self.vm(), FlashFeeUpdated { previous_fee_bps, new_fee_bps: fee_bps });
+        Ok(())
+    }
+
+    /// Mint `value` to `receiver`, call its `onFlashLoan`, then burn `value` plus the fee
+    ///
+    /// The receiver must approve this contract for `value + fee` before returning. If its
+    /// callback reverts, the loan reverts with the receiver's revert data. The receiver can only
+    /// call back into this contract when it is built with the `reentrant` feature, as by default.
+    fn flash_loan(
+        &mut self,
+        receiver: Address,
+        token: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<bool, Vec<u8>> {
+        let max_loan = self.max_flash_loan(token);
+        if value > max_loan {
+            return Err(ERC20Error::ERC3156ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan }).into());
+        }
+
+        let fee = self.flash_fee(token, value)?;
+        self._mint(receiver, value)?;
+
+        let call = IERC3156FlashBorrower::onFlashLoanCall {
+            initiator: self.vm().msg_sender(),
+            token,
+            amount: value,
+            fee,
+            data: data.0.into(),
+        };
+        let result = self.vm().call(&self, receiver, &call.abi_encode())?;
+        if result.as_slice() != FLASH_LOAN_CALLBACK {
+            return Err(ERC20Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver { receiver }).into());
+        }
+
+        let this = self.vm().contract_address();
+        self._spend_allowance(receiver, this, value + fee)?;
+        self._burn(receiver, value + fee)?;
+        Ok(true)
+    }
+
+    /// Current timepoint of the votes clock (the block number)
+    fn clock(&self) -> U48 {
+        self._clock()
+    }
+
+    #[selector(name = "CLOCK_MODE")]
+    fn clock_mode(&self) -> String {
+        String::from(CLOCK_MODE)
+    }
+
+    /// Account `account` has delegated its votes to
+    fn delegates(&self, account: Address) -> Address {
+        self.delegates.get(account)
+    }
+
+    /// Delegate the caller's votes to `delegatee`
+    fn delegate(&mut self, delegatee: Address) {
+        self._delegate(self.vm().msg_sender(), delegatee);
+    }
+
+    /// Delegate votes with an EIP-712 signature from the delegator
+    fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), ERC20Error> {
+        if U256::from(self.vm().block_timestamp()) > expiry {
+            return Err(ERC20Error::VotesExpiredSignature(VotesExpiredSignature { expiry }));
+        }
+
+        let mut encoded = Vec::with_capacity(128);
+        encoded.extend_from_slice(&DELEGATION_TYPEHASH);
+        encoded.extend_from_slice(delegatee.into_word().as_slice());
+        encoded.extend_from_slice(&nonce.to_be_bytes::<32>());
+        encoded.extend_from_slice(&expiry.to_be_bytes::<32>());
+        let struct_hash = self.vm().native_keccak256(&encoded);
+        let signer = self._recover(self._hash_typed_data(struct_hash), v, r, s)?;
+
+        let current_nonce = self.nonces.get(signer);
+        if nonce != current_nonce {
+            return Err(ERC20Error::InvalidAccountNonce(InvalidAccountNonce {
+                account: signer,
+                current_nonce,
+            }));
+        }
+        self.nonces.setter(signer).set(current_nonce + U256::from(1));
+
+        self._delegate(signer, delegatee);
+        Ok(())
+    }
+
+    /// Current votes of `account`
+    fn get_votes(&self, account: Address) -> U256 {
+        latest_checkpoint(&self.checkpoints.getter(account))
+    }
+
+    /// Votes `account` had at the end of block `timepoint`
+    fn get_past_votes(&self, account: Address, timepoint: U256) -> Result<U256, ERC20Error> {
+        let key = self._check_timepoint(timepoint)?;
+        Ok(upper_lookup(&self.checkpoints.getter(account), key))
+    }
+
+    /// Total supply at the end of block `timepoint`
+    fn get_past_total_supply(&self, timepoint: U256) -> Result<U256, ERC20Error> {
+        let key = self._check_timepoint(timepoint)?;
+        Ok(upper_lookup(&self.total_supply_checkpoints, key))
+    }
+
+    fn num_checkpoints(&self, account: Address) -> u32 {
+        self.checkpoints.getter(account).len() as u32
+    }
+
+    /// Checkpoint `pos` of `account` as `(block, votes)`
+    fn checkpoints(&self, account: Address, pos: u32) -> (U48, U208) {
+        let checkpoints = self.checkpoints.getter(account);
+        let checkpoint = checkpoints.get(pos as usize).expect("checkpoint out of range");
+        (checkpoint.key.get(), checkpoint.value.get())
+    }
 }
 
 
@@ -354,4 +855,487 @@ mod test {
       
       println!("All burn tests passed!");
     }
+    #[test]
+    fn test_transfer_ownership() {
+        use stylus_sdk::testing::*;
+        let owner = Address::repeat_byte(0x0E);
+        let new_owner = Address::repeat_byte(0x0F);
+        let vm = TestVM::default();
+        vm.set_sender(owner);
+        let mut contract = ERC20::from(&vm);
+        contract.constructor(String::from("Test"), String::from("TT"));
+        assert_eq!(contract.owner(), owner);
+
+        let result = contract.transfer_ownership(Address::ZERO);
+        assert!(matches!(result, Err(ERC20Error::OwnableInvalidOwner(_))));
+
+        assert!(contract.transfer_ownership(new_owner).is_ok());
+        assert_eq!(contract.owner(), new_owner);
+
+        // The old owner can no longer mint or transfer ownership
+        assert!(contract.mint(owner, U256::from(1)).is_err());
+        assert!(contract.transfer_ownership(owner).is_err());
+
+        vm.set_sender(new_owner);
+        assert!(contract.mint(owner, U256::from(1)).is_ok());
+    }
+
+    mod permit {
+        use super::super::*;
+        use ethers::abi::{encode, Token};
+        use ethers::signers::{LocalWallet, Signer};
+        use ethers::types::{Signature, H256, U256 as EU256};
+        use ethers::utils::keccak256;
+        use stylus_sdk::testing::*;
+
+        pub(super) const OWNER_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        const OTHER_KEY: &str = "59c6995e998f97a5a0044966f0926ee2ddec89b3a08e8d3fd2b7b69f6d3ba4ad";
+        const CHAIN_ID: u64 = 42161;
+        pub(super) const NOW: u64 = 1_700_000_000;
+
+        pub(super) fn setup() -> (TestVM, ERC20) {
+            let vm = TestVM::default();
+            vm.set_chain_id(CHAIN_ID);
+            vm.set_contract_address(Address::from([9; 20]));
+            vm.set_block_timestamp(NOW);
+            let mut contract = ERC20::from(&vm);
+            contract.constructor(String::from("Test"), String::from("TT"));
+            (vm, contract)
+        }
+
+        pub(super) fn to_address(wallet: &LocalWallet) -> Address {
+            Address::from(wallet.address().0)
+        }
+
+        pub(super) fn to_token(address: Address) -> Token {
+            Token::Address(address.0 .0.into())
+        }
+
+        // Digest built off-chain the way a wallet would for `eth_signTypedData_v4`
+        pub(super) fn typed_data_digest(struct_hash: [u8; 32]) -> [u8; 32] {
+            let domain = keccak256(encode(&[
+                Token::FixedBytes(keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec()),
+                Token::FixedBytes(keccak256("Test").to_vec()),
+                Token::FixedBytes(keccak256("1").to_vec()),
+                Token::Uint(CHAIN_ID.into()),
+                to_token(Address::from([9; 20])),
+            ]));
+            keccak256([&[0x19, 0x01][..], &domain, &struct_hash].concat())
+        }
+
+        fn permit_digest(
+            owner: Address,
+            spender: Address,
+            value: u64,
+            nonce: u64,
+            deadline: u64,
+        ) -> [u8; 32] {
+            let struct_hash = keccak256(encode(&[
+                Token::FixedBytes(keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)").to_vec()),
+                to_token(owner),
+                to_token(spender),
+                Token::Uint(value.into()),
+                Token::Uint(nonce.into()),
+                Token::Uint(deadline.into()),
+            ]));
+            typed_data_digest(struct_hash)
+        }
+
+        fn to_b256(value: EU256) -> B256 {
+            let mut bytes = [0u8; 32];
+            value.to_big_endian(&mut bytes);
+            B256::from(bytes)
+        }
+
+        // Register what the ecrecover precompile returns for (v, r, s) over the digest
+        fn mock_ecrecover(vm: &TestVM, digest: [u8; 32], v: u8, r: B256, s: B256) -> Address {
+            let signature = Signature {
+                r: EU256::from_big_endian(r.as_slice()),
+                s: EU256::from_big_endian(s.as_slice()),
+                v: v.into(),
+            };
+            let signer = Address::from(signature.recover(H256::from(digest)).unwrap().0);
+
+            let input = [&digest[..], &U256::from(v).to_be_bytes::<32>(), r.as_slice(), s.as_slice()].concat();
+            vm.mock_static_call(ECRECOVER, input, Ok(signer.into_word().to_vec()));
+            signer
+        }
+
+        // Sign the digest and register what the ecrecover precompile returns for it
+        pub(super) fn sign(vm: &TestVM, wallet: &LocalWallet, digest: [u8; 32]) -> (u8, B256, B256) {
+            let signature: Signature = wallet.sign_hash(H256::from(digest)).unwrap();
+            let (v, r, s) = (signature.v as u8, to_b256(signature.r), to_b256(signature.s));
+            mock_ecrecover(vm, digest, v, r, s);
+            (v, r, s)
+        }
+
+        #[test]
+        fn test_domain_separator() {
+            let (_vm, contract) = setup();
+            let expected = keccak256(encode(&[
+                Token::FixedBytes(keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec()),
+                Token::FixedBytes(keccak256("Test").to_vec()),
+                Token::FixedBytes(keccak256("1").to_vec()),
+                Token::Uint(CHAIN_ID.into()),
+                to_token(Address::from([9; 20])),
+            ]));
+            assert_eq!(contract.domain_separator(), B256::from(expected));
+        }
+
+        #[test]
+        fn test_permit() {
+            let (vm, mut contract) = setup();
+            let wallet: LocalWallet = OWNER_KEY.parse().unwrap();
+            let owner = to_address(&wallet);
+            let spender = Address::from([2; 20]);
+            let recipient = Address::from([3; 20]);
+            contract.mint(owner, U256::from(1000)).unwrap();
+
+            let deadline = NOW + 3600;
+            let digest = permit_digest(owner, spender, 400, 0, deadline);
+            let (v, r, s) = sign(&vm, &wallet, digest);
+
+            contract.permit(owner, spender, U256::from(400), U256::from(deadline), v, r, s).unwrap();
+            assert_eq!(contract.allowance(owner, spender), U256::from(400));
+            assert_eq!(contract.nonces(owner), U256::from(1));
+
+            // The spender pays the gas and moves the tokens
+            vm.set_sender(spender);
+            contract.transfer_from(owner, recipient, U256::from(300)).unwrap();
+            assert_eq!(contract.balance_of(recipient), U256::from(300));
+            assert_eq!(contract.allowance(owner, spender), U256::from(100));
+
+            // The nonce moved on, so the same signature recovers some other address
+            let replayed = permit_digest(owner, spender, 400, 1, deadline);
+            let signer = mock_ecrecover(&vm, replayed, v, r, s);
+            assert_ne!(signer, owner);
+            let result = contract.permit(owner, spender, U256::from(400), U256::from(deadline), v, r, s);
+            match result {
+                Err(ERC20Error::ERC2612InvalidSigner(err)) => {
+                    assert_eq!(err.signer, signer);
+                    assert_eq!(err.owner, owner);
+                }
+                other => panic!("expected ERC2612InvalidSigner, got {:?}", other),
+            }
+            assert_eq!(contract.nonces(owner), U256::from(1));
+        }
+
+        #[test]
+        fn test_permit_invalid_signer() {
+            let (vm, mut contract) = setup();
+            let owner = to_address(&OWNER_KEY.parse().unwrap());
+            let other: LocalWallet = OTHER_KEY.parse().unwrap();
+            let spender = Address::from([2; 20]);
+
+            let deadline = NOW + 3600;
+            let (v, r, s) = sign(&vm, &other, permit_digest(owner, spender, 400, 0, deadline));
+
+            match contract.permit(owner, spender, U256::from(400), U256::from(deadline), v, r, s) {
+                Err(ERC20Error::ERC2612InvalidSigner(err)) => {
+                    assert_eq!(err.signer, to_address(&other));
+                    assert_eq!(err.owner, owner);
+                }
+                other => panic!("expected ERC2612InvalidSigner, got {:?}", other),
+            }
+            assert_eq!(contract.allowance(owner, spender), U256::ZERO);
+            assert_eq!(contract.nonces(owner), U256::ZERO);
+        }
+
+        #[test]
+        fn test_permit_failed_ecrecover_call() {
+            let (vm, mut contract) = setup();
+            let owner = to_address(&OWNER_KEY.parse().unwrap());
+            let spender = Address::from([2; 20]);
+
+            let deadline = NOW + 3600;
+            let digest = permit_digest(owner, spender, 400, 0, deadline);
+            let (v, r, s) = (27, B256::repeat_byte(1), B256::repeat_byte(2));
+            let input = [&digest[..], &U256::from(v).to_be_bytes::<32>(), r.as_slice(), s.as_slice()].concat();
+            vm.mock_static_call(ECRECOVER, input, Err(vec![]));
+
+            let result = contract.permit(owner, spender, U256::from(400), U256::from(deadline), v, r, s);
+            assert!(matches!(result, Err(ERC20Error::ECDSAInvalidSignature(_))));
+            assert_eq!(contract.nonces(owner), U256::ZERO);
+        }
+
+        #[test]
+        fn test_permit_expired() {
+            let (vm, mut contract) = setup();
+            let wallet: LocalWallet = OWNER_KEY.parse().unwrap();
+            let owner = to_address(&wallet);
+            let spender = Address::from([2; 20]);
+
+            let deadline = NOW - 1;
+            let (v, r, s) = sign(&vm, &wallet, permit_digest(owner, spender, 400, 0, deadline));
+
+            let result = contract.permit(owner, spender, U256::from(400), U256::from(deadline), v, r, s);
+            assert!(matches!(result, Err(ERC20Error::ERC2612ExpiredSignature(_))));
+        }
+
+        #[test]
+        fn test_permit_rejects_high_s() {
+            let (vm, mut contract) = setup();
+            let wallet: LocalWallet = OWNER_KEY.parse().unwrap();
+            let owner = to_address(&wallet);
+            let spender = Address::from([2; 20]);
+
+            let deadline = NOW + 3600;
+            let (v, r, s) = sign(&vm, &wallet, permit_digest(owner, spender, 400, 0, deadline));
+
+            // (r, n - s) with the flipped v is also valid for ecrecover
+            let n = (MAX_S << 1) + U256::from(1);
+            let high_s = B256::from(n - U256::from_be_bytes(s.0));
+            let flipped_v = if v == 27 { 28 } else { 27 };
+
+            let result = contract.permit(owner, spender, U256::from(400), U256::from(deadline), flipped_v, r, high_s);
+            assert!(matches!(result, Err(ERC20Error::ECDSAInvalidSignatureS(_))));
+        }
+    }
+    mod votes {
+        use super::super::*;
+        use super::permit::{setup, sign, to_address, to_token, typed_data_digest, NOW, OWNER_KEY};
+        use ethers::abi::{encode, Token};
+        use ethers::signers::LocalWallet;
+        use ethers::utils::keccak256;
+
+        #[test]
+        fn test_delegation_checkpoints() {
+            let (vm, mut contract) = setup();
+            let alice = Address::from([2; 20]);
+            let bob = Address::from([3; 20]);
+            let carol = Address::from([4; 20]);
+
+            vm.set_block_number(10);
+            contract.mint(alice, U256::from(1000)).unwrap();
+            // Balances only count as votes once delegated
+            assert_eq!(contract.get_votes(alice), U256::ZERO);
+
+            vm.set_block_number(11);
+            vm.set_sender(alice);
+            contract.delegate(alice);
+            vm.set_sender(bob);
+            contract.delegate(carol);
+            assert_eq!(contract.delegates(bob), carol);
+            assert_eq!(contract.get_votes(alice), U256::from(1000));
+
+            vm.set_block_number(12);
+            vm.set_sender(alice);
+            contract.transfer(bob, U256::from(300)).unwrap();
+            contract.transfer(bob, U256::from(100)).unwrap();
+            assert_eq!(contract.get_votes(alice), U256::from(600));
+            assert_eq!(contract.get_votes(carol), U256::from(400));
+
+            // Both transfers in block 12 share a checkpoint
+            assert_eq!(contract.num_checkpoints(alice), 2);
+            assert_eq!(contract.checkpoints(alice, 0), (U48::from(11), U208::from(1000)));
+            assert_eq!(contract.checkpoints(alice, 1), (U48::from(12), U208::from(600)));
+
+            vm.set_block_number(13);
+            contract.burn(U256::from(100)).unwrap();
+
+            vm.set_block_number(20);
+            assert_eq!(contract.get_past_votes(alice, U256::from(10)).unwrap(), U256::ZERO);
+            assert_eq!(contract.get_past_votes(alice, U256::from(11)).unwrap(), U256::from(1000));
+            assert_eq!(contract.get_past_votes(alice, U256::from(12)).unwrap(), U256::from(600));
+            assert_eq!(contract.get_past_votes(alice, U256::from(19)).unwrap(), U256::from(500));
+            assert_eq!(contract.get_past_votes(carol, U256::from(11)).unwrap(), U256::ZERO);
+            assert_eq!(contract.get_past_votes(carol, U256::from(12)).unwrap(), U256::from(400));
+            assert_eq!(contract.get_past_total_supply(U256::from(9)).unwrap(), U256::ZERO);
+            assert_eq!(contract.get_past_total_supply(U256::from(12)).unwrap(), U256::from(1000));
+            assert_eq!(contract.get_past_total_supply(U256::from(13)).unwrap(), U256::from(900));
+
+            // Moving the delegation carries the whole balance across
+            vm.set_sender(bob);
+            contract.delegate(bob);
+            assert_eq!(contract.get_votes(carol), U256::ZERO);
+            assert_eq!(contract.get_votes(bob), U256::from(400));
+        }
+
+        #[test]
+        fn test_clock() {
+            let (vm, mut contract) = setup();
+            vm.set_block_number(50);
+            assert_eq!(contract.clock(), U48::from(50));
+            assert_eq!(contract.clock_mode(), "mode=blocknumber&from=default");
+
+            match contract.get_past_votes(Address::from([2; 20]), U256::from(50)) {
+                Err(ERC20Error::ERC5805FutureLookup(err)) => {
+                    assert_eq!(err.timepoint, U256::from(50));
+                    assert_eq!(err.clock, U48::from(50));
+                }
+                other => panic!("expected ERC5805FutureLookup, got {:?}", other),
+            }
+            assert!(contract.get_past_total_supply(U256::from(51)).is_err());
+
+            let result = contract.mint(Address::from([2; 20]), MAX_SUPPLY + U256::from(1));
+            assert!(matches!(result, Err(ERC20Error::ERC20ExceededSafeSupply(_))));
+        }
+
+        #[test]
+        fn test_delegate_by_sig() {
+            let (vm, mut contract) = setup();
+            let wallet: LocalWallet = OWNER_KEY.parse().unwrap();
+            let delegator = to_address(&wallet);
+            let delegatee = Address::from([4; 20]);
+            contract.mint(delegator, U256::from(1000)).unwrap();
+
+            let expiry = NOW + 3600;
+            let struct_hash = keccak256(encode(&[
+                Token::FixedBytes(keccak256("Delegation(address delegatee,uint256 nonce,uint256 expiry)").to_vec()),
+                to_token(delegatee),
+                Token::Uint(0.into()),
+                Token::Uint(expiry.into()),
+            ]));
+            let (v, r, s) = sign(&vm, &wallet, typed_data_digest(struct_hash));
+
+            // Anyone can relay the signature
+            vm.set_sender(Address::from([7; 20]));
+            contract.delegate_by_sig(delegatee, U256::ZERO, U256::from(expiry), v, r, s).unwrap();
+            assert_eq!(contract.delegates(delegator), delegatee);
+            assert_eq!(contract.get_votes(delegatee), U256::from(1000));
+            assert_eq!(contract.nonces(delegator), U256::from(1));
+
+            let result = contract.delegate_by_sig(delegatee, U256::ZERO, U256::from(expiry), v, r, s);
+            assert!(matches!(result, Err(ERC20Error::InvalidAccountNonce(_))));
+
+            vm.set_block_timestamp(expiry + 1);
+            let result = contract.delegate_by_sig(delegatee, U256::ZERO, U256::from(expiry), v, r, s);
+            assert!(matches!(result, Err(ERC20Error::VotesExpiredSignature(_))));
+        }
+    }
+    mod flash_mint {
+        use super::super::*;
+        use super::permit::setup;
+        use stylus_sdk::testing::*;
+
+        const TOKEN: Address = Address::new([9; 20]);
+
+        // Borrower contract whose `onFlashLoan` answer is scripted on the TestVM
+        struct MockBorrower {
+            address: Address,
+        }
+
+        impl MockBorrower {
+            fn new(vm: &TestVM, contract: &mut ERC20, address: Address) -> Self {
+                // Repayment allowance, which a real borrower grants in its callback
+                vm.set_sender(address);
+                contract.approve(TOKEN, U256::MAX).unwrap();
+                MockBorrower { address }
+            }
+
+            fn expect(&self, vm: &TestVM, initiator: Address, value: u64, fee: u64, data: &[u8], result: Result<Vec<u8>, Vec<u8>>) {
+                let call = IERC3156FlashBorrower::onFlashLoanCall {
+                    initiator,
+                    token: TOKEN,
+                    amount: U256::from(value),
+                    fee: U256::from(fee),
+                    data: data.to_vec().into(),
+                };
+                vm.mock_call(self.address, call.abi_encode(), result);
+            }
+        }
+
+        #[test]
+        fn test_flash_loan() {
+            let (vm, mut contract) = setup();
+            let owner = contract.owner.get();
+            let initiator = Address::from([5; 20]);
+            let borrower = MockBorrower::new(&vm, &mut contract, Address::from([6; 20]));
+            vm.set_sender(owner);
+            contract.mint(borrower.address, U256::from(100)).unwrap();
+
+            assert_eq!(contract.max_flash_loan(TOKEN), MAX_SUPPLY - U256::from(100));
+            assert_eq!(contract.max_flash_loan(Address::from([1; 20])), U256::ZERO);
+            assert_eq!(contract.flash_fee(TOKEN, U256::from(10_000)).unwrap(), U256::ZERO);
+
+            borrower.expect(&vm, initiator, 10_000, 0, b"arb", Ok(FLASH_LOAN_CALLBACK.to_vec()));
+            vm.set_sender(initiator);
+            let result = contract.flash_loan(borrower.address, TOKEN, U256::from(10_000), Bytes(b"arb".to_vec()));
+            assert!(result.unwrap());
+            assert_eq!(contract.balance_of(borrower.address), U256::from(100));
+            assert_eq!(contract.total_supply(), U256::from(100));
+
+            // 0.5% fee, burned together with the loan
+            vm.set_sender(owner);
+            contract.set_flash_fee(50).unwrap();
+            assert_eq!(contract.flash_fee(TOKEN, U256::from(10_000)).unwrap(), U256::from(50));
+
+            borrower.expect(&vm, initiator, 10_000, 50, b"", Ok(FLASH_LOAN_CALLBACK.to_vec()));
+            vm.set_sender(initiator);
+            contract.flash_loan(borrower.address, TOKEN, U256::from(10_000), Bytes(vec![])).unwrap();
+            assert_eq!(contract.balance_of(borrower.address), U256::from(50));
+            assert_eq!(contract.total_supply(), U256::from(50));
+
+            assert!(matches!(contract.set_flash_fee(0), Err(ERC20Error::ERC20InvalidSender(_))));
+        }
+
+        #[test]
+        fn test_flash_fee_is_capped() {
+            let (vm, mut contract) = setup();
+            vm.set_sender(contract.owner.get());
+
+            contract.set_flash_fee(10_000).unwrap();
+            assert_eq!(contract.flash_fee(TOKEN, U256::from(1_000)).unwrap(), U256::from(1_000));
+
+            match contract.set_flash_fee(10_001) {
+                Err(ERC20Error::ERC3156InvalidFee(err)) => assert_eq!(err.fee_bps, 10_001),
+                other => panic!("expected ERC3156InvalidFee, got {:?}", other),
+            }
+            assert_eq!(contract.flash_fee(TOKEN, U256::from(1_000)).unwrap(), U256::from(1_000));
+        }
+
+        #[test]
+        fn test_flash_loan_failures() {
+            let (vm, mut contract) = setup();
+            let initiator = Address::from([5; 20]);
+            let borrower = MockBorrower::new(&vm, &mut contract, Address::from([6; 20]));
+            let other_token = Address::from([1; 20]);
+
+            vm.set_sender(initiator);
+            let result = contract.flash_loan(borrower.address, other_token, U256::from(1), Bytes(vec![]));
+            let expected = ERC20Error::ERC3156ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan: U256::ZERO });
+            assert_eq!(result.unwrap_err(), Vec::<u8>::from(expected));
+            let result = contract.flash_fee(other_token, U256::from(1));
+            assert!(matches!(result, Err(ERC20Error::ERC3156UnsupportedToken(_))));
+
+            let result = contract.flash_loan(borrower.address, TOKEN, MAX_SUPPLY + U256::from(1), Bytes(vec![]));
+            let expected = ERC20Error::ERC3156ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan: MAX_SUPPLY });
+            assert_eq!(result.unwrap_err(), Vec::<u8>::from(expected));
+            let result = contract.flash_fee(TOKEN, U256::MAX);
+            assert!(matches!(result, Err(ERC20Error::ERC3156ExceededMaxLoan(_))));
+
+            // Wrong or missing callback value
+            borrower.expect(&vm, initiator, 1_000, 0, b"", Ok([0; 32].to_vec()));
+            let result = contract.flash_loan(borrower.address, TOKEN, U256::from(1_000), Bytes(vec![]));
+            let expected = ERC20Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver { receiver: borrower.address });
+            assert_eq!(result.unwrap_err(), Vec::<u8>::from(expected));
+
+            let receiver = Address::from([8; 20]);
+            let result = contract.flash_loan(receiver, TOKEN, U256::from(1_000), Bytes(vec![]));
+            let expected = ERC20Error::ERC3156InvalidReceiver(ERC3156InvalidReceiver { receiver });
+            assert_eq!(result.unwrap_err(), Vec::<u8>::from(expected));
+
+            // A reverting callback passes its own revert data back
+            borrower.expect(&vm, initiator, 1_000, 0, b"", Err(b"borrower failed".to_vec()));
+            let result = contract.flash_loan(borrower.address, TOKEN, U256::from(1_000), Bytes(vec![]));
+            assert_eq!(result.unwrap_err(), b"borrower failed".to_vec());
+        }
+
+        #[test]
+        fn test_flash_loan_requires_repayment_allowance() {
+            let (vm, mut contract) = setup();
+            let initiator = Address::from([5; 20]);
+            let borrower = MockBorrower { address: Address::from([6; 20]) };
+
+            borrower.expect(&vm, initiator, 1_000, 0, b"", Ok(FLASH_LOAN_CALLBACK.to_vec()));
+            vm.set_sender(initiator);
+            let result = contract.flash_loan(borrower.address, TOKEN, U256::from(1_000), Bytes(vec![]));
+            let expected = ERC20Error::ERC20InsufficientAllowance(ERC20InsufficientAllowance {
+                owner: borrower.address,
+                allowance: U256::ZERO,
+                needed: U256::from(1_000),
+            });
+            assert_eq!(result.unwrap_err(), Vec::<u8>::from(expected));
+        }
+    }
 }
@@ -0,0 +1,182 @@
+//! Checks the Stylus ERC-20 against the OpenZeppelin reference model.
+
+use alloy_primitives::{address, Address, LogData, U256};
+use alloy_sol_types::{sol, SolCall};
+use erc20_stylus::ERC20;
+use proptest::prelude::*;
+use stylus_testkit::differential::{self, Deviations, Erc20Subject, Op};
+use stylus_testkit::World;
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const OWNER: Address = Address::new([0x0E; 20]);
+
+// Where the course token differs from OpenZeppelin v5, kept for ABI compatibility
+const COURSE_TOKEN: Deviations = Deviations {
+    legacy_errors: true,
+    finite_max_allowance: true,
+};
+
+sol! {
+    interface IERC20 {
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address owner) external view returns (uint256);
+        function allowance(address owner, address spender) external view returns (uint256);
+        function transfer(address to, uint256 value) external;
+        function approve(address spender, uint256 value) external;
+        function transferFrom(address from, address to, uint256 value) external;
+        function mint(address to, uint256 value) external;
+        function burn(uint256 value) external;
+    }
+}
+
+/// The contract in a `World`, called through its ABI
+struct StylusErc20 {
+    world: World,
+}
+
+impl StylusErc20 {
+    fn new() -> Self {
+        let world = World::new();
+        world.set_sender(OWNER);
+        let mut token = world.deploy::<ERC20>(TOKEN);
+        token.constructor(String::from("Test"), String::from("TT"));
+        world.clear_logs();
+        StylusErc20 { world }
+    }
+
+    // Reverted calls are rolled back by the world
+    fn call(&mut self, sender: Address, call: impl SolCall) -> Result<(), Vec<u8>> {
+        self.world.set_sender(sender);
+        self.world.call(TOKEN, &call.abi_encode()).map(|_| ())
+    }
+
+    fn view<T: SolCall>(&self, call: T) -> T::Return {
+        self.world.call_sol(TOKEN, &call).unwrap()
+    }
+}
+
+impl Erc20Subject for StylusErc20 {
+    fn transfer(&mut self, sender: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.call(sender, IERC20::transferCall { to, value })
+    }
+
+    fn approve(&mut self, sender: Address, spender: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.call(sender, IERC20::approveCall { spender, value })
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.call(sender, IERC20::transferFromCall { from, to, value })
+    }
+
+    fn mint(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.call(OWNER, IERC20::mintCall { to, value })
+    }
+
+    fn burn(&mut self, sender: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.call(sender, IERC20::burnCall { value })
+    }
+
+    fn balance_of(&self, account: Address) -> U256 {
+        self.view(IERC20::balanceOfCall { owner: account })._0
+    }
+
+    fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        self.view(IERC20::allowanceCall { owner, spender })._0
+    }
+
+    fn total_supply(&self) -> U256 {
+        self.view(IERC20::totalSupplyCall {})._0
+    }
+
+    fn take_logs(&mut self) -> Vec<LogData> {
+        let logs = self
+            .world
+            .logs()
+            .into_iter()
+            .map(|log| LogData::new_unchecked(log.topics, log.data.into()))
+            .collect();
+        self.world.clear_logs();
+        logs
+    }
+}
+
+#[test]
+fn test_edge_cases() {
+    let ops = [
+        Op::Mint { to: 1, value: U256::from(1_000) },
+        Op::Mint { to: 0, value: U256::from(1) },
+        Op::Transfer { sender: 1, to: 0, value: U256::from(1) },
+        Op::Approve { sender: 1, spender: 0, value: U256::from(1) },
+        Op::Approve { sender: 1, spender: 2, value: U256::MAX },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(400) },
+        Op::Approve { sender: 1, spender: 2, value: U256::from(100) },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(101) },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(100) },
+        Op::TransferFrom { sender: 2, from: 0, to: 3, value: U256::ZERO },
+        Op::Burn { sender: 3, value: U256::from(501) },
+        Op::Burn { sender: 3, value: U256::from(500) },
+    ];
+    if let Err(divergence) = differential::run_with(&mut StylusErc20::new(), &ops, COURSE_TOKEN) {
+        panic!("{}", divergence);
+    }
+}
+
+#[test]
+fn test_known_deviations() {
+    // Each sequence diverges from OpenZeppelin only through one documented deviation
+    let cases = [
+        (
+            vec![Op::Mint { to: 0, value: U256::from(1) }],
+            "revert ERC20InvalidReceiver(address)",
+            "revert ERC20InvalidReciver(address)",
+        ),
+        (
+            vec![Op::Approve { sender: 1, spender: 0, value: U256::from(1) }],
+            "revert ERC20InvalidSpender(address)",
+            "revert ERC20InvalidReciver(address)",
+        ),
+        (
+            vec![Op::TransferFrom { sender: 2, from: 0, to: 3, value: U256::ZERO }],
+            "revert ERC20InvalidApprover(address)",
+            "revert ERC20InvalidSender(address)",
+        ),
+        (
+            vec![Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(1) }],
+            "revert ERC20InsufficientAllowance(address,uint256,uint256) 0x000000000000000000000000b2",
+            "revert ERC20InsufficientAllowance(address,uint256,uint256) 0x000000000000000000000000a1",
+        ),
+    ];
+    for (ops, expected, actual) in cases {
+        let divergence = differential::run(&mut StylusErc20::new(), &ops).unwrap_err();
+        assert_eq!(divergence.what, "call result differs");
+        assert!(divergence.expected.starts_with(expected), "{}", divergence);
+        assert!(divergence.actual.starts_with(actual), "{}", divergence);
+    }
+
+    // `U256::MAX` allowances are spent like any other
+    let ops = [
+        Op::Mint { to: 1, value: U256::from(10) },
+        Op::Approve { sender: 1, spender: 2, value: U256::MAX },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(4) },
+    ];
+    let divergence = differential::run(&mut StylusErc20::new(), &ops).unwrap_err();
+    assert_eq!(divergence.step, 2);
+    assert_eq!(divergence.what, format!("allowance({}, {}) differs", differential::ACTORS[1], differential::ACTORS[2]));
+    assert_eq!(divergence.actual, (U256::MAX - U256::from(4)).to_string());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn test_matches_openzeppelin(ops in differential::ops(48)) {
+        let result = differential::run_with(&mut StylusErc20::new(), &ops, COURSE_TOKEN);
+        prop_assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Flash mints against a borrower contract that calls back into the token.
+//!
+//! Borrowers can only call back when the token is re-enterable, as it is with the default
+//! `reentrant` feature. Without it the tests that repay from the callback are skipped, and one
+//! checks that the call back reverts instead.
+
+use alloy_primitives::{address, keccak256, Address, B256, U256};
+use alloy_sol_types::{sol, SolCall, SolError};
+use erc20_stylus::{ERC3156InvalidReceiver, FlashFeeUpdated, ERC20};
+#[cfg(feature = "reentrant")]
+use erc20_stylus::{ERC20InsufficientAllowance, Transfer};
+use stylus_sdk::{prelude::*, stylus_core::calls::CallAccess, ArbResult};
+use stylus_testkit::{HandlerCall, World};
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const BORROWER: Address = address!("0xB0B0000000000000000000000000000000000000");
+const OWNER: Address = address!("0x1111111111111111111111111111111111111111");
+const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+
+sol! {
+    interface IERC3156FlashBorrower {
+        function onFlashLoan(address initiator, address token, uint256 amount, uint256 fee, bytes calldata data) external returns (bytes32);
+    }
+
+    interface IERC20 {
+        function approve(address spender, uint256 value) external;
+        function allowance(address owner, address spender) external view returns (uint256);
+        function balanceOf(address owner) external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+        function mint(address to, uint256 value) external;
+        function setFlashFee(uint16 fee_bps) external;
+    }
+
+    interface IERC3156FlashLender {
+        function flashLoan(address receiver, address token, uint256 value, bytes data) external returns (bool);
+    }
+}
+
+// Borrower that records its balance during the loan in slot 0, then approves
+// the token for repayment when `repay` is set
+fn register_borrower(world: &World, repay: bool) {
+    world.register(BORROWER, move |vm, input| {
+        let call = IERC3156FlashBorrower::onFlashLoanCall::abi_decode(input, true)
+            .map_err(|_| Vec::<u8>::new())?;
+
+        let balance = IERC20::balanceOfCall { owner: BORROWER };
+        let balance = vm.static_call(&HandlerCall::new(), call.token, &balance.abi_encode())?;
+        unsafe { vm.storage_cache_bytes32(U256::ZERO, B256::from_slice(&balance)) };
+
+        if repay {
+            let approve = IERC20::approveCall {
+                spender: call.token,
+                value: call.amount + call.fee,
+            };
+            vm.call(&HandlerCall::new(), call.token, &approve.abi_encode())?;
+        }
+        Ok(keccak256("ERC3156FlashBorrower.onFlashLoan").to_vec())
+    });
+}
+
+// Borrower that answers `onFlashLoan` with `result` without calling back into the token
+fn register_scripted_borrower(world: &World, result: ArbResult) {
+    world.register(BORROWER, move |_, _| result.clone());
+}
+
+fn setup() -> World {
+    let world = World::new();
+    world.set_sender(OWNER);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+    let mint = IERC20::mintCall {
+        to: BORROWER,
+        value: U256::from(100),
+    };
+    world.call_sol(TOKEN, &mint).unwrap();
+    world.call_sol(TOKEN, &IERC20::setFlashFeeCall { fee_bps: 50 }).unwrap();
+    world
+}
+
+fn flash_loan(world: &World, value: u64, data: &[u8]) -> Result<bool, Vec<u8>> {
+    let call = IERC3156FlashLender::flashLoanCall {
+        receiver: BORROWER,
+        token: TOKEN,
+        value: U256::from(value),
+        data: data.to_vec().into(),
+    };
+    world.call_sol(TOKEN, &call).map(|result| result._0)
+}
+
+#[cfg(feature = "reentrant")]
+fn balance_of(world: &World, owner: Address) -> U256 {
+    world.call_sol(TOKEN, &IERC20::balanceOfCall { owner }).unwrap()._0
+}
+
+fn total_supply(world: &World) -> U256 {
+    world.call_sol(TOKEN, &IERC20::totalSupplyCall {}).unwrap()._0
+}
+
+#[test]
+#[cfg(feature = "reentrant")]
+fn test_flash_loan_repaid_in_callback() {
+    let world = setup();
+    // The borrower approves repayment while the token is still executing `flashLoan`
+    register_borrower(&world, true);
+
+    world.clear_logs();
+    world.set_sender(ALICE);
+    assert!(flash_loan(&world, 10_000, b"arb").unwrap());
+
+    // The borrower held the loan during the callback, then repaid it plus a 0.5% fee
+    assert_eq!(world.storage(BORROWER, U256::ZERO), B256::from(U256::from(10_100)));
+    assert_eq!(balance_of(&world, BORROWER), U256::from(50));
+    assert_eq!(total_supply(&world), U256::from(50));
+    let allowance = IERC20::allowanceCall {
+        owner: BORROWER,
+        spender: TOKEN,
+    };
+    assert_eq!(world.call_sol(TOKEN, &allowance).unwrap()._0, U256::ZERO);
+
+    let transfers = world.events_from::<Transfer>(TOKEN);
+    assert_eq!(transfers.len(), 2);
+    assert_eq!((transfers[0].from, transfers[0].to), (Address::ZERO, BORROWER));
+    assert_eq!(transfers[0].value, U256::from(10_000));
+    assert_eq!((transfers[1].from, transfers[1].to), (BORROWER, Address::ZERO));
+    assert_eq!(transfers[1].value, U256::from(10_050));
+}
+
+#[test]
+#[cfg(feature = "reentrant")]
+fn test_flash_loan_without_repayment_reverts() {
+    let world = setup();
+    register_borrower(&world, false);
+
+    world.set_sender(ALICE);
+    let expected = ERC20InsufficientAllowance {
+        owner: BORROWER,
+        allowance: U256::ZERO,
+        needed: U256::from(10_050),
+    };
+    assert_eq!(flash_loan(&world, 10_000, b"").unwrap_err(), expected.abi_encode());
+
+    // The minted loan is rolled back with the revert
+    assert_eq!(balance_of(&world, BORROWER), U256::from(100));
+    assert_eq!(total_supply(&world), U256::from(100));
+}
+
+#[test]
+#[cfg(not(feature = "reentrant"))]
+fn test_flash_loan_callback_cannot_reenter_by_default() {
+    let world = setup();
+    register_borrower(&world, true);
+
+    // Without re-entry, the borrower's call back into the token reverts, and so does the loan
+    // with the borrower's revert data
+    world.set_sender(ALICE);
+    assert_eq!(flash_loan(&world, 10_000, b"").unwrap_err(), Vec::<u8>::new());
+    assert_eq!(total_supply(&world), U256::from(100));
+}
+
+#[test]
+fn test_flash_loan_passes_borrower_revert_back() {
+    let world = setup();
+    register_scripted_borrower(&world, Err(b"not today".to_vec()));
+
+    world.set_sender(ALICE);
+    assert_eq!(flash_loan(&world, 10_000, b"").unwrap_err(), b"not today".to_vec());
+    assert_eq!(total_supply(&world), U256::from(100));
+}
+
+#[test]
+fn test_flash_loan_wrong_callback_value_reverts() {
+    let world = setup();
+    register_scripted_borrower(&world, Ok(B256::ZERO.to_vec()));
+
+    world.set_sender(ALICE);
+    let expected = ERC3156InvalidReceiver { receiver: BORROWER };
+    assert_eq!(flash_loan(&world, 10_000, b"").unwrap_err(), expected.abi_encode());
+    assert_eq!(total_supply(&world), U256::from(100));
+}
+
+#[test]
+fn test_set_flash_fee_emits_event() {
+    let world = setup();
+
+    world.clear_logs();
+    world.call_sol(TOKEN, &IERC20::setFlashFeeCall { fee_bps: 100 }).unwrap();
+
+    let updates = world.events_from::<FlashFeeUpdated>(TOKEN);
+    assert_eq!(updates.len(), 1);
+    assert_eq!((updates[0].previous_fee_bps, updates[0].new_fee_bps), (50, 100));
+}
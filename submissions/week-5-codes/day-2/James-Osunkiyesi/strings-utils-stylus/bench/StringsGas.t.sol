@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {Test, console} from "forge-std/Test.sol";
+import {Strings} from "@openzeppelin/contracts/utils/Strings.sol";
+
+/// Gas used by OpenZeppelin's Strings for the inputs of benches/conversions.rs, printed as CSV.
+///
+///   forge test --match-contract StringsGasTest -vv | grep -E '^ +[a-z_]+,' | tr -d ' ' > ../solidity-gas.csv
+///
+/// Run from `bench/`, whose foundry.toml sets up forge-std and OpenZeppelin.
+contract StringsGasTest is Test {
+    function test_gas() public view {
+        string[4] memory names = ["0", "small", "2^128", "max"];
+        uint256[4] memory values = [uint256(0), 42, 1 << 128, type(uint256).max];
+
+        console.log("function,input,gas");
+        for (uint256 i = 0; i < values.length; i++) {
+            uint256 value = values[i];
+
+            uint256 start = gasleft();
+            Strings.toString(value);
+            _log("to_string", names[i], start - gasleft());
+
+            start = gasleft();
+            Strings.toHexString(value);
+            _log("to_hex_string", names[i], start - gasleft());
+
+            start = gasleft();
+            Strings.toHexString(value, 32);
+            _log("to_hex_string_with_length", names[i], start - gasleft());
+        }
+    }
+
+    function _log(string memory function_, string memory input, uint256 gas) private pure {
+        console.log(string.concat(function_, ",", input, ",", Strings.toString(gas)));
+    }
+}
@@ -0,0 +1,51 @@
+#!/bin/bash
+
+# Ink used by each conversion on a Stylus node, printed as CSV for benches/conversions.rs
+#
+#   ./bench/ink.sh > stylus-ink.csv
+#
+# Deploys the `InkBench` contract (the `ink-bench` feature) and calls `measure` for every
+# function, implementation and input. The overhead of an empty measurement is subtracted.
+# Defaults to a local nitro-devnode and its prefunded key.
+
+set -e
+
+RPC_URL="${RPC_URL:-http://localhost:8547}"
+PRIVATE_KEY="${PRIVATE_KEY:-0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659}"
+
+# Same inputs as benches/conversions.rs
+INPUTS=(
+    "0:0"
+    "small:42"
+    "2^128:340282366920938463463374607431768211456"
+    "max:115792089237316195423570985008687907853269984665640564039457584007913129639935"
+)
+FUNCTIONS=("to_string" "to_hex_string" "to_hex_string_with_length")
+
+cd "$(dirname "$0")/.."
+ADDRESS=$(cargo stylus deploy \
+    --features=ink-bench \
+    --no-verify \
+    --private-key="$PRIVATE_KEY" \
+    --endpoint="$RPC_URL" 2>&1 |
+    sed 's/\x1b\[[0-9;]*m//g' |
+    grep -oE 'deployed code at address:? *0x[0-9a-fA-F]{40}' |
+    grep -oE '0x[0-9a-fA-F]{40}')
+
+measure() {
+    cast call "$ADDRESS" "measure(uint8,bool,uint256)(uint64)" "$1" "$2" "$3" --rpc-url "$RPC_URL" |
+        awk '{print $1}'
+}
+
+OVERHEAD=$(measure 255 false 0)
+
+echo "function,implementation,input,ink"
+for index in "${!FUNCTIONS[@]}"; do
+    for implementation in reference optimized; do
+        optimized=$([ "$implementation" = optimized ] && echo true || echo false)
+        for input in "${INPUTS[@]}"; do
+            ink=$(measure "$index" "$optimized" "${input#*:}")
+            echo "${FUNCTIONS[$index]},$implementation,${input%%:*},$((ink - OVERHEAD))"
+        done
+    done
+done
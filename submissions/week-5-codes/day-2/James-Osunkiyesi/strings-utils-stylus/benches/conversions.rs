@@ -0,0 +1,140 @@
+//! Cost of each student's integer conversions, next to OpenZeppelin's `Strings.sol`.
+//!
+//! ```text
+//! cargo bench --bench conversions
+//! STYLUS_INK=stylus-ink.csv SOLIDITY_GAS=solidity-gas.csv cargo bench --bench conversions
+//! ```
+//!
+//! The stylus-test `TestVM` does not meter ink, so ink can't be measured here and the ns/call
+//! columns are native timings instead. They rank the algorithms against each other but are not
+//! ink figures. Every implementation is checked to give the same output as `reference` before it
+//! is timed.
+//!
+//! The ink columns are read from the CSV written by `bench/ink.sh`, which measures the crate's
+//! own implementations in a deployed contract, and the gas column from the CSV written by
+//! `bench/StringsGas.t.sol`. They show `-` when the variable is not set.
+
+use std::{collections::HashMap, env, fs, hint::black_box, time::Instant};
+
+use alloy_primitives::U256;
+use strings_utils_stylus::{optimized, reference};
+use stylus_string_utils::Strings;
+
+type Implementation = (&'static str, fn(U256) -> String);
+
+fn inputs() -> [(&'static str, U256); 4] {
+    [
+        ("0", U256::ZERO),
+        ("small", U256::from(42)),
+        ("2^128", U256::from(1) << 128),
+        ("max", U256::MAX),
+    ]
+}
+
+// Median time of one call in nanoseconds, over 10 batches of as many calls as
+// fit in a 5ms warmup
+fn measure(f: impl Fn() -> String) -> f64 {
+    let start = Instant::now();
+    let mut calls = 0u32;
+    while start.elapsed().as_millis() < 5 {
+        black_box(f());
+        calls += 1;
+    }
+
+    let mut samples: Vec<f64> = (0..10)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..calls {
+                black_box(f());
+            }
+            start.elapsed().as_nanos() as f64 / calls as f64
+        })
+        .collect();
+    samples.sort_by(f64::total_cmp);
+    samples[samples.len() / 2]
+}
+
+// Last column of each line of the CSV at `$variable`, keyed by the other columns
+fn read_csv(variable: &str) -> HashMap<Vec<String>, u64> {
+    let Ok(path) = env::var(variable) else {
+        return HashMap::new();
+    };
+    let csv = fs::read_to_string(&path).unwrap_or_else(|err| panic!("can't read {path}: {err}"));
+    csv.lines()
+        .filter_map(|line| {
+            let mut fields: Vec<String> = line.trim().split(',').map(str::to_string).collect();
+            let cost = fields.pop()?.parse().ok()?;
+            Some((fields, cost))
+        })
+        .collect()
+}
+
+fn cell(costs: &HashMap<Vec<String>, u64>, key: &[&str]) -> String {
+    let key: Vec<String> = key.iter().map(|field| field.to_string()).collect();
+    costs.get(&key).map_or("-".to_string(), u64::to_string)
+}
+
+// One Markdown table with a column per implementation, the first being the one checked against,
+// then the ink of `reference` and `optimized` and the gas of `Strings.sol`
+fn print_table(function: &str, implementations: &[Implementation]) {
+    let ink = read_csv("STYLUS_INK");
+    let gas = read_csv("SOLIDITY_GAS");
+
+    let names: Vec<String> = implementations
+        .iter()
+        .map(|(name, _)| format!("{name} (ns)"))
+        .collect();
+    println!("`{function}`:\n");
+    println!(
+        "| Input | {} | reference (ink) | optimized (ink) | Strings.sol (gas) |",
+        names.join(" | ")
+    );
+    println!("|-------|{}", "---:|".repeat(names.len() + 3));
+
+    let (_, expected) = implementations[0];
+    for (input, value) in inputs() {
+        let value = black_box(value);
+        let mut cells: Vec<String> = implementations
+            .iter()
+            .map(|(name, f)| {
+                assert_eq!(f(value), expected(value), "{name} differs for {input}");
+                format!("{:.1}", measure(|| f(value)))
+            })
+            .collect();
+        cells.push(cell(&ink, &[function, "reference", input]));
+        cells.push(cell(&ink, &[function, "optimized", input]));
+        cells.push(cell(&gas, &[function, input]));
+        println!("| {input} | {} |", cells.join(" | "));
+    }
+    println!();
+}
+
+fn main() {
+    print_table(
+        "to_string",
+        &[
+            ("reference", reference::to_string),
+            ("optimized", optimized::to_string),
+            ("string-utils", string_utils::to_string),
+            ("stylus-string-utils", Strings::to_string),
+        ],
+    );
+    print_table(
+        "to_hex_string",
+        &[
+            ("reference", reference::to_hex_string),
+            ("optimized", optimized::to_hex_string),
+        ],
+    );
+    print_table(
+        "to_hex_string_with_length",
+        &[
+            ("reference", |value| {
+                reference::to_hex_string_with_length(value, 32).unwrap()
+            }),
+            ("optimized", |value| {
+                optimized::to_hex_string_with_length(value, 32).unwrap()
+            }),
+        ],
+    );
+}
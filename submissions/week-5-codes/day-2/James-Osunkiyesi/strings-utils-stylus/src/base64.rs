@@ -0,0 +1,186 @@
+//! # Base64
+//!
+//! A Rust implementation of OpenZeppelin's `Base64.sol` library, plus the matching decoders.
+//! Used to embed JSON and SVG in `data:` URIs for fully on-chain token metadata.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::StringsError;
+
+/// Standard alphabet from RFC 4648, section 4
+const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// URL and filename safe alphabet from RFC 4648, section 5
+const TABLE_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Converts bytes to their Base64 string representation.
+///
+/// This function replicates OpenZeppelin's `Base64.encode(bytes)` function. The output uses the
+/// standard alphabet and is padded with "=" to a multiple of 4 characters.
+pub fn encode(data: &[u8]) -> String {
+    encode_with(data, TABLE, true)
+}
+
+/// Converts bytes to their Base64Url string representation.
+///
+/// This function replicates OpenZeppelin's `Base64.encodeURL(bytes)` function. The output uses
+/// the URL safe alphabet and has no padding.
+pub fn encode_url(data: &[u8]) -> String {
+    encode_with(data, TABLE_URL, false)
+}
+
+/// Converts a padded Base64 string, as produced by `encode`, back to bytes.
+///
+/// Only canonical encodings are accepted: the padding must be present and the unused bits of the
+/// last character must be zero.
+pub fn decode(input: &str) -> Result<Vec<u8>, StringsError> {
+    let bytes = input.as_bytes();
+    if bytes.len() % 4 != 0 {
+        return Err(StringsError::InvalidBase64Length {
+            length: bytes.len(),
+        });
+    }
+
+    // At most two "=" at the end, any other "=" is an invalid character
+    let padding = bytes
+        .iter()
+        .rev()
+        .take(2)
+        .take_while(|&&chr| chr == b'=')
+        .count();
+    decode_with(&bytes[..bytes.len() - padding], false)
+}
+
+/// Converts an unpadded Base64Url string, as produced by `encode_url`, back to bytes.
+///
+/// Only canonical encodings are accepted: padding is not allowed and the unused bits of the last
+/// character must be zero.
+pub fn decode_url(input: &str) -> Result<Vec<u8>, StringsError> {
+    decode_with(input.as_bytes(), true)
+}
+
+fn encode_with(data: &[u8], table: &[u8; 64], padding: bool) -> String {
+    let mut result = Vec::with_capacity(4 * data.len().div_ceil(3));
+
+    // Every 3 bytes become 4 characters of 6 bits each
+    for chunk in data.chunks(3) {
+        let mut group = 0u32;
+        for (i, &byte) in chunk.iter().enumerate() {
+            group |= (byte as u32) << (16 - 8 * i);
+        }
+
+        // A partial chunk of n bytes only needs n + 1 characters
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(table[((group >> (18 - 6 * i)) & 0x3f) as usize]);
+            } else if padding {
+                result.push(b'=');
+            }
+        }
+    }
+
+    String::from_utf8(result).expect("Invalid UTF-8 from Base64 table")
+}
+
+fn decode_with(data: &[u8], url: bool) -> Result<Vec<u8>, StringsError> {
+    // A single character can't hold a whole byte
+    if data.len() % 4 == 1 {
+        return Err(StringsError::InvalidBase64Length { length: data.len() });
+    }
+
+    let mut result = Vec::with_capacity(data.len() * 3 / 4);
+    for (chunk_index, chunk) in data.chunks(4).enumerate() {
+        let mut group = 0u32;
+        for (i, &chr) in chunk.iter().enumerate() {
+            let index = 4 * chunk_index + i;
+            let value = decode_symbol(chr, url).ok_or(StringsError::InvalidChar { index })?;
+            group |= (value as u32) << (18 - 6 * i);
+        }
+
+        // A chunk of n characters holds n - 1 bytes, the remaining bits must be zero
+        let bytes = group.to_be_bytes();
+        let length = chunk.len() - 1;
+        if bytes[1 + length..].iter().any(|&byte| byte != 0) {
+            let index = 4 * chunk_index + length;
+            return Err(StringsError::InvalidChar { index });
+        }
+        result.extend_from_slice(&bytes[1..1 + length]);
+    }
+
+    Ok(result)
+}
+
+/// Value of a single Base64 character in the standard or URL safe alphabet.
+fn decode_symbol(chr: u8, url: bool) -> Option<u8> {
+    match chr {
+        b'A'..=b'Z' => Some(chr - b'A'),
+        b'a'..=b'z' => Some(chr - b'a' + 26),
+        b'0'..=b'9' => Some(chr - b'0' + 52),
+        b'+' if !url => Some(62),
+        b'/' if !url => Some(63),
+        b'-' if url => Some(62),
+        b'_' if url => Some(63),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (input, expected) in vectors {
+            assert_eq!(encode(input.as_bytes()), expected);
+            assert_eq!(decode(expected).unwrap(), input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_encode_url() {
+        assert_eq!(encode(&[0xfb, 0xff]), "+/8=");
+        assert_eq!(encode_url(&[0xfb, 0xff]), "-_8");
+        assert_eq!(decode_url("-_8").unwrap(), vec![0xfb, 0xff]);
+        assert_eq!(encode_url(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert_eq!(
+            decode("Zg="),
+            Err(StringsError::InvalidBase64Length { length: 3 })
+        );
+        assert_eq!(
+            decode("Zm9vY"),
+            Err(StringsError::InvalidBase64Length { length: 5 })
+        );
+        assert_eq!(
+            decode("Zm9v!mFy"),
+            Err(StringsError::InvalidChar { index: 4 })
+        );
+        assert_eq!(decode("Z==="), Err(StringsError::InvalidChar { index: 1 }));
+        assert_eq!(decode("Zm=v"), Err(StringsError::InvalidChar { index: 2 }));
+        // Unused bits of the last character are set
+        assert_eq!(decode("Zh=="), Err(StringsError::InvalidChar { index: 1 }));
+
+        // Each decoder only accepts its own alphabet and padding
+        assert_eq!(decode("-_8="), Err(StringsError::InvalidChar { index: 0 }));
+        assert_eq!(
+            decode_url("+/8"),
+            Err(StringsError::InvalidChar { index: 0 })
+        );
+        assert_eq!(
+            decode_url("Zg=="),
+            Err(StringsError::InvalidChar { index: 2 })
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! # Ink benchmark contract
+//!
+//! Entrypoint used by `bench/ink.sh` to measure the ink each conversion costs on a Stylus node.
+//! Only compiled with the `ink-bench` feature, so contracts depending on this crate don't get
+//! an entrypoint.
+
+use alloy_primitives::U256;
+use core::hint::black_box;
+use stylus_sdk::{prelude::*, stylus_core::host::MeteringAccess};
+
+use crate::{optimized, reference};
+
+sol_storage! {
+    #[entrypoint]
+    pub struct InkBench {}
+}
+
+#[public]
+impl InkBench {
+    /// Ink used by one call of `function` on `value`
+    ///
+    /// `function` is 0 for `to_string`, 1 for `to_hex_string` and 2 for
+    /// `to_hex_string_with_length(value, 32)`. Any other value measures an empty call, which is
+    /// the overhead included in the other figures.
+    pub fn measure(&self, function: u8, optimized: bool, value: U256) -> u64 {
+        let value = black_box(value);
+        let start = self.vm().evm_ink_left();
+        match (function, optimized) {
+            (0, false) => drop(black_box(reference::to_string(value))),
+            (0, true) => drop(black_box(optimized::to_string(value))),
+            (1, false) => drop(black_box(reference::to_hex_string(value))),
+            (1, true) => drop(black_box(optimized::to_hex_string(value))),
+            (2, false) => drop(black_box(reference::to_hex_string_with_length(value, 32))),
+            (2, true) => drop(black_box(optimized::to_hex_string_with_length(value, 32))),
+            _ => {}
+        }
+        start - self.vm().evm_ink_left()
+    }
+}
@@ -0,0 +1,162 @@
+//! # JSON
+//!
+//! Escaping and a minimal object builder for on-chain token metadata, returned from `uri` or
+//! `tokenURI` as a `data:application/json;base64,...` URI.
+
+use alloc::{format, string::String};
+use alloy_primitives::U256;
+
+use crate::{base64, to_string, HEX_DIGITS};
+
+/// Escapes a string so it can be placed between double quotes in a JSON document.
+///
+/// Similar to OpenZeppelin's `escapeJSON(string)` function, but every control character below
+/// 0x20 is escaped as the JSON spec requires, not only `\b`, `\t`, `\n`, `\f` and `\r`.
+pub fn escape_json(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+
+    for chr in input.chars() {
+        match chr {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\u{8}' => result.push_str("\\b"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            '\u{c}' => result.push_str("\\f"),
+            '\r' => result.push_str("\\r"),
+            chr if (chr as u32) < 0x20 => {
+                result.push_str("\\u00");
+                result.push(HEX_DIGITS[(chr as usize) >> 4] as char);
+                result.push(HEX_DIGITS[(chr as usize) & 0xf] as char);
+            }
+            chr => result.push(chr),
+        }
+    }
+
+    result
+}
+
+/// Builds a `data:` URI holding `data` Base64 encoded, e.g. `data:image/svg+xml;base64,...`.
+pub fn data_uri(media_type: &str, data: &[u8]) -> String {
+    format!("data:{media_type};base64,{}", base64::encode(data))
+}
+
+/// Builder for a flat JSON object, such as ERC-721 or ERC-6909 token metadata.
+///
+/// Keys and string values are escaped, so they can come from user input:
+///
+/// ```
+/// # use alloy_primitives::U256;
+/// # use strings_utils_stylus::JsonBuilder;
+/// let json = JsonBuilder::new()
+///     .string("name", "Sword #7")
+///     .number("level", U256::from(3))
+///     .raw("attributes", "[]")
+///     .build();
+/// assert_eq!(json, r#"{"name":"Sword #7","level":3,"attributes":[]}"#);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct JsonBuilder {
+    fields: String,
+}
+
+impl JsonBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a string value.
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.push_key(key);
+        self.fields.push('"');
+        self.fields.push_str(&escape_json(value));
+        self.fields.push('"');
+        self
+    }
+
+    /// Adds a number value, written in decimal.
+    pub fn number(mut self, key: &str, value: U256) -> Self {
+        self.push_key(key);
+        self.fields.push_str(&to_string(value));
+        self
+    }
+
+    /// Adds a boolean value.
+    pub fn bool(mut self, key: &str, value: bool) -> Self {
+        self.push_key(key);
+        self.fields.push_str(if value { "true" } else { "false" });
+        self
+    }
+
+    /// Adds a value that is already JSON, e.g. an array or a nested object. It is not escaped.
+    pub fn raw(mut self, key: &str, json: &str) -> Self {
+        self.push_key(key);
+        self.fields.push_str(json);
+        self
+    }
+
+    /// Returns the JSON object.
+    pub fn build(self) -> String {
+        format!("{{{}}}", self.fields)
+    }
+
+    /// Returns the JSON object as a `data:application/json;base64,...` URI.
+    pub fn build_data_uri(self) -> String {
+        data_uri("application/json", self.build().as_bytes())
+    }
+
+    fn push_key(&mut self, key: &str) {
+        if !self.fields.is_empty() {
+            self.fields.push(',');
+        }
+        self.fields.push('"');
+        self.fields.push_str(&escape_json(key));
+        self.fields.push_str("\":");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_json() {
+        assert_eq!(escape_json("plain text"), "plain text");
+        assert_eq!(escape_json(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape_json(r"C:\path"), r"C:\\path");
+        assert_eq!(escape_json("a\nb\tc\r"), r"a\nb\tc\r");
+        assert_eq!(escape_json("\u{8}\u{c}"), r"\b\f");
+        assert_eq!(escape_json("\u{0}\u{1f}"), r"\u0000\u001f");
+        // Non-ASCII and characters above the control range pass through
+        assert_eq!(escape_json("héllo / ✓ \u{7f}"), "héllo / ✓ \u{7f}");
+    }
+
+    #[test]
+    fn test_builder() {
+        assert_eq!(JsonBuilder::new().build(), "{}");
+
+        let json = JsonBuilder::new()
+            .string("name", "Gold \"Bar\"")
+            .string("description", "line1\nline2")
+            .number("decimals", U256::from(18))
+            .bool("soulbound", false)
+            .raw("attributes", r#"[{"trait_type":"Weight","value":1}]"#)
+            .build();
+        assert_eq!(
+            json,
+            r#"{"name":"Gold \"Bar\"","description":"line1\nline2","decimals":18,"soulbound":false,"attributes":[{"trait_type":"Weight","value":1}]}"#
+        );
+    }
+
+    #[test]
+    fn test_data_uri() {
+        assert_eq!(
+            JsonBuilder::new().string("name", "A").build_data_uri(),
+            "data:application/json;base64,eyJuYW1lIjoiQSJ9"
+        );
+        assert_eq!(
+            data_uri("image/svg+xml", b"<svg/>"),
+            "data:image/svg+xml;base64,PHN2Zy8+"
+        );
+    }
+}
@@ -3,8 +3,33 @@
 //! A Rust implementation of OpenZeppelin's `Strings.sol` library for Arbitrum Stylus.
 //! Provides utility functions for converting various types to strings, matching the exact
 //! behavior of OpenZeppelin's implementation.
+//!
+//! The library is `no_std` and only needs `alloc`, so it can be used inside Stylus contracts.
 
-use alloy_primitives::{Address, I256, U256};
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+pub mod base64;
+#[cfg(feature = "ink-bench")]
+pub mod ink;
+pub mod json;
+pub mod optimized;
+pub mod reference;
+pub mod units;
+
+use alloc::{format, string::String, vec::Vec};
+use alloy_primitives::{Address, Sign, I256, U256};
+
+pub use json::{data_uri, escape_json, JsonBuilder};
+#[cfg(feature = "optimized")]
+pub use optimized::{to_hex_string, to_hex_string_with_length, to_string};
+#[cfg(not(feature = "optimized"))]
+pub use reference::{to_hex_string, to_hex_string_with_length, to_string};
+pub use units::{
+    format_units, format_units_signed, parse_units, parse_units_signed, try_parse_units,
+    try_parse_units_signed, Rounding, UnitsFormat,
+};
 
 /// Hex digits constant used for hex string conversion
 const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
@@ -13,42 +38,22 @@ const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
 const ADDRESS_LENGTH: usize = 20;
 
 /// Error types matching OpenZeppelin's behavior
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum StringsError {
     /// Hex length insufficient for the given value
     InsufficientHexLength { value: U256, length: usize },
-}
-
-/// Converts a U256 value to its ASCII decimal string representation.
-/// 
-/// This function replicates OpenZeppelin's `toString(uint256)` function exactly.
-/// It uses the same algorithm: count digits, allocate buffer, fill backwards.
-pub fn to_string(value: U256) -> String {
-    // Handle zero case first (matching OpenZeppelin)
-    if value.is_zero() {
-        return "0".to_string();
-    }
-    
-    // Count digits first
-    let mut temp = value;
-    let mut digits = 0;
-    while !temp.is_zero() {
-        digits += 1;
-        temp /= U256::from(10);
-    }
-    
-    // Create buffer
-    let mut buffer = vec![0u8; digits];
-    let mut remaining = value;
-    
-    // Fill buffer backwards (matching Solidity implementation)
-    while !remaining.is_zero() {
-        digits -= 1;
-        buffer[digits] = 48 + (remaining % U256::from(10)).to::<u8>();
-        remaining /= U256::from(10);
-    }
-    
-    String::from_utf8(buffer).expect("Invalid UTF-8 from digits")
+    /// Character at `index` is not a valid digit
+    InvalidChar { index: usize },
+    /// String is not 40 hex digits with an optional "0x" prefix
+    InvalidAddressFormat,
+    /// Parsed value does not fit in the result type
+    Overflow,
+    /// `begin..end` is not a range within the string
+    InvalidRange { begin: usize, end: usize },
+    /// Base64 string has a length no encoding produces
+    InvalidBase64Length { length: usize },
+    /// Amount has non-zero digits beyond its `decimals` decimals
+    TooManyDecimals { decimals: u8 },
 }
 
 /// Converts an I256 (signed integer) to its ASCII decimal string representation.
@@ -63,53 +68,6 @@ pub fn to_string_signed(value: I256) -> String {
     }
 }
 
-/// Converts a U256 value to its ASCII hexadecimal string representation.
-/// 
-/// This function replicates OpenZeppelin's `toHexString(uint256)` function.
-/// It returns "0x00" for zero and uses variable length for other values.
-pub fn to_hex_string(value: U256) -> String {
-    if value.is_zero() {
-        return "0x00".to_string();
-    }
-    
-    // Calculate required length in bytes (each byte = 2 hex chars)
-    let mut temp = value;
-    let mut length = 0;
-    while !temp.is_zero() {
-        length += 1;
-        temp >>= 8;
-    }
-    
-    // Use the fixed-length version with calculated length
-    to_hex_string_with_length(value, length).unwrap()
-}
-
-/// Converts a U256 value to its ASCII hexadecimal string representation with fixed length.
-/// 
-/// This function replicates OpenZeppelin's `toHexString(uint256, uint256)` function.
-/// It creates a fixed-length hex string, padding with zeros or returning error if insufficient.
-pub fn to_hex_string_with_length(value: U256, length: usize) -> Result<String, StringsError> {
-    let mut local_value = value;
-    let hex_length = 2 * length;
-    let mut buffer = vec![0u8; hex_length + 2]; // +2 for "0x"
-    
-    buffer[0] = b'0';
-    buffer[1] = b'x';
-    
-    // Fill buffer from right to left (matching Solidity implementation)
-    for i in (2..hex_length + 2).rev() {
-        buffer[i] = HEX_DIGITS[(local_value & U256::from(0xf)).to::<usize>()];
-        local_value >>= 4;
-    }
-    
-    // Check if value was too large for the specified length
-    if !local_value.is_zero() {
-        return Err(StringsError::InsufficientHexLength { value, length });
-    }
-    
-    Ok(String::from_utf8(buffer).expect("Invalid UTF-8 from hex digits"))
-}
-
 /// Converts an Address to its ASCII hexadecimal string representation (not checksummed).
 /// 
 /// This function replicates OpenZeppelin's `toHexString(address)` function.
@@ -130,7 +88,6 @@ pub fn address_to_hex_string(addr: Address) -> String {
 
 /// This function replicates OpenZeppelin's `toChecksumHexString(address)` function.
 /// It implements EIP-55 checksumming by capitalizing hex digits based on the keccak256 hash.
-
 pub fn address_to_checksum_hex_string(addr: Address) -> String {
     // Start with the non-checksummed hex string
     let hex_string = address_to_hex_string(addr);
@@ -159,6 +116,184 @@ pub fn address_to_checksum_hex_string(addr: Address) -> String {
     String::from_utf8(buffer).expect("Invalid UTF-8 from checksum conversion")
 }
 
+/// Parses a decimal string into a U256.
+///
+/// This function replicates OpenZeppelin's `parseUint(string)` function. Only the digits 0-9
+/// are accepted, and an empty string parses to zero.
+///
+/// Panics if the string is not a valid number, use `try_parse_uint` to get an error instead.
+pub fn parse_uint(input: &str) -> U256 {
+    parse_uint_range(input, 0, input.len())
+}
+
+/// Parses the bytes `begin..end` of a decimal string into a U256.
+///
+/// This function replicates OpenZeppelin's `parseUint(string, uint256, uint256)` function.
+pub fn parse_uint_range(input: &str, begin: usize, end: usize) -> U256 {
+    try_parse_uint_range(input, begin, end).expect("Invalid decimal string")
+}
+
+/// Variant of `parse_uint` that returns an error instead of panicking.
+pub fn try_parse_uint(input: &str) -> Result<U256, StringsError> {
+    try_parse_uint_range(input, 0, input.len())
+}
+
+/// Variant of `parse_uint_range` that returns an error instead of panicking.
+pub fn try_parse_uint_range(input: &str, begin: usize, end: usize) -> Result<U256, StringsError> {
+    let buffer = checked_range(input, begin, end)?;
+    parse_digits(buffer, begin, end, 10)
+}
+
+/// Parses a decimal string with an optional "+" or "-" sign into an I256.
+///
+/// This function replicates OpenZeppelin's `parseInt(string)` function. A sign with no digits
+/// parses to zero, and the value must fit in an I256.
+///
+/// Panics if the string is not a valid number, use `try_parse_int` to get an error instead.
+pub fn parse_int(input: &str) -> I256 {
+    parse_int_range(input, 0, input.len())
+}
+
+/// Parses the bytes `begin..end` of a signed decimal string into an I256.
+///
+/// This function replicates OpenZeppelin's `parseInt(string, uint256, uint256)` function.
+pub fn parse_int_range(input: &str, begin: usize, end: usize) -> I256 {
+    try_parse_int_range(input, begin, end).expect("Invalid decimal string")
+}
+
+/// Variant of `parse_int` that returns an error instead of panicking.
+pub fn try_parse_int(input: &str) -> Result<I256, StringsError> {
+    try_parse_int_range(input, 0, input.len())
+}
+
+/// Variant of `parse_int_range` that returns an error instead of panicking.
+pub fn try_parse_int_range(input: &str, begin: usize, end: usize) -> Result<I256, StringsError> {
+    let buffer = checked_range(input, begin, end)?;
+
+    let (sign, digits_begin) = match buffer.get(begin) {
+        Some(b'-') if begin < end => (Sign::Negative, begin + 1),
+        Some(b'+') if begin < end => (Sign::Positive, begin + 1),
+        _ => (Sign::Positive, begin),
+    };
+    let abs = parse_digits(buffer, digits_begin, end, 10)?;
+
+    // The magnitude of I256::MIN is only valid with a minus sign
+    I256::checked_from_sign_and_abs(sign, abs).ok_or(StringsError::Overflow)
+}
+
+/// Parses a hexadecimal string with an optional "0x" prefix into a U256.
+///
+/// This function replicates OpenZeppelin's `parseHexUint(string)` function. Both upper and
+/// lowercase digits are accepted, and an empty string or a bare "0x" parses to zero.
+///
+/// Panics if the string is not a valid number, use `try_parse_hex_uint` to get an error instead.
+pub fn parse_hex_uint(input: &str) -> U256 {
+    parse_hex_uint_range(input, 0, input.len())
+}
+
+/// Parses the bytes `begin..end` of a hexadecimal string into a U256.
+///
+/// This function replicates OpenZeppelin's `parseHexUint(string, uint256, uint256)` function.
+pub fn parse_hex_uint_range(input: &str, begin: usize, end: usize) -> U256 {
+    try_parse_hex_uint_range(input, begin, end).expect("Invalid hexadecimal string")
+}
+
+/// Variant of `parse_hex_uint` that returns an error instead of panicking.
+pub fn try_parse_hex_uint(input: &str) -> Result<U256, StringsError> {
+    try_parse_hex_uint_range(input, 0, input.len())
+}
+
+/// Variant of `parse_hex_uint_range` that returns an error instead of panicking.
+pub fn try_parse_hex_uint_range(
+    input: &str,
+    begin: usize,
+    end: usize,
+) -> Result<U256, StringsError> {
+    let buffer = checked_range(input, begin, end)?;
+    let digits_begin = if has_hex_prefix(buffer, begin, end) { begin + 2 } else { begin };
+    parse_digits(buffer, digits_begin, end, 16)
+}
+
+/// Parses a hexadecimal address string into an Address.
+///
+/// This function replicates OpenZeppelin's `parseAddress(string)` function. The string must be
+/// exactly 40 hex digits with an optional "0x" prefix. The EIP-55 checksum is not verified.
+///
+/// Panics if the string is not a valid address, use `try_parse_address` to get an error instead.
+pub fn parse_address(input: &str) -> Address {
+    parse_address_range(input, 0, input.len())
+}
+
+/// Parses the bytes `begin..end` of a string into an Address.
+///
+/// This function replicates OpenZeppelin's `parseAddress(string, uint256, uint256)` function.
+pub fn parse_address_range(input: &str, begin: usize, end: usize) -> Address {
+    try_parse_address_range(input, begin, end).expect("Invalid address string")
+}
+
+/// Variant of `parse_address` that returns an error instead of panicking.
+pub fn try_parse_address(input: &str) -> Result<Address, StringsError> {
+    try_parse_address_range(input, 0, input.len())
+}
+
+/// Variant of `parse_address_range` that returns an error instead of panicking.
+pub fn try_parse_address_range(
+    input: &str,
+    begin: usize,
+    end: usize,
+) -> Result<Address, StringsError> {
+    let buffer = checked_range(input, begin, end)?;
+
+    let prefix_length = if has_hex_prefix(buffer, begin, end) { 2 } else { 0 };
+    if end - begin != prefix_length + 2 * ADDRESS_LENGTH {
+        return Err(StringsError::InvalidAddressFormat);
+    }
+
+    // 40 hex digits always fit, so the only possible error is an invalid character
+    let value = parse_digits(buffer, begin + prefix_length, end, 16)
+        .map_err(|_| StringsError::InvalidAddressFormat)?;
+    Ok(Address::from_slice(&value.to_be_bytes::<32>()[32 - ADDRESS_LENGTH..]))
+}
+
+/// Returns the bytes of `input` if `begin..end` is a range within it.
+fn checked_range(input: &str, begin: usize, end: usize) -> Result<&[u8], StringsError> {
+    if begin > end || end > input.len() {
+        return Err(StringsError::InvalidRange { begin, end });
+    }
+    Ok(input.as_bytes())
+}
+
+/// Whether `buffer[begin..end]` starts with "0x" (lowercase only, as in OpenZeppelin).
+fn has_hex_prefix(buffer: &[u8], begin: usize, end: usize) -> bool {
+    end > begin + 1 && &buffer[begin..begin + 2] == b"0x"
+}
+
+/// Parses `buffer[begin..end]` as digits in `base` (10 or 16), most significant first.
+fn parse_digits(buffer: &[u8], begin: usize, end: usize, base: u8) -> Result<U256, StringsError> {
+    let mut result = U256::ZERO;
+    for (index, &chr) in buffer.iter().enumerate().take(end).skip(begin) {
+        let digit = parse_chr(chr);
+        if digit >= base {
+            return Err(StringsError::InvalidChar { index });
+        }
+        result = result
+            .checked_mul(U256::from(base))
+            .and_then(|value| value.checked_add(U256::from(digit)))
+            .ok_or(StringsError::Overflow)?;
+    }
+    Ok(result)
+}
+
+/// Value of a single hex digit, or 255 if `chr` is not one (matching OpenZeppelin's `_tryParseChr`).
+fn parse_chr(chr: u8) -> u8 {
+    match chr {
+        b'0'..=b'9' => chr - b'0',
+        b'a'..=b'f' => chr - b'a' + 10,
+        b'A'..=b'F' => chr - b'A' + 10,
+        _ => 255,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,4 +420,127 @@ mod tests {
             assert_eq!(to_hex_string(value), expected_hex);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_uint() {
+        assert_eq!(parse_uint("0"), U256::ZERO);
+        assert_eq!(parse_uint("12345"), U256::from(12345));
+        assert_eq!(parse_uint("007"), U256::from(7));
+        assert_eq!(parse_uint(""), U256::ZERO);
+        assert_eq!(parse_uint(&U256::MAX.to_string()), U256::MAX);
+
+        assert_eq!(try_parse_uint("12a4"), Err(StringsError::InvalidChar { index: 2 }));
+        assert_eq!(try_parse_uint("-1"), Err(StringsError::InvalidChar { index: 0 }));
+        assert_eq!(try_parse_uint(" 1"), Err(StringsError::InvalidChar { index: 0 }));
+        assert_eq!(try_parse_uint(&format!("{}0", U256::MAX)), Err(StringsError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid decimal string")]
+    fn test_parse_uint_invalid_panics() {
+        parse_uint("1.5");
+    }
+
+    #[test]
+    fn test_parse_uint_range() {
+        assert_eq!(parse_uint_range("amount=250;", 7, 10), U256::from(250));
+        assert_eq!(parse_uint_range("abc", 1, 1), U256::ZERO);
+        assert_eq!(
+            try_parse_uint_range("123", 2, 4),
+            Err(StringsError::InvalidRange { begin: 2, end: 4 })
+        );
+        assert_eq!(
+            try_parse_uint_range("123", 2, 1),
+            Err(StringsError::InvalidRange { begin: 2, end: 1 })
+        );
+        // Error indexes are positions in the whole string
+        assert_eq!(try_parse_uint_range("x=1y", 2, 4), Err(StringsError::InvalidChar { index: 3 }));
+    }
+
+    #[test]
+    fn test_parse_int() {
+        assert_eq!(parse_int("42"), I256::try_from(42).unwrap());
+        assert_eq!(parse_int("+42"), I256::try_from(42).unwrap());
+        assert_eq!(parse_int("-42"), I256::try_from(-42).unwrap());
+        assert_eq!(parse_int("-0"), I256::ZERO);
+        assert_eq!(parse_int("-"), I256::ZERO);
+        assert_eq!(parse_int(&I256::MAX.to_string()), I256::MAX);
+        assert_eq!(parse_int(&I256::MIN.to_string()), I256::MIN);
+
+        // |I256::MIN| only fits with a minus sign
+        let min_abs = I256::MIN.unsigned_abs().to_string();
+        assert_eq!(try_parse_int(&min_abs), Err(StringsError::Overflow));
+        assert_eq!(try_parse_int("--1"), Err(StringsError::InvalidChar { index: 1 }));
+        assert_eq!(try_parse_int("1-"), Err(StringsError::InvalidChar { index: 1 }));
+        assert_eq!(parse_int_range("[-7]", 1, 3), I256::try_from(-7).unwrap());
+    }
+
+    #[test]
+    fn test_parse_int_round_trip() {
+        for value in [i64::MIN, -1_000_000, -1, 0, 1, 999, i64::MAX] {
+            let value = I256::try_from(value).unwrap();
+            assert_eq!(parse_int(&to_string_signed(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_uint() {
+        assert_eq!(parse_hex_uint("0xff"), U256::from(255));
+        assert_eq!(parse_hex_uint("ff"), U256::from(255));
+        assert_eq!(parse_hex_uint("0xDeadBeef"), U256::from(0xdeadbeefu64));
+        assert_eq!(parse_hex_uint("0x"), U256::ZERO);
+        assert_eq!(parse_hex_uint(""), U256::ZERO);
+        assert_eq!(parse_hex_uint(&to_hex_string(U256::MAX)), U256::MAX);
+
+        // Only a lowercase prefix is recognised, as in OpenZeppelin
+        assert_eq!(try_parse_hex_uint("0Xff"), Err(StringsError::InvalidChar { index: 1 }));
+        assert_eq!(try_parse_hex_uint("0xfg"), Err(StringsError::InvalidChar { index: 3 }));
+        let too_large = format!("0x1{}", "0".repeat(64));
+        assert_eq!(try_parse_hex_uint(&too_large), Err(StringsError::Overflow));
+        assert_eq!(parse_hex_uint_range("id:0x2a", 3, 7), U256::from(42));
+    }
+
+    #[test]
+    fn test_parse_hex_uint_round_trip() {
+        for value in [0u64, 1, 15, 16, 255, 256, 65535, u64::MAX] {
+            let value = U256::from(value);
+            assert_eq!(parse_hex_uint(&to_hex_string(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_parse_address() {
+        let addr_bytes = [0x52, 0x90, 0x8e, 0x08, 0x4f, 0x3d, 0x7d, 0xe1,
+                         0xb3, 0x9a, 0x96, 0x30, 0x02, 0x64, 0xbd, 0x2a,
+                         0x47, 0x9e, 0x9c, 0x8f];
+        let addr = Address::from_slice(&addr_bytes);
+
+        assert_eq!(parse_address(&address_to_hex_string(addr)), addr);
+        assert_eq!(parse_address(&address_to_checksum_hex_string(addr)), addr);
+        assert_eq!(parse_address(&address_to_hex_string(addr)[2..]), addr);
+        assert_eq!(parse_address("0x0000000000000000000000000000000000000000"), Address::ZERO);
+
+        let with_text = format!("to {} now", address_to_hex_string(addr));
+        assert_eq!(parse_address_range(&with_text, 3, 45), addr);
+    }
+
+    #[test]
+    fn test_parse_address_invalid() {
+        let hex = "0x52908e084f3d7de1b39a96300264bd2a479e9c8f";
+        assert_eq!(try_parse_address(&hex[..41]), Err(StringsError::InvalidAddressFormat));
+        assert_eq!(try_parse_address(&format!("{hex}0")), Err(StringsError::InvalidAddressFormat));
+        assert_eq!(try_parse_address(&hex.replace('f', "g")), Err(StringsError::InvalidAddressFormat));
+        assert_eq!(try_parse_address(&hex.replace("0x", "0X")), Err(StringsError::InvalidAddressFormat));
+        assert_eq!(try_parse_address(""), Err(StringsError::InvalidAddressFormat));
+        assert_eq!(
+            try_parse_address_range(hex, 0, 43),
+            Err(StringsError::InvalidRange { begin: 0, end: 43 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid address string")]
+    fn test_parse_address_invalid_panics() {
+        parse_address("0x1234");
+    }
+}
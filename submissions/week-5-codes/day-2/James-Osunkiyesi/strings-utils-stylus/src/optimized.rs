@@ -0,0 +1,98 @@
+//! # Optimized conversions
+//!
+//! Drop-in replacements for `to_string`, `to_hex_string` and `to_hex_string_with_length`, which
+//! the crate root re-exports with the `optimized` feature. The [`reference`](crate::reference)
+//! versions follow the Solidity algorithms digit by digit, so every digit costs a 256-bit
+//! division or shift. These do the same work on native 64-bit integers instead, and give the
+//! same output for every input.
+
+use alloc::{string::String, vec, vec::Vec};
+use alloy_primitives::U256;
+
+use crate::{StringsError, HEX_DIGITS};
+
+/// Largest power of ten that fits in a u64, so a U256 is at most 5 chunks of 19 digits
+const CHUNK: u64 = 10_000_000_000_000_000_000;
+const CHUNK_DIGITS: usize = 19;
+
+/// Converts a U256 value to its ASCII decimal string representation.
+///
+/// Same output as OpenZeppelin's `toString(uint256)`. Splits the value into base 10^19 chunks,
+/// which takes at most 4 divisions of the U256, and writes each chunk with u64 arithmetic.
+pub fn to_string(value: U256) -> String {
+    let mut buffer = Vec::with_capacity(5 * CHUNK_DIGITS);
+
+    // Most values in practice fit a u64 and need no U256 division at all
+    if let Ok(small) = u64::try_from(value) {
+        push_digits(&mut buffer, small, 0);
+        return String::from_utf8(buffer).expect("Invalid UTF-8 from digits");
+    }
+
+    // Least significant chunk first
+    let mut chunks = [0u64; 5];
+    let mut count = 0;
+    let mut remaining = value;
+    loop {
+        let (quotient, chunk) = remaining.div_rem(U256::from(CHUNK));
+        chunks[count] = chunk.to::<u64>();
+        count += 1;
+        remaining = quotient;
+        if remaining.is_zero() {
+            break;
+        }
+    }
+
+    // The most significant chunk is not zero padded
+    push_digits(&mut buffer, chunks[count - 1], 0);
+    for &chunk in chunks[..count - 1].iter().rev() {
+        push_digits(&mut buffer, chunk, CHUNK_DIGITS);
+    }
+
+    String::from_utf8(buffer).expect("Invalid UTF-8 from digits")
+}
+
+/// Converts a U256 value to its ASCII hexadecimal string representation.
+///
+/// Same output as OpenZeppelin's `toHexString(uint256)`, including "0x00" for zero.
+pub fn to_hex_string(value: U256) -> String {
+    let length = value.byte_len().max(1);
+    to_hex_string_with_length(value, length).unwrap()
+}
+
+/// Converts a U256 value to its ASCII hexadecimal string representation with fixed length.
+///
+/// Same output as OpenZeppelin's `toHexString(uint256, uint256)`. Reads the nibbles straight from
+/// the big-endian bytes instead of shifting the value.
+pub fn to_hex_string_with_length(value: U256, length: usize) -> Result<String, StringsError> {
+    if value.byte_len() > length {
+        return Err(StringsError::InsufficientHexLength { value, length });
+    }
+
+    let mut buffer = vec![b'0'; 2 * length + 2];
+    buffer[1] = b'x';
+
+    // Bytes beyond the value's own length stay as "00" padding
+    let bytes = value.to_be_bytes::<32>();
+    let used = value.byte_len();
+    let offset = buffer.len() - 2 * used;
+    for (i, &byte) in bytes[32 - used..].iter().enumerate() {
+        buffer[offset + 2 * i] = HEX_DIGITS[(byte >> 4) as usize];
+        buffer[offset + 2 * i + 1] = HEX_DIGITS[(byte & 0xf) as usize];
+    }
+
+    Ok(String::from_utf8(buffer).expect("Invalid UTF-8 from hex digits"))
+}
+
+// Appends the decimal digits of `value`, left padded with zeros to `width`
+fn push_digits(buffer: &mut Vec<u8>, mut value: u64, width: usize) {
+    let mut digits = [b'0'; 20];
+    let mut start = digits.len();
+    while value > 0 {
+        start -= 1;
+        digits[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+
+    let start = start.min(digits.len() - width.max(1));
+    buffer.extend_from_slice(&digits[start..]);
+}
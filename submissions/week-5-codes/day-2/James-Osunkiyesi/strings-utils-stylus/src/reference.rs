@@ -0,0 +1,92 @@
+//! # Reference conversions
+//!
+//! `to_string`, `to_hex_string` and `to_hex_string_with_length` ported from OpenZeppelin's
+//! `Strings.sol`, one digit at a time. The crate root re-exports these unless the `optimized`
+//! feature is enabled.
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+use alloy_primitives::U256;
+
+use crate::{StringsError, HEX_DIGITS};
+
+/// Converts a U256 value to its ASCII decimal string representation.
+/// 
+/// This function replicates OpenZeppelin's `toString(uint256)` function exactly.
+/// It uses the same algorithm: count digits, allocate buffer, fill backwards.
+pub fn to_string(value: U256) -> String {
+    // Handle zero case first (matching OpenZeppelin)
+    if value.is_zero() {
+        return "0".to_string();
+    }
+    
+    // Count digits first
+    let mut temp = value;
+    let mut digits = 0;
+    while !temp.is_zero() {
+        digits += 1;
+        temp /= U256::from(10);
+    }
+    
+    // Create buffer
+    let mut buffer = vec![0u8; digits];
+    let mut remaining = value;
+    
+    // Fill buffer backwards (matching Solidity implementation)
+    while !remaining.is_zero() {
+        digits -= 1;
+        buffer[digits] = 48 + (remaining % U256::from(10)).to::<u8>();
+        remaining /= U256::from(10);
+    }
+    
+    String::from_utf8(buffer).expect("Invalid UTF-8 from digits")
+}
+
+/// Converts a U256 value to its ASCII hexadecimal string representation.
+/// 
+/// This function replicates OpenZeppelin's `toHexString(uint256)` function.
+/// It returns "0x00" for zero and uses variable length for other values.
+pub fn to_hex_string(value: U256) -> String {
+    if value.is_zero() {
+        return "0x00".to_string();
+    }
+    
+    // Calculate required length in bytes (each byte = 2 hex chars)
+    let mut temp = value;
+    let mut length = 0;
+    while !temp.is_zero() {
+        length += 1;
+        temp >>= 8;
+    }
+    
+    // Use the fixed-length version with calculated length
+    to_hex_string_with_length(value, length).unwrap()
+}
+
+/// Converts a U256 value to its ASCII hexadecimal string representation with fixed length.
+/// 
+/// This function replicates OpenZeppelin's `toHexString(uint256, uint256)` function.
+/// It creates a fixed-length hex string, padding with zeros or returning error if insufficient.
+pub fn to_hex_string_with_length(value: U256, length: usize) -> Result<String, StringsError> {
+    let mut local_value = value;
+    let hex_length = 2 * length;
+    let mut buffer = vec![0u8; hex_length + 2]; // +2 for "0x"
+    
+    buffer[0] = b'0';
+    buffer[1] = b'x';
+    
+    // Fill buffer from right to left (matching Solidity implementation)
+    for i in (2..hex_length + 2).rev() {
+        buffer[i] = HEX_DIGITS[(local_value & U256::from(0xf)).to::<usize>()];
+        local_value >>= 4;
+    }
+    
+    // Check if value was too large for the specified length
+    if !local_value.is_zero() {
+        return Err(StringsError::InsufficientHexLength { value, length });
+    }
+    
+    Ok(String::from_utf8(buffer).expect("Invalid UTF-8 from hex digits"))
+}
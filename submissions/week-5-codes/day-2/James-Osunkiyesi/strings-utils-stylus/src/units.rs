@@ -0,0 +1,418 @@
+//! # Units
+//!
+//! Fixed-point formatting and parsing of token amounts, e.g. `1500000000000000000` with 18
+//! decimals is `"1.5"`. All arithmetic is done on decimal digits, so there is no floating point
+//! and no overflow for any number of decimals.
+
+use alloc::{string::String, vec, vec::Vec};
+use alloy_primitives::{Sign, I256, U256};
+
+use crate::{to_string, try_parse_uint, StringsError};
+
+/// Rounding applied when `UnitsFormat::max_fraction_digits` cuts off digits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Toward zero
+    #[default]
+    Trunc,
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceil,
+    /// Away from zero
+    Expand,
+    /// To the nearest value, ties away from zero
+    Nearest,
+}
+
+/// Configurable formatter for token amounts.
+///
+/// Trailing zeros of the fraction are always removed, and whole amounts have no decimal point.
+///
+/// ```
+/// # use alloy_primitives::U256;
+/// # use strings_utils_stylus::units::{Rounding, UnitsFormat};
+/// let format = UnitsFormat::new(6)
+///     .max_fraction_digits(2)
+///     .rounding(Rounding::Nearest)
+///     .thousands_separator(',');
+/// assert_eq!(format.format(U256::from(1_234_567_890_u64)), "1,234.57");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitsFormat {
+    decimals: u8,
+    max_fraction_digits: Option<usize>,
+    rounding: Rounding,
+    thousands_separator: Option<char>,
+}
+
+impl UnitsFormat {
+    /// Exact formatting of amounts with `decimals` decimals, without separators
+    pub fn new(decimals: u8) -> Self {
+        UnitsFormat {
+            decimals,
+            max_fraction_digits: None,
+            rounding: Rounding::Trunc,
+            thousands_separator: None,
+        }
+    }
+
+    /// Shows at most `digits` digits after the decimal point, rounding the rest away
+    pub fn max_fraction_digits(mut self, digits: usize) -> Self {
+        self.max_fraction_digits = Some(digits);
+        self
+    }
+
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Groups the digits before the decimal point in threes with `separator`
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Formats an unsigned amount.
+    pub fn format(&self, value: U256) -> String {
+        self.format_abs(value, false)
+    }
+
+    /// Formats a signed amount. Amounts that round to zero have no minus sign.
+    pub fn format_signed(&self, value: I256) -> String {
+        self.format_abs(value.unsigned_abs(), value.is_negative())
+    }
+
+    fn format_abs(&self, abs: U256, negative: bool) -> String {
+        let decimals = self.decimals as usize;
+
+        // Left pad so there is at least one digit before the decimal point
+        let mut digits = to_string(abs).into_bytes();
+        if digits.len() <= decimals {
+            let mut padded = vec![b'0'; decimals + 1 - digits.len()];
+            padded.append(&mut digits);
+            digits = padded;
+        }
+
+        let mut fraction_digits = decimals;
+        if let Some(max) = self.max_fraction_digits.filter(|&max| max < decimals) {
+            let dropped = digits.split_off(digits.len() - (decimals - max));
+            if self.rounds_up(&dropped, negative) {
+                increment(&mut digits);
+            }
+            fraction_digits = max;
+        }
+
+        let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+        let fraction = trim_trailing_zeros(fraction);
+        let is_zero = integer.iter().chain(fraction).all(|&digit| digit == b'0');
+
+        let mut result = String::with_capacity(digits.len() + digits.len() / 3 + 2);
+        if negative && !is_zero {
+            result.push('-');
+        }
+        for (i, &digit) in integer.iter().enumerate() {
+            if let Some(separator) = self.thousands_separator {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    result.push(separator);
+                }
+            }
+            result.push(digit as char);
+        }
+        if !fraction.is_empty() {
+            result.push('.');
+            result.extend(fraction.iter().map(|&digit| digit as char));
+        }
+
+        result
+    }
+
+    // Whether cutting off `dropped` moves the magnitude up by one unit of the last kept digit
+    fn rounds_up(&self, dropped: &[u8], negative: bool) -> bool {
+        let inexact = dropped.iter().any(|&digit| digit != b'0');
+        match self.rounding {
+            Rounding::Trunc => false,
+            Rounding::Floor => inexact && negative,
+            Rounding::Ceil => inexact && !negative,
+            Rounding::Expand => inexact,
+            Rounding::Nearest => dropped[0] >= b'5',
+        }
+    }
+}
+
+/// Formats an amount with `decimals` decimals exactly, e.g. `"1.5"` for 1.5 ether in wei.
+///
+/// Matches `formatUnits` in viem. Unlike ethers, whole amounts have no `.0`.
+pub fn format_units(value: U256, decimals: u8) -> String {
+    UnitsFormat::new(decimals).format(value)
+}
+
+/// Formats a signed amount with `decimals` decimals exactly, e.g. `"-0.25"`.
+pub fn format_units_signed(value: I256, decimals: u8) -> String {
+    UnitsFormat::new(decimals).format_signed(value)
+}
+
+/// Parses a decimal amount such as `"1.5"` into its value with `decimals` decimals.
+///
+/// The fraction can have more digits than `decimals` only if the extra digits are zeros. As with
+/// `parse_uint`, an empty string parses to zero.
+///
+/// Panics if the string is not a valid amount, use `try_parse_units` to get an error instead.
+pub fn parse_units(input: &str, decimals: u8) -> U256 {
+    try_parse_units(input, decimals).expect("Invalid decimal amount")
+}
+
+/// Variant of `parse_units` that returns an error instead of panicking.
+pub fn try_parse_units(input: &str, decimals: u8) -> Result<U256, StringsError> {
+    parse_units_from(input, 0, decimals)
+}
+
+/// Parses a decimal amount with an optional "+" or "-" sign into an I256.
+///
+/// Panics if the string is not a valid amount, use `try_parse_units_signed` to get an error
+/// instead.
+pub fn parse_units_signed(input: &str, decimals: u8) -> I256 {
+    try_parse_units_signed(input, decimals).expect("Invalid decimal amount")
+}
+
+/// Variant of `parse_units_signed` that returns an error instead of panicking.
+pub fn try_parse_units_signed(input: &str, decimals: u8) -> Result<I256, StringsError> {
+    let (sign, begin) = match input.as_bytes().first() {
+        Some(b'-') => (Sign::Negative, 1),
+        Some(b'+') => (Sign::Positive, 1),
+        _ => (Sign::Positive, 0),
+    };
+    let abs = parse_units_from(input, begin, decimals)?;
+    I256::checked_from_sign_and_abs(sign, abs).ok_or(StringsError::Overflow)
+}
+
+fn parse_units_from(input: &str, begin: usize, decimals: u8) -> Result<U256, StringsError> {
+    let bytes = &input.as_bytes()[begin..];
+    let decimals = decimals as usize;
+
+    let point = bytes.iter().position(|&chr| chr == b'.');
+    let (integer, fraction) = match point {
+        Some(point) => (&bytes[..point], &bytes[point + 1..]),
+        None => (bytes, &[][..]),
+    };
+
+    // Errors point at the offending byte of the whole input
+    let fraction_begin = begin + integer.len() + 1;
+    check_digits(integer, begin)?;
+    check_digits(fraction, fraction_begin)?;
+
+    if trim_trailing_zeros(fraction).len() > decimals {
+        return Err(StringsError::TooManyDecimals {
+            decimals: decimals as u8,
+        });
+    }
+
+    // The value is the digits of both parts with the fraction right padded to `decimals`
+    let mut digits = Vec::with_capacity(integer.len() + decimals);
+    digits.extend_from_slice(integer);
+    digits.extend(fraction.iter().copied().take(decimals));
+    digits.resize(integer.len() + decimals, b'0');
+
+    let digits = String::from_utf8(digits).expect("Invalid UTF-8 from digits");
+    // Every byte is a digit, so this can only fail with an overflow
+    try_parse_uint(&digits)
+}
+
+fn check_digits(digits: &[u8], offset: usize) -> Result<(), StringsError> {
+    match digits.iter().position(|chr| !chr.is_ascii_digit()) {
+        Some(i) => Err(StringsError::InvalidChar { index: offset + i }),
+        None => Ok(()),
+    }
+}
+
+fn trim_trailing_zeros(digits: &[u8]) -> &[u8] {
+    let length = digits
+        .iter()
+        .rposition(|&digit| digit != b'0')
+        .map_or(0, |i| i + 1);
+    &digits[..length]
+}
+
+// Adds one to a string of decimal digits, growing it if every digit carries
+fn increment(digits: &mut Vec<u8>) {
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return;
+        }
+    }
+    digits.insert(0, b'1');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ether(value: &str) -> U256 {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(ether("1500000000000000000"), 18), "1.5");
+        assert_eq!(format_units(ether("1000000000000000000"), 18), "1");
+        assert_eq!(format_units(U256::from(1), 18), "0.000000000000000001");
+        assert_eq!(format_units(U256::ZERO, 18), "0");
+        assert_eq!(format_units(U256::from(1234), 0), "1234");
+        assert_eq!(format_units(U256::from(1234), 2), "12.34");
+        assert_eq!(
+            format_units(U256::MAX, 18),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+        // More decimals than U256 has digits
+        assert_eq!(
+            format_units(U256::from(5), 80),
+            format!("0.{}5", "0".repeat(79))
+        );
+    }
+
+    #[test]
+    fn test_format_units_signed() {
+        assert_eq!(
+            format_units_signed(I256::try_from(-25).unwrap(), 2),
+            "-0.25"
+        );
+        assert_eq!(format_units_signed(I256::try_from(250).unwrap(), 2), "2.5");
+        assert_eq!(format_units_signed(I256::ZERO, 2), "0");
+        assert_eq!(
+            format_units_signed(I256::MIN, 0),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let value = U256::from(12350); // 1.2350 with 4 decimals
+        let format = UnitsFormat::new(4).max_fraction_digits(2);
+        assert_eq!(format.rounding(Rounding::Trunc).format(value), "1.23");
+        assert_eq!(format.rounding(Rounding::Floor).format(value), "1.23");
+        assert_eq!(format.rounding(Rounding::Ceil).format(value), "1.24");
+        assert_eq!(format.rounding(Rounding::Expand).format(value), "1.24");
+        assert_eq!(format.rounding(Rounding::Nearest).format(value), "1.24");
+        assert_eq!(
+            format.rounding(Rounding::Nearest).format(U256::from(12349)),
+            "1.23"
+        );
+
+        // Exact values are never rounded
+        assert_eq!(
+            format.rounding(Rounding::Expand).format(U256::from(12300)),
+            "1.23"
+        );
+
+        let negative = I256::try_from(-12350).unwrap();
+        assert_eq!(
+            format.rounding(Rounding::Trunc).format_signed(negative),
+            "-1.23"
+        );
+        assert_eq!(
+            format.rounding(Rounding::Floor).format_signed(negative),
+            "-1.24"
+        );
+        assert_eq!(
+            format.rounding(Rounding::Ceil).format_signed(negative),
+            "-1.23"
+        );
+        assert_eq!(
+            format.rounding(Rounding::Expand).format_signed(negative),
+            "-1.24"
+        );
+    }
+
+    #[test]
+    fn test_rounding_carries() {
+        let format = UnitsFormat::new(3)
+            .max_fraction_digits(1)
+            .rounding(Rounding::Nearest);
+        assert_eq!(format.format(U256::from(9_960)), "10");
+        assert_eq!(format.format(U256::from(99_950)), "100");
+        assert_eq!(format.max_fraction_digits(0).format(U256::from(499)), "0");
+        assert_eq!(format.max_fraction_digits(0).format(U256::from(500)), "1");
+
+        // No "-0" when a negative amount rounds to zero
+        let tiny = I256::try_from(-1).unwrap();
+        assert_eq!(format.format_signed(tiny), "0");
+        assert_eq!(format.rounding(Rounding::Floor).format_signed(tiny), "-0.1");
+    }
+
+    #[test]
+    fn test_thousands_separator() {
+        let format = UnitsFormat::new(2).thousands_separator(',');
+        assert_eq!(
+            format.format(U256::from(12_345_678_901_u64)),
+            "123,456,789.01"
+        );
+        assert_eq!(format.format(U256::from(100_000)), "1,000");
+        assert_eq!(format.format(U256::from(99_900)), "999");
+        assert_eq!(format.format(U256::from(5)), "0.05");
+        assert_eq!(
+            format
+                .thousands_separator('_')
+                .format_signed(I256::try_from(-123_456_700).unwrap()),
+            "-1_234_567"
+        );
+    }
+
+    #[test]
+    fn test_parse_units() {
+        assert_eq!(parse_units("1.5", 18), ether("1500000000000000000"));
+        assert_eq!(parse_units("1", 18), ether("1000000000000000000"));
+        assert_eq!(parse_units(".5", 1), U256::from(5));
+        assert_eq!(parse_units("2.", 1), U256::from(20));
+        assert_eq!(parse_units("0.000000000000000001", 18), U256::from(1));
+        assert_eq!(parse_units("12.3400", 2), U256::from(1234));
+        assert_eq!(parse_units("", 18), U256::ZERO);
+        assert_eq!(parse_units("0", 80), U256::ZERO);
+
+        assert_eq!(
+            try_parse_units("1.234", 2),
+            Err(StringsError::TooManyDecimals { decimals: 2 })
+        );
+        assert_eq!(
+            try_parse_units("1,5", 18),
+            Err(StringsError::InvalidChar { index: 1 })
+        );
+        assert_eq!(
+            try_parse_units("1.5.0", 18),
+            Err(StringsError::InvalidChar { index: 3 })
+        );
+        assert_eq!(
+            try_parse_units("-1", 18),
+            Err(StringsError::InvalidChar { index: 0 })
+        );
+        assert_eq!(try_parse_units("1", 78), Err(StringsError::Overflow));
+    }
+
+    #[test]
+    fn test_parse_units_signed() {
+        assert_eq!(parse_units_signed("-0.25", 2), I256::try_from(-25).unwrap());
+        assert_eq!(parse_units_signed("+2.5", 2), I256::try_from(250).unwrap());
+        assert_eq!(parse_units_signed("-0", 2), I256::ZERO);
+        assert_eq!(
+            try_parse_units_signed("--1", 2),
+            Err(StringsError::InvalidChar { index: 1 })
+        );
+        assert_eq!(
+            try_parse_units_signed("-1.x", 2),
+            Err(StringsError::InvalidChar { index: 3 })
+        );
+        assert_eq!(
+            try_parse_units_signed(&format_units(I256::MAX.into_raw(), 0), 1),
+            Err(StringsError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid decimal amount")]
+    fn test_parse_units_invalid_panics() {
+        parse_units("1e18", 18);
+    }
+}
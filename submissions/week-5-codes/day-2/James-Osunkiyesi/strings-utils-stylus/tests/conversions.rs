@@ -0,0 +1,49 @@
+//! Fuzz tests of the integer conversions against ruint's own formatting.
+//!
+//! Both the reference and the optimized implementations are checked, whichever the crate root
+//! re-exports.
+
+use alloy_primitives::{I256, U256};
+use proptest::prelude::*;
+use strings_utils_stylus::{optimized, reference, to_string_signed, StringsError};
+
+// Uniform bytes almost always give 78 digit values, so also shrink them by a random shift
+fn any_u256() -> impl Strategy<Value = U256> {
+    (any::<[u8; 32]>(), 0usize..256).prop_map(|(bytes, shift)| U256::from_be_bytes(bytes) >> shift)
+}
+
+proptest! {
+    #[test]
+    fn to_string_matches_reference(value in any_u256()) {
+        prop_assert_eq!(reference::to_string(value), value.to_string());
+        prop_assert_eq!(optimized::to_string(value), value.to_string());
+    }
+
+    #[test]
+    fn to_string_signed_matches_reference(bytes in any::<[u8; 32]>()) {
+        let value = I256::from_be_bytes(bytes);
+        prop_assert_eq!(to_string_signed(value), value.to_string());
+    }
+
+    #[test]
+    fn to_hex_string_matches_reference(value in any_u256()) {
+        let digits = format!("{value:x}");
+        // Whole bytes, with at least one
+        let expected = format!("0x{digits:0>width$}", width = digits.len().div_ceil(2).max(1) * 2);
+        prop_assert_eq!(&reference::to_hex_string(value), &expected);
+        prop_assert_eq!(optimized::to_hex_string(value), expected);
+    }
+
+    #[test]
+    fn to_hex_string_with_length_matches_reference(value in any_u256(), length in 0usize..40) {
+        let expected = || {
+            if value.byte_len() > length {
+                Err(StringsError::InsufficientHexLength { value, length })
+            } else {
+                Ok(format!("0x{value:0>width$x}", width = 2 * length))
+            }
+        };
+        prop_assert_eq!(reference::to_hex_string_with_length(value, length), expected());
+        prop_assert_eq!(optimized::to_hex_string_with_length(value, length), expected());
+    }
+}
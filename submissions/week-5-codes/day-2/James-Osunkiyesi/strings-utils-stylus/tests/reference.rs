@@ -0,0 +1,53 @@
+//! Fuzz tests of the Base64 and JSON functions against std reference implementations.
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use proptest::prelude::*;
+use strings_utils_stylus::{base64 as b64, escape_json, JsonBuilder};
+
+proptest! {
+    #[test]
+    fn encode_matches_reference(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        prop_assert_eq!(b64::encode(&data), STANDARD.encode(&data));
+        prop_assert_eq!(b64::encode_url(&data), URL_SAFE_NO_PAD.encode(&data));
+    }
+
+    #[test]
+    fn decode_round_trips(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        prop_assert_eq!(b64::decode(&b64::encode(&data)).unwrap(), data.clone());
+        prop_assert_eq!(b64::decode_url(&b64::encode_url(&data)).unwrap(), data);
+    }
+
+    // Mostly valid characters, so both valid and invalid inputs come up often
+    #[test]
+    fn decode_matches_reference(input in "[A-Za-z0-9+/]{0,12}={0,2}|[A-Za-z0-9+/=_-]{0,12}") {
+        prop_assert_eq!(b64::decode(&input).ok(), STANDARD.decode(&input).ok());
+    }
+
+    #[test]
+    fn decode_url_matches_reference(input in "[A-Za-z0-9_-]{0,12}|[A-Za-z0-9+/=_-]{0,12}") {
+        prop_assert_eq!(b64::decode_url(&input).ok(), URL_SAFE_NO_PAD.decode(&input).ok());
+    }
+
+    #[test]
+    fn escape_json_matches_reference(input in any::<String>()) {
+        let quoted = format!("\"{}\"", escape_json(&input));
+        prop_assert_eq!(&quoted, &serde_json::to_string(&input).unwrap());
+        prop_assert_eq!(serde_json::from_str::<String>(&quoted).unwrap(), input);
+    }
+
+    #[test]
+    fn builder_output_parses(name in any::<String>(), description in any::<String>()) {
+        let uri = JsonBuilder::new()
+            .string("name", &name)
+            .string("description", &description)
+            .build_data_uri();
+
+        let encoded = uri.strip_prefix("data:application/json;base64,").unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&STANDARD.decode(encoded).unwrap()).unwrap();
+        prop_assert_eq!(json["name"].as_str(), Some(name.as_str()));
+        prop_assert_eq!(json["description"].as_str(), Some(description.as_str()));
+    }
+}
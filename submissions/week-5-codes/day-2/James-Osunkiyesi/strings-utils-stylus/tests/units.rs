@@ -0,0 +1,70 @@
+//! Round-trip and reference fuzz tests of the units formatting and parsing.
+
+use alloy_primitives::{I256, U256};
+use proptest::prelude::*;
+use strings_utils_stylus::{
+    format_units, format_units_signed, parse_units, parse_units_signed, Rounding, UnitsFormat,
+};
+
+fn any_u256() -> impl Strategy<Value = U256> {
+    any::<[u8; 32]>().prop_map(U256::from_be_bytes)
+}
+
+// Reference formatting with integer division, for values and decimals where 10^decimals fits
+fn reference(value: u128, decimals: u32) -> String {
+    let unit = 10u128.pow(decimals);
+    let fraction = format!("{:0width$}", value % unit, width = decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (value / unit).to_string()
+    } else {
+        format!("{}.{}", value / unit, fraction)
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_matches_reference(value in any::<u128>(), decimals in 0u8..=38) {
+        prop_assert_eq!(format_units(U256::from(value), decimals), reference(value, decimals as u32));
+    }
+
+    #[test]
+    fn parse_inverts_format(value in any_u256(), decimals in 0u8..=90) {
+        prop_assert_eq!(parse_units(&format_units(value, decimals), decimals), value);
+    }
+
+    #[test]
+    fn parse_inverts_format_signed(bytes in any::<[u8; 32]>(), decimals in 0u8..=90) {
+        let value = I256::from_raw(U256::from_be_bytes(bytes));
+        prop_assert_eq!(parse_units_signed(&format_units_signed(value, decimals), decimals), value);
+    }
+
+    // Rounded output is within one unit of the last shown digit, on the side the mode asks for
+    #[test]
+    fn rounding_bounds(value in any::<u64>(), decimals in 1u8..=12, digits in 0usize..12) {
+        let value = U256::from(value);
+        let format = UnitsFormat::new(decimals).max_fraction_digits(digits);
+        let step = U256::from(10u64).pow(U256::from(decimals.saturating_sub(digits as u8)));
+
+        let down = parse_units(&format.rounding(Rounding::Trunc).format(value), decimals);
+        let up = parse_units(&format.rounding(Rounding::Expand).format(value), decimals);
+        let nearest = parse_units(&format.rounding(Rounding::Nearest).format(value), decimals);
+
+        prop_assert!(down <= value && value - down < step);
+        prop_assert!(up >= value && up - value < step);
+        // Ties round away from zero
+        let expected = if value - down < up - value { down } else { up };
+        prop_assert_eq!(nearest, expected);
+    }
+
+    #[test]
+    fn separators_only_group_digits(value in any_u256(), decimals in 0u8..=30) {
+        let grouped = UnitsFormat::new(decimals).thousands_separator(',').format(value);
+        prop_assert_eq!(grouped.replace(',', ""), format_units(value, decimals));
+
+        let integer = grouped.split('.').next().unwrap();
+        let mut groups = integer.split(',');
+        prop_assert!((1..=3).contains(&groups.next().unwrap().len()));
+        prop_assert!(groups.all(|group| group.len() == 3));
+    }
+}
@@ -6,10 +6,18 @@
 extern crate alloc;
 
 use alloc::{string::String, vec::Vec};
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, U256, U8};
 use alloy_sol_types::sol;
 use stylus_sdk::prelude::*;
 
+sol_storage! {
+    pub struct TodoRevision {
+        string text;
+        address editor;
+        uint256 edited_at;
+    }
+}
+
 sol_storage! {
     pub struct Todo {
         uint256 id;
@@ -17,6 +25,23 @@ sol_storage! {
         bool completed;
         address creator;
         uint256 created_at;
+        // Zero for todos outside any shared list
+        uint256 list_id;
+        // Deleted todos keep the rest of their data
+        bool deleted;
+        uint256 deleted_at;
+        // Every text the todo has had, oldest first
+        TodoRevision[] history;
+    }
+}
+
+sol_storage! {
+    pub struct TodoList {
+        uint256 id;
+        string name;
+        address owner;
+        // Role of each collaborator, the owner has every permission without one
+        mapping(address => uint8) roles;
     }
 }
 
@@ -27,9 +52,26 @@ sol_storage! {
         uint256 next_id;
         uint256 total_todos;
         address owner;
+        // Ids of each creator's todos that are not deleted
+        mapping(address => uint256[]) todos_of;
+        // Position of each id in its creator's `todos_of` array
+        mapping(uint256 => uint256) todos_of_index;
+        mapping(uint256 => TodoList) lists;
+        uint256 next_list_id;
     }
 }
 
+// Collaborator roles in a shared list, each with the permissions of the ones below it
+pub const ROLE_NONE: u8 = 0;
+// Can read the list's todos
+pub const ROLE_VIEWER: u8 = 1;
+// Can create, update and complete todos, and delete their own
+pub const ROLE_EDITOR: u8 = 2;
+// Can also delete any todo and share the list as viewer or editor
+pub const ROLE_ADMIN: u8 = 3;
+// The list's owner, who can also add and remove admins
+pub const ROLE_OWNER: u8 = 4;
+
 sol! {
     #[derive(Debug)]
     error TodoNotFoundError();
@@ -46,10 +88,17 @@ sol! {
     #[derive(Debug)]
     error UnauthorizedError();
 
-    event TodoCreated(uint256 indexed id, string text, address indexed creator);
-    event TodoCompleted(uint256 indexed id, address indexed completer);
-    event TodoDeleted(uint256 indexed id, address indexed deleter);
-    event TodoUpdated(uint256 indexed id, string new_text, address indexed updater);
+    event TodoCreated(
+        uint256 indexed id, uint256 indexed list_id, string text, address indexed creator
+    );
+    event TodoCompleted(uint256 indexed id, uint256 indexed list_id, address indexed completer);
+    event TodoDeleted(uint256 indexed id, uint256 indexed list_id, address indexed deleter);
+    event TodoUpdated(
+        uint256 indexed id, uint256 indexed list_id, string new_text, address indexed updater
+    );
+    event ListCreated(uint256 indexed list_id, string name, address indexed owner);
+    event ListShared(uint256 indexed list_id, address indexed user, uint8 role);
+    event ListRevoked(uint256 indexed list_id, address indexed user);
 }
 
 #[derive(SolidityError, Debug)]
@@ -68,42 +117,34 @@ impl TodoContract {
         self.owner.set(self.vm().msg_sender());
         self.next_id.set(U256::from(1));
         self.total_todos.set(U256::ZERO);
+        self.next_list_id.set(U256::from(1));
     }
 
+    /// Creates a personal todo, which belongs to no list
     pub fn create_todo(&mut self, text: String) -> Result<U256, TodoError> {
-        if text.is_empty() {
-            return Err(TodoError::EmptyTextError(EmptyTextError{}));
-        }
-
-        let id = self.next_id.get();
-        let creator = self.vm().msg_sender();
-        let created_at = U256::from(self.vm().block_timestamp());
-
-        let mut todo = self.todos.setter(id);
-        todo.id.set(id);
-        todo.text.set_str(&text);
-        todo.completed.set(false);
-        todo.creator.set(creator);
-        todo.created_at.set(created_at);
-
-        self.next_id.set(id + U256::from(1));
-        self.total_todos.set(self.total_todos.get() + U256::from(1));
-
-        log(self.vm(), TodoCreated {
-            id,
-            text,
-            creator,
-        });
+        self._create_todo(U256::ZERO, text)
+    }
 
-        Ok(id)
+    /// Creates a todo in a shared list, which needs the editor role
+    pub fn create_todo_in_list(&mut self, list_id: U256, text: String) -> Result<U256, TodoError> {
+        if !self.list_exists(list_id) {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+        if self.get_role(list_id, self.vm().msg_sender()) < ROLE_EDITOR {
+            return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
+        }
+        self._create_todo(list_id, text)
     }
 
+    /// Todos in a shared list can only be read with a role in it
     pub fn get_todo(&self, id: U256) -> Result<(U256, String, bool, Address, U256), TodoError> {
-        let todo = self.todos.get(id);
-        
-        if todo.id.get() == U256::ZERO && id != U256::ZERO {
+        if !self.todo_exists(id) {
             return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
         }
+        let todo = self.todos.get(id);
+        if !self._can_read(todo.list_id.get(), self.vm().msg_sender()) {
+            return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
+        }
 
         Ok((
             todo.id.get(),
@@ -116,27 +157,27 @@ impl TodoContract {
 
     pub fn complete_todo(&mut self, id: U256) -> Result<(), TodoError> {
         let sender = self.vm().msg_sender();
-        let owner = self.owner.get();
         
-        let mut todo = self.todos.setter(id);
-        
-        if todo.id.get() == U256::ZERO && id != U256::ZERO {
+        if !self.todo_exists(id) {
             return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
         }
+        let todo = self.todos.get(id);
 
         if todo.completed.get() {
             return Err(TodoError::AlreadyCompletedError(AlreadyCompletedError{}));
         }
 
-        if sender != todo.creator.get() && sender != owner {
+        let list_id = todo.list_id.get();
+        if !self._can_edit(list_id, todo.creator.get(), sender) {
             return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
         }
 
-        todo.completed.set(true);
+        self.todos.setter(id).completed.set(true);
 
 
         log(self.vm(), TodoCompleted {
             id,
+            list_id,
             completer: sender,
         });
 
@@ -149,23 +190,26 @@ impl TodoContract {
         }
 
         let sender = self.vm().msg_sender();
-        let owner = self.owner.get();
         
-        let mut todo = self.todos.setter(id);
-        
-        if todo.id.get() == U256::ZERO && id != U256::ZERO {
+        if !self.todo_exists(id) {
             return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
         }
+        let edited_at = U256::from(self.vm().block_timestamp());
+        let todo = self.todos.get(id);
+        let list_id = todo.list_id.get();
 
-        if sender != todo.creator.get() && sender != owner {
+        if !self._can_edit(list_id, todo.creator.get(), sender) {
             return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
         }
 
+        let mut todo = self.todos.setter(id);
         todo.text.set_str(&new_text);
+        Self::_push_revision(&mut todo, &new_text, sender, edited_at);
 
 
         log(self.vm(), TodoUpdated {
             id,
+            list_id,
             new_text,
             updater: sender,
         });
@@ -173,29 +217,31 @@ impl TodoContract {
         Ok(())
     }
 
+    /// Soft delete: the todo can no longer be read or changed, but `get_deleted_at` and
+    /// `get_todo_history` still report it.
     pub fn delete_todo(&mut self, id: U256) -> Result<(), TodoError> {
-        let todo = self.todos.get(id);
-        
-        if todo.id.get() == U256::ZERO && id != U256::ZERO {
+        if !self.todo_exists(id) {
             return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
         }
+        let creator = self.todos.get(id).creator.get();
+        let list_id = self.todos.get(id).list_id.get();
 
         let sender = self.vm().msg_sender();
-        if sender != todo.creator.get() && sender != self.owner.get() {
+        if !self._can_delete(list_id, creator, sender) {
             return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
         }
 
-        let mut todo_setter = self.todos.setter(id);
-        todo_setter.id.set(U256::ZERO);
-        todo_setter.text.set_str("");
-        todo_setter.completed.set(false);
-        todo_setter.creator.set(Address::ZERO);
-        todo_setter.created_at.set(U256::ZERO);
+        let deleted_at = U256::from(self.vm().block_timestamp());
+        let mut todo = self.todos.setter(id);
+        todo.deleted.set(true);
+        todo.deleted_at.set(deleted_at);
+        self._remove_from_todos_of(creator, id);
 
         self.total_todos.set(self.total_todos.get() - U256::from(1));
 
         log(self.vm(), TodoDeleted {
             id,
+            list_id,
             deleter: sender,
         });
 
@@ -214,9 +260,64 @@ impl TodoContract {
         self.owner.get()
     }
 
+    /// Ids start at 1, so id 0 never exists
     pub fn todo_exists(&self, id: U256) -> bool {
         let todo = self.todos.get(id);
-        todo.id.get() != U256::ZERO || id == U256::ZERO
+        todo.id.get() != U256::ZERO && !todo.deleted.get()
+    }
+
+    /// Number of todos `user` created that are not deleted
+    pub fn get_todo_count_of(&self, user: Address) -> U256 {
+        U256::from(self.todos_of.get(user).len())
+    }
+
+    /// Ids of up to `limit` of `user`'s todos, starting at `offset`. Deleting a todo moves the
+    /// user's last todo into its place, so the order is not the order of creation.
+    pub fn get_todos_of(&self, user: Address, offset: U256, limit: U256) -> Vec<U256> {
+        let todos_of = self.todos_of.get(user);
+        let len = U256::from(todos_of.len());
+        let start = offset.min(len);
+        let end = start.saturating_add(limit).min(len);
+
+        let mut ids = Vec::new();
+        for index in start.to::<usize>()..end.to::<usize>() {
+            ids.push(todos_of.get(index).unwrap_or_default());
+        }
+        ids
+    }
+
+    /// When the todo was deleted, or zero if it was not
+    pub fn get_deleted_at(&self, id: U256) -> Result<U256, TodoError> {
+        let todo = self.todos.get(id);
+        if todo.id.get() == U256::ZERO {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+        Ok(todo.deleted_at.get())
+    }
+
+    /// Every text the todo has had as (text, editor, edited_at), oldest first. The first entry is
+    /// from when the todo was created and the last is the current text. Also available for
+    /// deleted todos.
+    pub fn get_todo_history(&self, id: U256) -> Result<Vec<(String, Address, U256)>, TodoError> {
+        let todo = self.todos.get(id);
+        if todo.id.get() == U256::ZERO {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+        if !self._can_read(todo.list_id.get(), self.vm().msg_sender()) {
+            return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
+        }
+
+        let mut history = Vec::new();
+        for index in 0..todo.history.len() {
+            if let Some(revision) = todo.history.get(index) {
+                history.push((
+                    revision.text.get_string(),
+                    revision.editor.get(),
+                    revision.edited_at.get(),
+                ));
+            }
+        }
+        Ok(history)
     }
 
     pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), TodoError> {
@@ -227,9 +328,202 @@ impl TodoContract {
         self.owner.set(new_owner);
         Ok(())
     }
+
+    /// Creates a shared list owned by the caller
+    pub fn create_list(&mut self, name: String) -> Result<U256, TodoError> {
+        if name.is_empty() {
+            return Err(TodoError::EmptyTextError(EmptyTextError{}));
+        }
+
+        let list_id = self.next_list_id.get();
+        let owner = self.vm().msg_sender();
+
+        let mut list = self.lists.setter(list_id);
+        list.id.set(list_id);
+        list.name.set_str(&name);
+        list.owner.set(owner);
+        self.next_list_id.set(list_id + U256::from(1));
+
+        log(self.vm(), ListCreated {
+            list_id,
+            name,
+            owner,
+        });
+
+        Ok(list_id)
+    }
+
+    /// Gives `user` a role in the list, replacing any role they had. Admins can share the list
+    /// as viewer or editor, only the owner can add admins.
+    pub fn share_list(&mut self, list_id: U256, user: Address, role: u8) -> Result<(), TodoError> {
+        // Sharing needs an actual role, `revoke` removes one
+        if role == ROLE_NONE {
+            return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
+        }
+        self._check_can_manage(list_id, user, role)?;
+
+        self.lists.setter(list_id).roles.setter(user).set(U8::from(role));
+
+        log(self.vm(), ListShared {
+            list_id,
+            user,
+            role,
+        });
+
+        Ok(())
+    }
+
+    /// Removes `user`'s role in the list. Admins can revoke viewers and editors, only the owner
+    /// can revoke admins.
+    pub fn revoke(&mut self, list_id: U256, user: Address) -> Result<(), TodoError> {
+        self._check_can_manage(list_id, user, ROLE_NONE)?;
+
+        self.lists.setter(list_id).roles.delete(user);
+
+        log(self.vm(), ListRevoked {
+            list_id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    pub fn list_exists(&self, list_id: U256) -> bool {
+        self.lists.get(list_id).id.get() != U256::ZERO
+    }
+
+    /// The list's name and owner
+    pub fn get_list(&self, list_id: U256) -> Result<(String, Address), TodoError> {
+        if !self.list_exists(list_id) {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+        let list = self.lists.get(list_id);
+        Ok((list.name.get_string(), list.owner.get()))
+    }
+
+    /// `user`'s role in the list, `ROLE_OWNER` for its owner and `ROLE_NONE` for anyone else
+    /// without a role
+    pub fn get_role(&self, list_id: U256, user: Address) -> u8 {
+        let list = self.lists.get(list_id);
+        if list.id.get() != U256::ZERO && list.owner.get() == user {
+            return ROLE_OWNER;
+        }
+        list.roles.get(user).to::<u8>()
+    }
+
+    /// The shared list a todo belongs to, or zero if it is not in one
+    pub fn get_todo_list(&self, id: U256) -> Result<U256, TodoError> {
+        if !self.todo_exists(id) {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+        Ok(self.todos.get(id).list_id.get())
+    }
+}
+
+impl TodoContract {
+    // Creates a todo in `list_id`, or a personal one if it is zero, once the caller is allowed to
+    fn _create_todo(&mut self, list_id: U256, text: String) -> Result<U256, TodoError> {
+        if text.is_empty() {
+            return Err(TodoError::EmptyTextError(EmptyTextError{}));
+        }
+
+        let id = self.next_id.get();
+        let creator = self.vm().msg_sender();
+        let created_at = U256::from(self.vm().block_timestamp());
+
+        let mut todo = self.todos.setter(id);
+        todo.id.set(id);
+        todo.text.set_str(&text);
+        todo.completed.set(false);
+        todo.creator.set(creator);
+        todo.created_at.set(created_at);
+        todo.list_id.set(list_id);
+        Self::_push_revision(&mut todo, &text, creator, created_at);
+
+        let mut todos_of = self.todos_of.setter(creator);
+        self.todos_of_index.setter(id).set(U256::from(todos_of.len()));
+        todos_of.push(id);
+
+        self.next_id.set(id + U256::from(1));
+        self.total_todos.set(self.total_todos.get() + U256::from(1));
+
+        log(self.vm(), TodoCreated {
+            id,
+            list_id,
+            text,
+            creator,
+        });
+
+        Ok(id)
+    }
+
+    // Whether `sender` may read a todo. Personal todos are readable by anyone
+    fn _can_read(&self, list_id: U256, sender: Address) -> bool {
+        list_id == U256::ZERO || self.get_role(list_id, sender) >= ROLE_VIEWER
+    }
+
+    // Whether `sender` may update or complete a todo. The contract owner may change any personal
+    // todo, but todos in a shared list only follow the list's roles
+    fn _can_edit(&self, list_id: U256, creator: Address, sender: Address) -> bool {
+        if list_id == U256::ZERO {
+            return sender == creator || sender == self.owner.get();
+        }
+        self.get_role(list_id, sender) >= ROLE_EDITOR
+    }
+
+    // Whether `sender` may delete a todo: editors can delete their own, admins any
+    fn _can_delete(&self, list_id: U256, creator: Address, sender: Address) -> bool {
+        if list_id == U256::ZERO {
+            return sender == creator || sender == self.owner.get();
+        }
+        let role = self.get_role(list_id, sender);
+        role >= ROLE_ADMIN || (role >= ROLE_EDITOR && sender == creator)
+    }
+
+    // Checks that the sender may give `user` the role `role` in the list, or take theirs away
+    // if `role` is `ROLE_NONE`
+    fn _check_can_manage(&self, list_id: U256, user: Address, role: u8) -> Result<(), TodoError> {
+        if !self.list_exists(list_id) {
+            return Err(TodoError::TodoNotFoundError(TodoNotFoundError{}));
+        }
+
+        let sender_role = self.get_role(list_id, self.vm().msg_sender());
+        let current_role = self.get_role(list_id, user);
+        // Admins manage the roles below their own, the owner manages every role but its own
+        let limit = if sender_role == ROLE_OWNER { ROLE_OWNER } else { ROLE_ADMIN };
+        if sender_role < ROLE_ADMIN || current_role >= limit || role >= limit {
+            return Err(TodoError::UnauthorizedError(UnauthorizedError{}));
+        }
+        Ok(())
+    }
+
+    fn _push_revision(todo: &mut Todo, text: &str, editor: Address, edited_at: U256) {
+        let mut revision = todo.history.grow();
+        revision.text.set_str(text);
+        revision.editor.set(editor);
+        revision.edited_at.set(edited_at);
+    }
+
+    // Swap and pop `id` out of `creator`'s todos
+    fn _remove_from_todos_of(&mut self, creator: Address, id: U256) {
+        let index = self.todos_of_index.get(id);
+        let mut todos_of = self.todos_of.setter(creator);
+        let last = U256::from(todos_of.len() - 1);
+
+        if index != last {
+            let last_id = todos_of.get(last).unwrap_or_default();
+            if let Some(mut slot) = todos_of.setter(index) {
+                slot.set(last_id);
+            }
+            self.todos_of_index.setter(last_id).set(index);
+        }
+        todos_of.pop();
+        self.todos_of_index.delete(id);
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use stylus_sdk::testing::*;
@@ -523,4 +817,404 @@ mod tests {
         assert_eq!(completed1, false);
         assert_eq!(completed3, false);
     }
+
+    #[test]
+    fn test_todos_of() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let owner = vm.msg_sender();
+        let user = Address::from([1u8; 20]);
+        let first = contract.create_todo("First".to_string()).unwrap();
+        vm.set_sender(user);
+        let second = contract.create_todo("Second".to_string()).unwrap();
+        vm.set_sender(owner);
+        let third = contract.create_todo("Third".to_string()).unwrap();
+
+        assert_eq!(contract.get_todo_count_of(owner), U256::from(2));
+        assert_eq!(contract.get_todo_count_of(user), U256::from(1));
+        assert_eq!(contract.get_todo_count_of(Address::from([2u8; 20])), U256::ZERO);
+
+        let all = U256::from(10);
+        assert_eq!(contract.get_todos_of(owner, U256::ZERO, all), vec![first, third]);
+        assert_eq!(contract.get_todos_of(user, U256::ZERO, all), vec![second]);
+    }
+
+    #[test]
+    fn test_todos_of_pagination() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let owner = vm.msg_sender();
+        let ids: Vec<U256> = (0..5)
+            .map(|i| contract.create_todo(format!("Todo {i}")).unwrap())
+            .collect();
+
+        let page = |offset: u64, limit: u64| {
+            contract.get_todos_of(owner, U256::from(offset), U256::from(limit))
+        };
+        assert_eq!(page(0, 2), ids[0..2]);
+        assert_eq!(page(2, 2), ids[2..4]);
+        assert_eq!(page(4, 2), ids[4..5]);
+        assert!(page(5, 2).is_empty());
+        assert!(page(1, 0).is_empty());
+        assert_eq!(contract.get_todos_of(owner, U256::ZERO, U256::MAX), ids);
+        assert!(contract.get_todos_of(owner, U256::MAX, U256::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_delete_swaps_and_pops() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let owner = vm.msg_sender();
+        let ids: Vec<U256> = (0..4)
+            .map(|i| contract.create_todo(format!("Todo {i}")).unwrap())
+            .collect();
+        let all = U256::from(10);
+
+        // The last todo takes the deleted one's place
+        contract.delete_todo(ids[1]).unwrap();
+        assert_eq!(contract.get_todos_of(owner, U256::ZERO, all), vec![ids[0], ids[3], ids[2]]);
+
+        // Deleting the last one just pops it
+        contract.delete_todo(ids[2]).unwrap();
+        assert_eq!(contract.get_todos_of(owner, U256::ZERO, all), vec![ids[0], ids[3]]);
+
+        // The moved todo can still be deleted from its new place
+        contract.delete_todo(ids[3]).unwrap();
+        contract.delete_todo(ids[0]).unwrap();
+        assert_eq!(contract.get_todo_count_of(owner), U256::ZERO);
+        assert!(contract.get_todos_of(owner, U256::ZERO, all).is_empty());
+    }
+
+    #[test]
+    fn test_owner_delete_removes_from_creator() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let owner = vm.msg_sender();
+        let user = Address::from([1u8; 20]);
+        vm.set_sender(user);
+        let todo_id = contract.create_todo("User's todo".to_string()).unwrap();
+
+        vm.set_sender(owner);
+        contract.delete_todo(todo_id).unwrap();
+        assert_eq!(contract.get_todo_count_of(user), U256::ZERO);
+        assert_eq!(contract.get_todo_count_of(owner), U256::ZERO);
+    }
+
+    #[test]
+    fn test_soft_delete() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        vm.set_block_timestamp(100);
+        let todo_id = contract.create_todo("To be deleted".to_string()).unwrap();
+        assert_eq!(contract.get_deleted_at(todo_id).unwrap(), U256::ZERO);
+
+        vm.set_block_timestamp(200);
+        contract.delete_todo(todo_id).unwrap();
+        assert_eq!(contract.get_deleted_at(todo_id).unwrap(), U256::from(200));
+
+        // Deleted todos can't be changed or deleted again
+        assert!(matches!(
+            contract.complete_todo(todo_id).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert!(matches!(
+            contract.update_todo(todo_id, "Too late".to_string()).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert!(matches!(
+            contract.delete_todo(todo_id).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert_eq!(contract.get_total_todos(), U256::ZERO);
+
+        // But their history is kept
+        let history = contract.get_todo_history(todo_id).unwrap();
+        let expected = ("To be deleted".to_string(), vm.msg_sender(), U256::from(100));
+        assert_eq!(history, vec![expected]);
+
+        // Ids that were never used are not found
+        let result = contract.get_deleted_at(U256::from(999));
+        assert!(matches!(result.unwrap_err(), TodoError::TodoNotFoundError(_)));
+        let result = contract.get_todo_history(U256::from(999));
+        assert!(matches!(result.unwrap_err(), TodoError::TodoNotFoundError(_)));
+    }
+
+    #[test]
+    fn test_todo_zero() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        // Id 0 is never handed out, so the owner can't touch it even with no todos
+        assert_eq!(contract.todo_exists(U256::ZERO), false);
+        assert!(matches!(
+            contract.get_todo(U256::ZERO).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert!(matches!(
+            contract.update_todo(U256::ZERO, "Sneaky".to_string()).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert!(matches!(
+            contract.complete_todo(U256::ZERO).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert!(matches!(
+            contract.delete_todo(U256::ZERO).unwrap_err(),
+            TodoError::TodoNotFoundError(_)
+        ));
+        assert_eq!(contract.get_total_todos(), U256::ZERO);
+    }
+
+    #[test]
+    fn test_todo_history() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let owner = vm.msg_sender();
+        let user = Address::from([1u8; 20]);
+        vm.set_sender(user);
+        vm.set_block_timestamp(100);
+        let todo_id = contract.create_todo("Draft".to_string()).unwrap();
+
+        vm.set_block_timestamp(150);
+        contract.update_todo(todo_id, "Second draft".to_string()).unwrap();
+
+        // The owner's edit is recorded with the owner as editor
+        vm.set_sender(owner);
+        vm.set_block_timestamp(300);
+        contract.update_todo(todo_id, "Final".to_string()).unwrap();
+
+        // Failed updates leave no trace
+        assert!(contract.update_todo(todo_id, "".to_string()).is_err());
+
+        let history = contract.get_todo_history(todo_id).unwrap();
+        assert_eq!(
+            history,
+            vec![
+                ("Draft".to_string(), user, U256::from(100)),
+                ("Second draft".to_string(), user, U256::from(150)),
+                ("Final".to_string(), owner, U256::from(300)),
+            ]
+        );
+        let (_, text, _, _, _) = contract.get_todo(todo_id).unwrap();
+        assert_eq!(text, "Final");
+    }
+
+    #[test]
+    fn test_create_list() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let user = Address::from([1u8; 20]);
+        vm.set_sender(user);
+        let list_id = contract.create_list("Groceries".to_string()).unwrap();
+        assert_eq!(list_id, U256::from(1));
+        assert!(contract.list_exists(list_id));
+        assert_eq!(contract.get_list(list_id).unwrap(), ("Groceries".to_string(), user));
+        assert_eq!(contract.get_role(list_id, user), ROLE_OWNER);
+
+        let result = contract.create_list("".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::EmptyTextError(_)));
+
+        // Unknown lists are not found
+        let result = contract.get_list(U256::from(999));
+        assert!(matches!(result.unwrap_err(), TodoError::TodoNotFoundError(_)));
+        let result = contract.create_todo_in_list(U256::from(999), "Milk".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::TodoNotFoundError(_)));
+        assert!(!contract.list_exists(U256::ZERO));
+    }
+
+    #[test]
+    fn test_list_roles() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let list_owner = Address::from([1u8; 20]);
+        let viewer = Address::from([2u8; 20]);
+        let editor = Address::from([3u8; 20]);
+        let stranger = Address::from([4u8; 20]);
+        vm.set_sender(list_owner);
+        let list_id = contract.create_list("Groceries".to_string()).unwrap();
+        contract.share_list(list_id, viewer, ROLE_VIEWER).unwrap();
+        contract.share_list(list_id, editor, ROLE_EDITOR).unwrap();
+
+        // Editors can add todos to the list, viewers and strangers can't
+        vm.set_sender(editor);
+        let todo_id = contract.create_todo_in_list(list_id, "Milk".to_string()).unwrap();
+        assert_eq!(contract.get_todo_list(todo_id).unwrap(), list_id);
+        for user in [viewer, stranger] {
+            vm.set_sender(user);
+            let result = contract.create_todo_in_list(list_id, "Eggs".to_string());
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+            let result = contract.update_todo(todo_id, "Eggs".to_string());
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+            let result = contract.complete_todo(todo_id);
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+            let result = contract.delete_todo(todo_id);
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        }
+
+        // The list owner can edit todos other collaborators created
+        vm.set_sender(list_owner);
+        contract.update_todo(todo_id, "Oat milk".to_string()).unwrap();
+        contract.complete_todo(todo_id).unwrap();
+        let (_, text, completed, creator, _) = contract.get_todo(todo_id).unwrap();
+        assert_eq!(text, "Oat milk");
+        assert!(completed);
+        assert_eq!(creator, editor);
+
+        // Personal todos stay out of the list
+        vm.set_sender(editor);
+        let personal_id = contract.create_todo("Call mum".to_string()).unwrap();
+        assert_eq!(contract.get_todo_list(personal_id).unwrap(), U256::ZERO);
+        vm.set_sender(list_owner);
+        let result = contract.update_todo(personal_id, "Call dad".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+    }
+
+    #[test]
+    fn test_list_read_access() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let contract_owner = vm.msg_sender();
+        let list_owner = Address::from([1u8; 20]);
+        let viewer = Address::from([2u8; 20]);
+        let stranger = Address::from([4u8; 20]);
+        vm.set_sender(list_owner);
+        let list_id = contract.create_list("Groceries".to_string()).unwrap();
+        contract.share_list(list_id, viewer, ROLE_VIEWER).unwrap();
+        let todo_id = contract.create_todo_in_list(list_id, "Milk".to_string()).unwrap();
+
+        // Viewers can read the list's todos
+        vm.set_sender(viewer);
+        let (_, text, _, creator, _) = contract.get_todo(todo_id).unwrap();
+        assert_eq!((text.as_str(), creator), ("Milk", list_owner));
+        assert_eq!(contract.get_todo_history(todo_id).unwrap().len(), 1);
+
+        // Anyone without a role can't, the contract owner included
+        for user in [stranger, contract_owner] {
+            vm.set_sender(user);
+            let result = contract.get_todo(todo_id);
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+            let result = contract.get_todo_history(todo_id);
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        }
+
+        // The contract owner can't change the list's todos either
+        let result = contract.update_todo(todo_id, "Eggs".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        let result = contract.complete_todo(todo_id);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        let result = contract.delete_todo(todo_id);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        let result = contract.create_todo_in_list(list_id, "Eggs".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+    }
+
+    #[test]
+    fn test_list_delete_permissions() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let list_owner = Address::from([1u8; 20]);
+        let admin = Address::from([2u8; 20]);
+        let editor = Address::from([3u8; 20]);
+        let other_editor = Address::from([4u8; 20]);
+        vm.set_sender(list_owner);
+        let list_id = contract.create_list("Chores".to_string()).unwrap();
+        contract.share_list(list_id, admin, ROLE_ADMIN).unwrap();
+        contract.share_list(list_id, editor, ROLE_EDITOR).unwrap();
+        contract.share_list(list_id, other_editor, ROLE_EDITOR).unwrap();
+
+        vm.set_sender(editor);
+        let first_id = contract.create_todo_in_list(list_id, "Dishes".to_string()).unwrap();
+        let second_id = contract.create_todo_in_list(list_id, "Laundry".to_string()).unwrap();
+
+        // Editors can only delete their own todos
+        vm.set_sender(other_editor);
+        let result = contract.delete_todo(first_id);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        vm.set_sender(editor);
+        contract.delete_todo(first_id).unwrap();
+
+        // Admins can delete any
+        vm.set_sender(admin);
+        contract.delete_todo(second_id).unwrap();
+        assert!(!contract.todo_exists(second_id));
+        assert_eq!(contract.get_todo_count_of(editor), U256::ZERO);
+    }
+
+    #[test]
+    fn test_share_and_revoke() {
+        let vm = TestVM::default();
+        let mut contract = TodoContract::from(&vm);
+        contract.constructor();
+
+        let list_owner = Address::from([1u8; 20]);
+        let admin = Address::from([2u8; 20]);
+        let other_admin = Address::from([3u8; 20]);
+        let editor = Address::from([4u8; 20]);
+        vm.set_sender(list_owner);
+        let list_id = contract.create_list("Team".to_string()).unwrap();
+        contract.share_list(list_id, admin, ROLE_ADMIN).unwrap();
+        contract.share_list(list_id, other_admin, ROLE_ADMIN).unwrap();
+
+        // Roles must be a collaborator role
+        for role in [ROLE_NONE, ROLE_OWNER, 9] {
+            let result = contract.share_list(list_id, editor, role);
+            assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        }
+
+        // Admins share as viewer or editor, but can't add or remove admins
+        vm.set_sender(admin);
+        contract.share_list(list_id, editor, ROLE_VIEWER).unwrap();
+        contract.share_list(list_id, editor, ROLE_EDITOR).unwrap();
+        assert_eq!(contract.get_role(list_id, editor), ROLE_EDITOR);
+        let result = contract.share_list(list_id, editor, ROLE_ADMIN);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        let result = contract.revoke(list_id, other_admin);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+        let result = contract.revoke(list_id, list_owner);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+
+        // Editors can't share at all
+        vm.set_sender(editor);
+        let result = contract.share_list(list_id, Address::from([5u8; 20]), ROLE_VIEWER);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+
+        // Revoked editors lose write access
+        vm.set_sender(admin);
+        contract.revoke(list_id, editor).unwrap();
+        assert_eq!(contract.get_role(list_id, editor), ROLE_NONE);
+        vm.set_sender(editor);
+        let result = contract.create_todo_in_list(list_id, "Standup".to_string());
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+
+        // The owner can remove admins, but not themselves
+        vm.set_sender(list_owner);
+        contract.revoke(list_id, other_admin).unwrap();
+        assert_eq!(contract.get_role(list_id, other_admin), ROLE_NONE);
+        let result = contract.revoke(list_id, list_owner);
+        assert!(matches!(result.unwrap_err(), TodoError::UnauthorizedError(_)));
+
+        let result = contract.share_list(U256::from(999), editor, ROLE_VIEWER);
+        assert!(matches!(result.unwrap_err(), TodoError::TodoNotFoundError(_)));
+    }
 }
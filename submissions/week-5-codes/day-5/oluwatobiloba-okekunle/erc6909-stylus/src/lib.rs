@@ -6,8 +6,9 @@
 extern crate alloc;
 
 use alloc::{string::String, vec::Vec};
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{aliases::U8, Address, U256};
 use alloy_sol_types::sol;
+use strings_utils_stylus::to_string;
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::prelude::*;
 
@@ -24,7 +25,20 @@ sol_storage! {
       mapping(address => mapping(address => bool)) _operator_approvals;
       mapping(address => mapping(address => mapping(uint256 => uint256))) _allowances;
 
+      // Metadata extension
+      mapping(uint256 => string) _token_names;
+      mapping(uint256 => string) _token_symbols;
+      mapping(uint256 => uint8) _token_decimals;
 
+      // Content URI extension
+      string _contract_uri;
+      string _base_uri;
+      mapping(uint256 => string) _token_uris;
+
+      // Token supply extension, with ids that have a supply kept in a list
+      mapping(uint256 => uint256) _total_supplies;
+      uint256[] _token_ids;
+      mapping(uint256 => uint256) _token_id_positions; // index in `_token_ids` + 1
     }
 }
 
@@ -46,10 +60,22 @@ sol! {
     #[derive(Debug)]
     error ERC6909InsufficientAllowance(address owner, uint256 allowance, uint256 needed, uint256 id);
 
+    #[derive(Debug)]
+    error ERC6909OutOfBoundsIndex(uint256 index);
+
+    #[derive(Debug)]
+    error ERC6909InvalidArrayLength(uint256 ids_length, uint256 values_length);
+
     event Transfer(address indexed from, address indexed to, uint256 indexed id, uint256 value);
     event Approval(address indexed owner, address indexed spender, uint256 indexed id, uint256 value);
     event OperatorSet(address indexed owner, address indexed sender, bool approved);
 
+    event ERC6909NameUpdated(uint256 indexed id, string new_name);
+    event ERC6909SymbolUpdated(uint256 indexed id, string new_symbol);
+    event ERC6909DecimalsUpdated(uint256 indexed id, uint8 new_decimals);
+    event ContractURIUpdated();
+    event URI(string value, uint256 indexed id);
+    event BaseURIUpdated(string value);
 }
 
 // Define the Rust-equivalent of the Solidity errors
@@ -61,6 +87,8 @@ pub enum ERC6909Error {
     ERC6909InvalidApprover(ERC6909InvalidApprover),
     ERC6909InvalidSpender(ERC6909InvalidSpender),
     ERC6909InsufficientAllowance(ERC6909InsufficientAllowance),
+    ERC6909OutOfBoundsIndex(ERC6909OutOfBoundsIndex),
+    ERC6909InvalidArrayLength(ERC6909InvalidArrayLength),
 }
 
 impl ERC6909 {
@@ -185,6 +213,23 @@ impl ERC6909 {
             self._balance.setter(to).insert(id, balance + value);
         }
 
+        // Mints and burns change the supply of `id`
+        if from.is_zero() != to.is_zero() {
+            let supply = self._total_supplies.get(id);
+            let new_supply = if from.is_zero() {
+                supply + value
+            } else {
+                supply - value
+            };
+            self._total_supplies.insert(id, new_supply);
+
+            if supply.is_zero() && !new_supply.is_zero() {
+                self._add_token_id(id);
+            } else if !supply.is_zero() && new_supply.is_zero() {
+                self._remove_token_id(id);
+            }
+        }
+
         log(
             self.vm(),
             Transfer {
@@ -240,6 +285,62 @@ impl ERC6909 {
         self._update(from, to, id, value)?;
         Ok(())
     }
+
+    fn _check_array_lengths(ids: &[U256], values: &[U256]) -> Result<(), ERC6909Error> {
+        if ids.len() != values.len() {
+            return Err(ERC6909Error::ERC6909InvalidArrayLength(
+                ERC6909InvalidArrayLength {
+                    ids_length: U256::from(ids.len()),
+                    values_length: U256::from(values.len()),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn _add_token_id(&mut self, id: U256) {
+        self._token_ids.push(id);
+        let position = U256::from(self._token_ids.len());
+        self._token_id_positions.insert(id, position);
+    }
+
+    // Swap `id` with the last id and pop it, so the list stays dense
+    fn _remove_token_id(&mut self, id: U256) {
+        let index = self._token_id_positions.get(id).to::<usize>() - 1;
+        let last_index = self._token_ids.len() - 1;
+        if index != last_index {
+            let last_id = self._token_ids.get(last_index).unwrap();
+            self._token_ids.setter(index).unwrap().set(last_id);
+            self._token_id_positions
+                .insert(last_id, U256::from(index + 1));
+        }
+        self._token_ids.pop();
+        self._token_id_positions.delete(id);
+    }
+
+    fn _only_owner(&self) -> Result<(), ERC6909Error> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(ERC6909Error::ERC6909InvalidSender(ERC6909InvalidSender {
+                sender: self.vm().msg_sender(),
+            }));
+        }
+        Ok(())
+    }
+
+    fn _set_name(&mut self, id: U256, new_name: String) {
+        self._token_names.setter(id).set_str(&new_name);
+        log(self.vm(), ERC6909NameUpdated { id, new_name });
+    }
+
+    fn _set_symbol(&mut self, id: U256, new_symbol: String) {
+        self._token_symbols.setter(id).set_str(&new_symbol);
+        log(self.vm(), ERC6909SymbolUpdated { id, new_symbol });
+    }
+
+    fn _set_decimals(&mut self, id: U256, new_decimals: u8) {
+        self._token_decimals.insert(id, U8::from(new_decimals));
+        log(self.vm(), ERC6909DecimalsUpdated { id, new_decimals });
+    }
 }
 
 /// Declare that `Counter` is a contract with the following external methods.
@@ -264,15 +365,123 @@ impl ERC6909 {
         u8::from(18)
     }
 
-    /// Mint new tokens (only owner can mint)
-    fn mint(&mut self, to: Address, id: U256, value: U256) -> Result<(), ERC6909Error> {
-        // Check if caller is owner
-        if self.vm().msg_sender() != self.owner.get() {
-            return Err(ERC6909Error::ERC6909InvalidSender(ERC6909InvalidSender {
-                sender: self.vm().msg_sender(),
-            }));
+    /// Name of token `id`, empty until the owner sets it
+    #[selector(name = "name")]
+    fn token_name(&self, id: U256) -> String {
+        self._token_names.getter(id).get_string()
+    }
+
+    /// Symbol of token `id`, empty until the owner sets it
+    #[selector(name = "symbol")]
+    fn token_symbol(&self, id: U256) -> String {
+        self._token_symbols.getter(id).get_string()
+    }
+
+    /// Decimals of token `id`, zero until the owner sets them
+    #[selector(name = "decimals")]
+    fn token_decimals(&self, id: U256) -> u8 {
+        self._token_decimals.get(id).to::<u8>()
+    }
+
+    /// Set the name, symbol and decimals of token `id` (only owner)
+    fn set_token_metadata(
+        &mut self,
+        id: U256,
+        name: String,
+        symbol: String,
+        decimals: u8,
+    ) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
+        self._set_name(id, name);
+        self._set_symbol(id, symbol);
+        self._set_decimals(id, decimals);
+        Ok(())
+    }
+
+    #[selector(name = "contractURI")]
+    fn contract_uri(&self) -> String {
+        self._contract_uri.get_string()
+    }
+
+    /// URI of token `id`: its own URI if set, otherwise the base URI followed by the id
+    #[selector(name = "tokenURI")]
+    fn token_uri(&self, id: U256) -> String {
+        let uri = self._token_uris.getter(id).get_string();
+        if !uri.is_empty() {
+            return uri;
+        }
+
+        let base_uri = self._base_uri.get_string();
+        if base_uri.is_empty() {
+            return base_uri;
+        }
+        base_uri + &to_string(id)
+    }
+
+    #[selector(name = "setContractURI")]
+    fn set_contract_uri(&mut self, uri: String) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
+        self._contract_uri.set_str(uri);
+        log(self.vm(), ContractURIUpdated {});
+        Ok(())
+    }
+
+    /// Set the URI of a single token, overriding the base URI (only owner)
+    #[selector(name = "setTokenURI")]
+    fn set_token_uri(&mut self, id: U256, uri: String) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
+        self._token_uris.setter(id).set_str(&uri);
+        log(self.vm(), URI { value: uri, id });
+        Ok(())
+    }
+
+    /// Set the prefix of token URIs without their own URI (only owner)
+    #[selector(name = "setBaseURI")]
+    fn set_base_uri(&mut self, base_uri: String) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
+        self._base_uri.set_str(&base_uri);
+        log(self.vm(), BaseURIUpdated { value: base_uri });
+        Ok(())
+    }
+
+    /// Amount of token `id` in circulation
+    fn total_supply(&self, id: U256) -> U256 {
+        self._total_supplies.get(id)
+    }
+
+    /// Number of ids with a non-zero supply
+    fn token_count(&self) -> U256 {
+        U256::from(self._token_ids.len())
+    }
+
+    /// Id at `index` among ids with a non-zero supply, in no particular order
+    fn token_by_index(&self, index: U256) -> Result<U256, ERC6909Error> {
+        let id = if index < U256::from(self._token_ids.len()) {
+            self._token_ids.get(index)
+        } else {
+            None
+        };
+        id.ok_or(ERC6909Error::ERC6909OutOfBoundsIndex(
+            ERC6909OutOfBoundsIndex { index },
+        ))
+    }
+
+    /// Up to `limit` ids with a non-zero supply, starting at `offset`
+    fn token_ids(&self, offset: U256, limit: U256) -> Vec<U256> {
+        let len = U256::from(self._token_ids.len());
+        let end = offset.saturating_add(limit).min(len);
+        let mut ids = Vec::new();
+        let mut index = offset;
+        while index < end {
+            ids.push(self._token_ids.get(index).unwrap());
+            index += U256::from(1);
         }
+        ids
+    }
 
+    /// Mint new tokens (only owner can mint)
+    fn mint(&mut self, to: Address, id: U256, value: U256) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
         self._mint(to, id, value)
     }
 
@@ -317,30 +526,102 @@ impl ERC6909 {
     fn burn(&mut self, id: U256, value: U256) -> Result<(), ERC6909Error> {
         self._burn(self.vm().msg_sender(), id, value)
     }
+
+    // The batch functions below apply each (id, value) pair in turn, emitting one event per
+    // id. If any pair fails the whole call reverts, so either every change lands or none does.
+
+    /// Transfer several token ids to `receiver` at once
+    fn batch_transfer(
+        &mut self,
+        receiver: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+    ) -> Result<(), ERC6909Error> {
+        Self::_check_array_lengths(&ids, &values)?;
+        let sender = self.vm().msg_sender();
+        for (id, value) in ids.into_iter().zip(values) {
+            self._transfer(sender, receiver, id, value)?;
+        }
+        Ok(())
+    }
+
+    /// Transfer several token ids from `sender`, checking the caller's allowance for each id
+    /// unless it is `sender` itself or one of its operators
+    fn batch_transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+    ) -> Result<(), ERC6909Error> {
+        Self::_check_array_lengths(&ids, &values)?;
+        let caller = self.vm().msg_sender();
+        let approved = sender == caller || self.is_operator(sender, caller);
+        for (id, value) in ids.into_iter().zip(values) {
+            if !approved {
+                self._spend_allowance(sender, caller, id, value)?;
+            }
+            self._transfer(sender, receiver, id, value)?;
+        }
+        Ok(())
+    }
+
+    /// Mint several token ids to `to` at once (only owner can mint)
+    fn batch_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+    ) -> Result<(), ERC6909Error> {
+        self._only_owner()?;
+        Self::_check_array_lengths(&ids, &values)?;
+        for (id, value) in ids.into_iter().zip(values) {
+            self._mint(to, id, value)?;
+        }
+        Ok(())
+    }
+
+    /// Burn several of the caller's token ids at once
+    fn batch_burn(&mut self, ids: Vec<U256>, values: Vec<U256>) -> Result<(), ERC6909Error> {
+        Self::_check_array_lengths(&ids, &values)?;
+        let owner = self.vm().msg_sender();
+        for (id, value) in ids.into_iter().zip(values) {
+            self._burn(owner, id, value)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use alloy_primitives::{address, U256};
+    use alloy_sol_types::SolEvent;
     use stylus_sdk::testing::TestVM;
 
     #[no_mangle]
     pub unsafe extern "C" fn emit_log(_pointer: *const u8, _len: usize, _: usize) {}
 
     fn setup() -> (ERC6909, Address, Address, Address) {
+        let (_, contract, owner, alice, bob) = setup_with_vm();
+        (contract, owner, alice, bob)
+    }
+
+    // Same as `setup`, with the owner as msg_sender so owner-only functions can be called
+    fn setup_with_vm() -> (TestVM, ERC6909, Address, Address, Address) {
         let owner = address!("0x1111111111111111111111111111111111111111");
         let alice = address!("0x2222222222222222222222222222222222222222");
         let bob = address!("0x3333333333333333333333333333333333333333");
 
         let vm = TestVM::default();
+        vm.set_sender(owner);
         let mut contract = ERC6909::from(&vm);
         contract.owner.set(owner);
 
         contract.constructor(String::from("Test"), String::from("TT"));
         contract.owner.set(owner);
 
-        (contract, owner, alice, bob)
+        (vm, contract, owner, alice, bob)
     }
 
     #[test]
@@ -702,4 +983,314 @@ mod test {
         let result = contract._spend_allowance(alice, bob, token_id, spend_amount);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_token_metadata() {
+        let (vm, mut contract, _, alice, _) = setup_with_vm();
+        let token_id = U256::from(1);
+
+        // Unset metadata is empty
+        assert_eq!(contract.token_name(token_id), String::new());
+        assert_eq!(contract.token_symbol(token_id), String::new());
+        assert_eq!(contract.token_decimals(token_id), 0);
+
+        let result =
+            contract.set_token_metadata(token_id, "Gold".to_string(), "GLD".to_string(), 6);
+        assert!(result.is_ok());
+        assert_eq!(contract.token_name(token_id), "Gold".to_string());
+        assert_eq!(contract.token_symbol(token_id), "GLD".to_string());
+        assert_eq!(contract.token_decimals(token_id), 6);
+
+        // Other ids and the collection metadata are untouched
+        assert_eq!(contract.token_name(U256::from(2)), String::new());
+        assert_eq!(contract.name(), "Test".to_string());
+        assert_eq!(contract.decimals(), 18);
+
+        // Only the owner can set metadata
+        vm.set_sender(alice);
+        let result = contract.set_token_metadata(token_id, "Lead".to_string(), "LD".to_string(), 0);
+        assert!(matches!(result, Err(ERC6909Error::ERC6909InvalidSender(_))));
+        assert_eq!(contract.token_name(token_id), "Gold".to_string());
+    }
+
+    #[test]
+    fn test_content_uri() {
+        let (vm, mut contract, _, alice, _) = setup_with_vm();
+
+        assert_eq!(contract.contract_uri(), String::new());
+        assert_eq!(contract.token_uri(U256::from(7)), String::new());
+
+        contract
+            .set_contract_uri("ipfs://contract".to_string())
+            .unwrap();
+        assert_eq!(contract.contract_uri(), "ipfs://contract".to_string());
+
+        // Ids without their own URI fall back to the base URI followed by the id
+        contract
+            .set_base_uri("https://example.com/tokens/".to_string())
+            .unwrap();
+        assert_eq!(
+            contract.token_uri(U256::from(7)),
+            "https://example.com/tokens/7".to_string()
+        );
+        assert_eq!(
+            contract.token_uri(U256::from(123456789)),
+            "https://example.com/tokens/123456789".to_string()
+        );
+        let (topics, data) = vm.get_emitted_logs().last().unwrap().clone();
+        assert_eq!(topics[0], BaseURIUpdated::SIGNATURE_HASH);
+        let event = BaseURIUpdated::decode_raw_log(topics, &data, true).unwrap();
+        assert_eq!(event.value, "https://example.com/tokens/".to_string());
+
+        contract
+            .set_token_uri(U256::from(7), "ipfs://seven".to_string())
+            .unwrap();
+        assert_eq!(
+            contract.token_uri(U256::from(7)),
+            "ipfs://seven".to_string()
+        );
+        assert_eq!(
+            contract.token_uri(U256::from(8)),
+            "https://example.com/tokens/8".to_string()
+        );
+
+        vm.set_sender(alice);
+        assert!(contract
+            .set_contract_uri("ipfs://other".to_string())
+            .is_err());
+        assert!(contract.set_base_uri("ipfs://other/".to_string()).is_err());
+        assert!(contract
+            .set_token_uri(U256::from(7), "ipfs://other".to_string())
+            .is_err());
+        assert_eq!(
+            contract.token_uri(U256::from(7)),
+            "ipfs://seven".to_string()
+        );
+    }
+
+    #[test]
+    fn test_total_supply() {
+        let (mut contract, _, alice, bob) = setup();
+        let token_id = U256::from(1);
+
+        assert_eq!(contract.total_supply(token_id), U256::ZERO);
+
+        contract._mint(alice, token_id, U256::from(1000)).unwrap();
+        contract._mint(bob, token_id, U256::from(500)).unwrap();
+        assert_eq!(contract.total_supply(token_id), U256::from(1500));
+
+        // Transfers don't change the supply
+        contract
+            ._transfer(alice, bob, token_id, U256::from(300))
+            .unwrap();
+        assert_eq!(contract.total_supply(token_id), U256::from(1500));
+
+        contract._burn(bob, token_id, U256::from(800)).unwrap();
+        assert_eq!(contract.total_supply(token_id), U256::from(700));
+        assert_eq!(contract.total_supply(U256::from(2)), U256::ZERO);
+    }
+
+    #[test]
+    fn test_token_id_enumeration() {
+        let (mut contract, _, alice, bob) = setup();
+
+        assert_eq!(contract.token_count(), U256::ZERO);
+        assert!(contract.token_by_index(U256::ZERO).is_err());
+
+        for id in 1..=3 {
+            contract
+                ._mint(alice, U256::from(id), U256::from(100))
+                .unwrap();
+        }
+        // Minting an existing id doesn't list it twice
+        contract._mint(bob, U256::from(2), U256::from(100)).unwrap();
+        assert_eq!(contract.token_count(), U256::from(3));
+        assert_eq!(
+            contract.token_ids(U256::ZERO, U256::from(10)),
+            vec![U256::from(1), U256::from(2), U256::from(3)]
+        );
+        assert_eq!(
+            contract.token_ids(U256::from(1), U256::from(1)),
+            vec![U256::from(2)]
+        );
+        assert_eq!(
+            contract.token_ids(U256::from(5), U256::MAX),
+            Vec::<U256>::new()
+        );
+
+        // Burning the whole supply removes the id, the last id takes its place
+        contract
+            ._burn(alice, U256::from(1), U256::from(100))
+            .unwrap();
+        assert_eq!(contract.token_count(), U256::from(2));
+        assert_eq!(contract.token_by_index(U256::ZERO).unwrap(), U256::from(3));
+        assert_eq!(
+            contract.token_by_index(U256::from(1)).unwrap(),
+            U256::from(2)
+        );
+        let result = contract.token_by_index(U256::from(2));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909OutOfBoundsIndex(_))
+        ));
+
+        // An id keeps its place while part of its supply remains
+        contract
+            ._burn(alice, U256::from(2), U256::from(100))
+            .unwrap();
+        assert_eq!(contract.token_count(), U256::from(2));
+
+        contract._burn(bob, U256::from(2), U256::from(100)).unwrap();
+        contract
+            ._burn(alice, U256::from(3), U256::from(100))
+            .unwrap();
+        assert_eq!(contract.token_count(), U256::ZERO);
+
+        // A burned out id can be minted again
+        contract._mint(bob, U256::from(1), U256::from(1)).unwrap();
+        assert_eq!(
+            contract.token_ids(U256::ZERO, U256::from(10)),
+            vec![U256::from(1)]
+        );
+    }
+
+    fn ids(ids: &[u64]) -> Vec<U256> {
+        ids.iter().map(|id| U256::from(*id)).collect()
+    }
+
+    #[test]
+    fn test_batch_mint() {
+        let (vm, mut contract, _, alice, _) = setup_with_vm();
+
+        let result = contract.batch_mint(alice, ids(&[1, 2, 3]), ids(&[100, 200, 300]));
+        assert!(result.is_ok());
+        assert_eq!(contract.balance_of(alice, U256::from(1)), U256::from(100));
+        assert_eq!(contract.balance_of(alice, U256::from(2)), U256::from(200));
+        assert_eq!(contract.balance_of(alice, U256::from(3)), U256::from(300));
+        assert_eq!(contract.token_count(), U256::from(3));
+
+        // One Transfer event per id
+        let transfers = vm
+            .get_emitted_logs()
+            .iter()
+            .filter(|(topics, _)| topics[0] == Transfer::SIGNATURE_HASH)
+            .count();
+        assert_eq!(transfers, 3);
+
+        // Only the owner can mint
+        vm.set_sender(alice);
+        let result = contract.batch_mint(alice, ids(&[1]), ids(&[100]));
+        assert!(matches!(result, Err(ERC6909Error::ERC6909InvalidSender(_))));
+    }
+
+    #[test]
+    fn test_batch_array_length_mismatch_fails() {
+        let (mut contract, _, alice, bob) = setup();
+
+        let result = contract.batch_mint(alice, ids(&[1, 2]), ids(&[100]));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909InvalidArrayLength(_))
+        ));
+        assert!(contract.batch_transfer(bob, ids(&[1]), ids(&[])).is_err());
+        assert!(contract
+            .batch_transfer_from(alice, bob, ids(&[]), ids(&[1]))
+            .is_err());
+        assert!(contract.batch_burn(ids(&[1, 2, 3]), ids(&[1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_batch_transfer() {
+        let (vm, mut contract, _, alice, bob) = setup_with_vm();
+        contract
+            .batch_mint(alice, ids(&[1, 2]), ids(&[100, 200]))
+            .unwrap();
+
+        vm.set_sender(alice);
+        let result = contract.batch_transfer(bob, ids(&[1, 2, 1]), ids(&[10, 20, 30]));
+        assert!(result.is_ok());
+        assert_eq!(contract.balance_of(alice, U256::from(1)), U256::from(60));
+        assert_eq!(contract.balance_of(alice, U256::from(2)), U256::from(180));
+        assert_eq!(contract.balance_of(bob, U256::from(1)), U256::from(40));
+        assert_eq!(contract.balance_of(bob, U256::from(2)), U256::from(20));
+
+        // Fails as soon as one id is short
+        let result = contract.batch_transfer(bob, ids(&[1, 2]), ids(&[10, 500]));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909InsufficientBalance(_))
+        ));
+
+        let result = contract.batch_transfer(Address::ZERO, ids(&[1]), ids(&[10]));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909InvalidReceiver(_))
+        ));
+    }
+
+    #[test]
+    fn test_batch_transfer_from_checks_allowance_per_id() {
+        let (vm, mut contract, owner, alice, bob) = setup_with_vm();
+        contract
+            .batch_mint(alice, ids(&[1, 2]), ids(&[100, 200]))
+            .unwrap();
+
+        vm.set_sender(alice);
+        contract
+            .approve(bob, U256::from(1), U256::from(50))
+            .unwrap();
+        contract
+            .approve(bob, U256::from(2), U256::from(20))
+            .unwrap();
+
+        vm.set_sender(bob);
+        let result = contract.batch_transfer_from(alice, owner, ids(&[1, 2]), ids(&[50, 20]));
+        assert!(result.is_ok());
+        assert_eq!(contract.allowance(alice, bob, U256::from(1)), U256::ZERO);
+        assert_eq!(contract.allowance(alice, bob, U256::from(2)), U256::ZERO);
+        assert_eq!(contract.balance_of(owner, U256::from(1)), U256::from(50));
+        assert_eq!(contract.balance_of(owner, U256::from(2)), U256::from(20));
+
+        // Each id needs its own allowance
+        vm.set_sender(alice);
+        contract
+            .approve(bob, U256::from(1), U256::from(10))
+            .unwrap();
+        vm.set_sender(bob);
+        let result = contract.batch_transfer_from(alice, owner, ids(&[1, 2]), ids(&[10, 10]));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909InsufficientAllowance(_))
+        ));
+
+        // Operators need no allowance
+        vm.set_sender(alice);
+        contract.set_operator(bob, true).unwrap();
+        vm.set_sender(bob);
+        let result = contract.batch_transfer_from(alice, bob, ids(&[1, 2]), ids(&[40, 180]));
+        assert!(result.is_ok());
+        assert_eq!(contract.balance_of(bob, U256::from(1)), U256::from(40));
+        assert_eq!(contract.balance_of(bob, U256::from(2)), U256::from(180));
+    }
+
+    #[test]
+    fn test_batch_burn() {
+        let (vm, mut contract, _, alice, _) = setup_with_vm();
+        contract
+            .batch_mint(alice, ids(&[1, 2, 3]), ids(&[100, 200, 300]))
+            .unwrap();
+
+        vm.set_sender(alice);
+        let result = contract.batch_burn(ids(&[1, 2]), ids(&[100, 50]));
+        assert!(result.is_ok());
+        assert_eq!(contract.balance_of(alice, U256::from(1)), U256::ZERO);
+        assert_eq!(contract.total_supply(U256::from(2)), U256::from(150));
+        assert_eq!(contract.token_count(), U256::from(2));
+
+        let result = contract.batch_burn(ids(&[3]), ids(&[301]));
+        assert!(matches!(
+            result,
+            Err(ERC6909Error::ERC6909InsufficientBalance(_))
+        ));
+    }
 }
@@ -0,0 +1,398 @@
+//! ERC6909WrapperFactory - ERC-6909 to ERC-20 wrapper factory
+//!
+//! Each token id of an ERC-6909 contract can be given a standalone ERC-20 wrapper: a deployment
+//! of the course Stylus ERC-20 whose ownership has been transferred to this factory. Holders
+//! deposit id balances here and are minted the same amount of the wrapper, and redeem wrapper
+//! tokens to get the id balance back. The factory's balance of an id is always at least the
+//! supply of its wrapper, so every wrapper token can be redeemed. It can be more: holders can
+//! burn wrapper tokens with the ERC-20's own `burn`, and anyone can send id tokens straight to
+//! the factory. That surplus can't be redeemed.
+
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::prelude::*;
+
+sol_storage! {
+    #[entrypoint]
+    pub struct ERC6909WrapperFactory {
+        address owner;
+        // ERC-6909 contract whose ids are wrapped
+        address multi_token;
+        mapping(uint256 => address) wrappers;
+        mapping(address => bool) is_wrapper;
+    }
+}
+
+sol! {
+    #[derive(Debug)]
+    error ERC6909WrapperInvalidSender(address sender);
+
+    #[derive(Debug)]
+    error ERC6909WrapperNotSet(uint256 id);
+
+    #[derive(Debug)]
+    error ERC6909WrapperAlreadySet(uint256 id, address wrapper);
+
+    #[derive(Debug)]
+    error ERC6909WrapperInvalidToken(address wrapper);
+
+    #[derive(Debug)]
+    error ERC6909WrapperCallFailed(address target, bytes reason);
+
+    event WrapperSet(uint256 indexed id, address indexed wrapper);
+    event Deposit(address indexed account, uint256 indexed id, uint256 value);
+    event Redeem(address indexed account, uint256 indexed id, uint256 value);
+
+    interface IERC6909 {
+        function transfer(address receiver, uint256 id, uint256 value) external;
+        function transferFrom(address sender, address receiver, uint256 id, uint256 value) external;
+    }
+
+    // The parts of the course Stylus ERC-20 the factory relies on
+    interface IERC20Wrapper {
+        function owner() external view returns (address);
+        function totalSupply() external view returns (uint256);
+        function mint(address to, uint256 value) external;
+        function burn(uint256 value) external;
+        function transferFrom(address from, address to, uint256 value) external;
+    }
+}
+
+#[derive(SolidityError, Debug)]
+pub enum ERC6909WrapperError {
+    ERC6909WrapperInvalidSender(ERC6909WrapperInvalidSender),
+    ERC6909WrapperNotSet(ERC6909WrapperNotSet),
+    ERC6909WrapperAlreadySet(ERC6909WrapperAlreadySet),
+    ERC6909WrapperInvalidToken(ERC6909WrapperInvalidToken),
+    ERC6909WrapperCallFailed(ERC6909WrapperCallFailed),
+}
+
+impl ERC6909WrapperFactory {
+    fn _only_owner(&self) -> Result<(), ERC6909WrapperError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(ERC6909WrapperError::ERC6909WrapperInvalidSender(
+                ERC6909WrapperInvalidSender {
+                    sender: self.vm().msg_sender(),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn _wrapper(&self, id: U256) -> Result<Address, ERC6909WrapperError> {
+        let wrapper = self.wrappers.get(id);
+        if wrapper.is_zero() {
+            return Err(ERC6909WrapperError::ERC6909WrapperNotSet(
+                ERC6909WrapperNotSet { id },
+            ));
+        }
+        Ok(wrapper)
+    }
+
+    // Call `target`, passing its revert data back inside `ERC6909WrapperCallFailed`
+    fn _call(&mut self, target: Address, data: &[u8]) -> Result<Vec<u8>, ERC6909WrapperError> {
+        self.vm().call(&self, target, data).map_err(|e| {
+            ERC6909WrapperError::ERC6909WrapperCallFailed(ERC6909WrapperCallFailed {
+                target,
+                reason: Bytes::from(Vec::from(e)),
+            })
+        })
+    }
+
+    // Read a 32-byte word from a view function, `None` if the call fails
+    fn _static_call_word(&self, target: Address, data: &[u8]) -> Option<[u8; 32]> {
+        let output = self.vm().static_call(&self, target, data).ok()?;
+        output.get(..32)?.try_into().ok()
+    }
+}
+
+#[public]
+impl ERC6909WrapperFactory {
+    #[constructor]
+    pub fn constructor(&mut self, multi_token: Address) {
+        self.owner.set(self.vm().msg_sender());
+        self.multi_token.set(multi_token);
+    }
+
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    pub fn multi_token(&self) -> Address {
+        self.multi_token.get()
+    }
+
+    /// ERC-20 wrapping token `id`, or the zero address if there is none
+    pub fn wrapper_of(&self, id: U256) -> Address {
+        self.wrappers.get(id)
+    }
+
+    /// Designate `wrapper` as the ERC-20 for token `id` (only owner)
+    ///
+    /// The wrapper must already be owned by this factory, have no supply and not wrap another id.
+    pub fn set_wrapper(&mut self, id: U256, wrapper: Address) -> Result<(), ERC6909WrapperError> {
+        self._only_owner()?;
+
+        let current = self.wrappers.get(id);
+        if !current.is_zero() {
+            return Err(ERC6909WrapperError::ERC6909WrapperAlreadySet(
+                ERC6909WrapperAlreadySet {
+                    id,
+                    wrapper: current,
+                },
+            ));
+        }
+
+        let invalid =
+            ERC6909WrapperError::ERC6909WrapperInvalidToken(ERC6909WrapperInvalidToken { wrapper });
+        if wrapper.is_zero() || self.is_wrapper.get(wrapper) {
+            return Err(invalid);
+        }
+
+        let owner = self._static_call_word(wrapper, &IERC20Wrapper::ownerCall {}.abi_encode());
+        let supply =
+            self._static_call_word(wrapper, &IERC20Wrapper::totalSupplyCall {}.abi_encode());
+        let owned = owner
+            .is_some_and(|word| Address::from_word(word.into()) == self.vm().contract_address());
+        if !owned || supply != Some([0; 32]) {
+            return Err(invalid);
+        }
+
+        self.wrappers.insert(id, wrapper);
+        self.is_wrapper.insert(wrapper, true);
+        log(self.vm(), WrapperSet { id, wrapper });
+        Ok(())
+    }
+
+    /// Move `value` of token `id` from the caller into the factory and mint as much of its wrapper
+    ///
+    /// The caller must first approve the factory for `id` on the ERC-6909 contract, or make it
+    /// an operator.
+    pub fn deposit(&mut self, id: U256, value: U256) -> Result<(), ERC6909WrapperError> {
+        let wrapper = self._wrapper(id)?;
+        let account = self.vm().msg_sender();
+        let factory = self.vm().contract_address();
+
+        let pull = IERC6909::transferFromCall {
+            sender: account,
+            receiver: factory,
+            id,
+            value,
+        };
+        self._call(self.multi_token.get(), &pull.abi_encode())?;
+
+        let mint = IERC20Wrapper::mintCall { to: account, value };
+        self._call(wrapper, &mint.abi_encode())?;
+
+        log(self.vm(), Deposit { account, id, value });
+        Ok(())
+    }
+
+    /// Burn `value` of the caller's wrapper tokens and send back as much of token `id`
+    ///
+    /// The caller must first approve the factory for `value` on the wrapper.
+    pub fn redeem(&mut self, id: U256, value: U256) -> Result<(), ERC6909WrapperError> {
+        let wrapper = self._wrapper(id)?;
+        let account = self.vm().msg_sender();
+        let factory = self.vm().contract_address();
+
+        let pull = IERC20Wrapper::transferFromCall {
+            from: account,
+            to: factory,
+            value,
+        };
+        self._call(wrapper, &pull.abi_encode())?;
+        self._call(wrapper, &IERC20Wrapper::burnCall { value }.abi_encode())?;
+
+        let release = IERC6909::transferCall {
+            receiver: account,
+            id,
+            value,
+        };
+        self._call(self.multi_token.get(), &release.abi_encode())?;
+
+        log(self.vm(), Redeem { account, id, value });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_primitives::{address, B256};
+    use stylus_sdk::testing::TestVM;
+
+    const FACTORY: Address = address!("0xFAC7000000000000000000000000000000000000");
+    const MULTI_TOKEN: Address = address!("0x6909000000000000000000000000000000000000");
+    const WRAPPER: Address = address!("0x2020000000000000000000000000000000000000");
+    const OWNER: Address = address!("0x1111111111111111111111111111111111111111");
+    const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+
+    fn setup() -> (TestVM, ERC6909WrapperFactory) {
+        let vm = TestVM::default();
+        vm.set_contract_address(FACTORY);
+        vm.set_sender(OWNER);
+        let mut contract = ERC6909WrapperFactory::from(&vm);
+        contract.constructor(MULTI_TOKEN);
+        (vm, contract)
+    }
+
+    // Make `wrapper` look like an ERC-20 with the given owner and supply
+    fn mock_wrapper(vm: &TestVM, wrapper: Address, owner: Address, supply: U256) {
+        let owner_call = IERC20Wrapper::ownerCall {}.abi_encode();
+        vm.mock_static_call(wrapper, owner_call, Ok(owner.into_word().to_vec()));
+        let supply_call = IERC20Wrapper::totalSupplyCall {}.abi_encode();
+        vm.mock_static_call(wrapper, supply_call, Ok(supply.to_be_bytes_vec()));
+    }
+
+    fn setup_with_wrapper(id: U256) -> (TestVM, ERC6909WrapperFactory) {
+        let (vm, mut contract) = setup();
+        mock_wrapper(&vm, WRAPPER, FACTORY, U256::ZERO);
+        contract.set_wrapper(id, WRAPPER).unwrap();
+        (vm, contract)
+    }
+
+    #[test]
+    fn test_constructor() {
+        let (_, contract) = setup();
+        assert_eq!(contract.owner(), OWNER);
+        assert_eq!(contract.multi_token(), MULTI_TOKEN);
+        assert_eq!(contract.wrapper_of(U256::from(1)), Address::ZERO);
+    }
+
+    #[test]
+    fn test_set_wrapper() {
+        let id = U256::from(1);
+        let (vm, contract) = setup_with_wrapper(id);
+        assert_eq!(contract.wrapper_of(id), WRAPPER);
+
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].0[1], B256::from(id));
+        assert_eq!(logs[0].0[2], WRAPPER.into_word());
+    }
+
+    #[test]
+    fn test_set_wrapper_failures() {
+        let id = U256::from(1);
+        let (vm, mut contract) = setup_with_wrapper(id);
+
+        // An id keeps its wrapper
+        let other = address!("0x3030000000000000000000000000000000000000");
+        mock_wrapper(&vm, other, FACTORY, U256::ZERO);
+        let result = contract.set_wrapper(id, other);
+        assert!(matches!(
+            result,
+            Err(ERC6909WrapperError::ERC6909WrapperAlreadySet(_))
+        ));
+
+        // A wrapper only wraps one id
+        let result = contract.set_wrapper(U256::from(2), WRAPPER);
+        assert!(matches!(
+            result,
+            Err(ERC6909WrapperError::ERC6909WrapperInvalidToken(_))
+        ));
+
+        // Tokens the factory can't mint, or that already have a supply, are rejected
+        mock_wrapper(&vm, other, OWNER, U256::ZERO);
+        assert!(contract.set_wrapper(U256::from(2), other).is_err());
+        mock_wrapper(&vm, other, FACTORY, U256::from(1));
+        assert!(contract.set_wrapper(U256::from(2), other).is_err());
+        assert!(contract.set_wrapper(U256::from(2), Address::ZERO).is_err());
+
+        // Only the owner designates wrappers
+        mock_wrapper(&vm, other, FACTORY, U256::ZERO);
+        vm.set_sender(ALICE);
+        let result = contract.set_wrapper(U256::from(2), other);
+        assert!(matches!(
+            result,
+            Err(ERC6909WrapperError::ERC6909WrapperInvalidSender(_))
+        ));
+
+        vm.set_sender(OWNER);
+        assert!(contract.set_wrapper(U256::from(2), other).is_ok());
+    }
+
+    #[test]
+    fn test_deposit_and_redeem() {
+        let id = U256::from(7);
+        let value = U256::from(250);
+        let (vm, mut contract) = setup_with_wrapper(id);
+        vm.set_sender(ALICE);
+
+        let pull = IERC6909::transferFromCall {
+            sender: ALICE,
+            receiver: FACTORY,
+            id,
+            value,
+        };
+        vm.mock_call(MULTI_TOKEN, pull.abi_encode(), Ok(vec![]));
+        let mint = IERC20Wrapper::mintCall { to: ALICE, value };
+        vm.mock_call(WRAPPER, mint.abi_encode(), Ok(vec![]));
+        assert!(contract.deposit(id, value).is_ok());
+
+        let pull = IERC20Wrapper::transferFromCall {
+            from: ALICE,
+            to: FACTORY,
+            value,
+        };
+        vm.mock_call(WRAPPER, pull.abi_encode(), Ok(vec![]));
+        let burn = IERC20Wrapper::burnCall { value };
+        vm.mock_call(WRAPPER, burn.abi_encode(), Ok(vec![]));
+        let release = IERC6909::transferCall {
+            receiver: ALICE,
+            id,
+            value,
+        };
+        vm.mock_call(MULTI_TOKEN, release.abi_encode(), Ok(vec![]));
+        assert!(contract.redeem(id, value).is_ok());
+
+        // WrapperSet, Deposit and Redeem
+        assert_eq!(vm.get_emitted_logs().len(), 3);
+    }
+
+    #[test]
+    fn test_deposit_without_wrapper_fails() {
+        let (_, mut contract) = setup();
+        let result = contract.deposit(U256::from(1), U256::from(1));
+        assert!(matches!(
+            result,
+            Err(ERC6909WrapperError::ERC6909WrapperNotSet(_))
+        ));
+        assert!(contract.redeem(U256::from(1), U256::from(1)).is_err());
+    }
+
+    #[test]
+    fn test_failed_calls_revert_with_reason() {
+        let id = U256::from(7);
+        let value = U256::from(250);
+        let (vm, mut contract) = setup_with_wrapper(id);
+        vm.set_sender(ALICE);
+
+        // The ERC-6909 contract rejects the pull, e.g. for a missing allowance
+        let pull = IERC6909::transferFromCall {
+            sender: ALICE,
+            receiver: FACTORY,
+            id,
+            value,
+        };
+        let reason = vec![0xde, 0xad, 0xbe, 0xef];
+        vm.mock_call(MULTI_TOKEN, pull.abi_encode(), Err(reason.clone()));
+
+        match contract.deposit(id, value) {
+            Err(ERC6909WrapperError::ERC6909WrapperCallFailed(e)) => {
+                assert_eq!(e.target, MULTI_TOKEN);
+                assert_eq!(e.reason, Bytes::from(reason));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
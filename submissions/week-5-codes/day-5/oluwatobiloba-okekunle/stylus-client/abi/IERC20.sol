@@ -0,0 +1,103 @@
+/**
+ * This file was automatically generated by Stylus and represents a Rust program.
+ * For more information, please see [The Stylus SDK](https://github.com/OffchainLabs/stylus-sdk-rs).
+ */
+
+// SPDX-License-Identifier: MIT-OR-APACHE-2.0
+pragma solidity ^0.8.23;
+
+interface IERC20  {
+    function name() external view returns (string memory);
+
+    function symbol() external view returns (string memory);
+
+    function decimals() external view returns (uint8);
+
+    function totalSupply() external view returns (uint256);
+
+    function balanceOf(address owner) external view returns (uint256);
+
+    function transfer(address to, uint256 value) external;
+
+    function allowance(address owner, address spender) external view returns (uint256);
+
+    function approve(address spender, uint256 value) external;
+
+    function transferFrom(address from, address to, uint256 value) external;
+
+    function mint(address to, uint256 value) external;
+
+    function burn(uint256 value) external;
+
+    function owner() external view returns (address);
+
+    function transferOwnership(address new_owner) external;
+
+    function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+
+    function nonces(address owner) external view returns (uint256);
+
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+
+    function maxFlashLoan(address token) external view returns (uint256);
+
+    function flashFee(address token, uint256 value) external view returns (uint256);
+
+    function setFlashFee(uint16 fee_bps) external;
+
+    function flashLoan(address receiver, address token, uint256 value, bytes calldata data) external returns (bool);
+
+    function clock() external view returns (uint48);
+
+    function CLOCK_MODE() external view returns (string memory);
+
+    function delegates(address account) external view returns (address);
+
+    function delegate(address delegatee) external;
+
+    function delegateBySig(address delegatee, uint256 nonce, uint256 expiry, uint8 v, bytes32 r, bytes32 s) external;
+
+    function getVotes(address account) external view returns (uint256);
+
+    function getPastVotes(address account, uint256 timepoint) external view returns (uint256);
+
+    function getPastTotalSupply(uint256 timepoint) external view returns (uint256);
+
+    function numCheckpoints(address account) external view returns (uint32);
+
+    function checkpoints(address account, uint32 pos) external view returns (uint48, uint208);
+
+    error ERC20InsufficientBalance(address, uint256, uint256);
+
+    error ERC20InvalidSender(address);
+
+    error ERC20InvalidReciver(address);
+
+    error ERC20InsufficientAllowance(address, uint256, uint256);
+
+    error ERC2612ExpiredSignature(uint256);
+
+    error ERC2612InvalidSigner(address, address);
+
+    error ECDSAInvalidSignature();
+
+    error ECDSAInvalidSignatureS(bytes32);
+
+    error ERC20ExceededSafeSupply(uint256, uint256);
+
+    error ERC5805FutureLookup(uint256, uint48);
+
+    error VotesExpiredSignature(uint256);
+
+    error InvalidAccountNonce(address, uint256);
+
+    error ERC3156UnsupportedToken(address);
+
+    error ERC3156ExceededMaxLoan(uint256);
+
+    error ERC3156InvalidReceiver(address);
+
+    error ERC3156InvalidFee(uint16);
+
+    error OwnableInvalidOwner(address);
+}
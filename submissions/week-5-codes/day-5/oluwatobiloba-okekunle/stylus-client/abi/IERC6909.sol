@@ -0,0 +1,83 @@
+/**
+ * This file was automatically generated by Stylus and represents a Rust program.
+ * For more information, please see [The Stylus SDK](https://github.com/OffchainLabs/stylus-sdk-rs).
+ */
+
+// SPDX-License-Identifier: MIT-OR-APACHE-2.0
+pragma solidity ^0.8.23;
+
+interface IERC6909  {
+    function name() external view returns (string memory);
+
+    function symbol() external view returns (string memory);
+
+    function decimals() external view returns (uint8);
+
+    function name(uint256 id) external view returns (string memory);
+
+    function symbol(uint256 id) external view returns (string memory);
+
+    function decimals(uint256 id) external view returns (uint8);
+
+    function setTokenMetadata(uint256 id, string calldata name, string calldata symbol, uint8 decimals) external;
+
+    function contractURI() external view returns (string memory);
+
+    function tokenURI(uint256 id) external view returns (string memory);
+
+    function setContractURI(string calldata uri) external;
+
+    function setTokenURI(uint256 id, string calldata uri) external;
+
+    function setBaseURI(string calldata base_uri) external;
+
+    function totalSupply(uint256 id) external view returns (uint256);
+
+    function tokenCount() external view returns (uint256);
+
+    function tokenByIndex(uint256 index) external view returns (uint256);
+
+    function tokenIds(uint256 offset, uint256 limit) external view returns (uint256[] memory);
+
+    function mint(address to, uint256 id, uint256 value) external;
+
+    function balanceOf(address owner, uint256 id) external view returns (uint256);
+
+    function allowance(address owner, address spender, uint256 id) external view returns (uint256);
+
+    function isOperator(address owner, address spender) external view returns (bool);
+
+    function approve(address spender, uint256 id, uint256 value) external;
+
+    function setOperator(address spender, bool approved) external;
+
+    function transfer(address receiver, uint256 id, uint256 value) external;
+
+    function transferFrom(address sender, address receiver, uint256 id, uint256 value) external;
+
+    function burn(uint256 id, uint256 value) external;
+
+    function batchTransfer(address receiver, uint256[] memory ids, uint256[] memory values) external;
+
+    function batchTransferFrom(address sender, address receiver, uint256[] memory ids, uint256[] memory values) external;
+
+    function batchMint(address to, uint256[] memory ids, uint256[] memory values) external;
+
+    function batchBurn(uint256[] memory ids, uint256[] memory values) external;
+
+    error ERC6909InsufficientBalance(address, uint256, uint256, uint256);
+
+    error ERC6909InvalidSender(address);
+
+    error ERC6909InvalidReceiver(address);
+
+    error ERC6909InvalidApprover(address);
+
+    error ERC6909InvalidSpender(address);
+
+    error ERC6909InsufficientAllowance(address, uint256, uint256, uint256);
+
+    error ERC6909OutOfBoundsIndex(uint256);
+
+    error ERC6909InvalidArrayLength(uint256, uint256);
+}
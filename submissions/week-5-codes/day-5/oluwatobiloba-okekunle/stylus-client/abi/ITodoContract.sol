@@ -0,0 +1,63 @@
+/**
+ * This file was automatically generated by Stylus and represents a Rust program.
+ * For more information, please see [The Stylus SDK](https://github.com/OffchainLabs/stylus-sdk-rs).
+ */
+
+// SPDX-License-Identifier: MIT-OR-APACHE-2.0
+pragma solidity ^0.8.23;
+
+interface ITodoContract  {
+    function createTodo(string calldata text) external returns (uint256);
+
+    function createTodoInList(uint256 list_id, string calldata text) external returns (uint256);
+
+    function getTodo(uint256 id) external view returns (uint256, string memory, bool, address, uint256);
+
+    function completeTodo(uint256 id) external;
+
+    function updateTodo(uint256 id, string calldata new_text) external;
+
+    function deleteTodo(uint256 id) external;
+
+    function getTotalTodos() external view returns (uint256);
+
+    function getNextId() external view returns (uint256);
+
+    function getOwner() external view returns (address);
+
+    function todoExists(uint256 id) external view returns (bool);
+
+    function getTodoCountOf(address user) external view returns (uint256);
+
+    function getTodosOf(address user, uint256 offset, uint256 limit) external view returns (uint256[] memory);
+
+    function getDeletedAt(uint256 id) external view returns (uint256);
+
+    function getTodoHistory(uint256 id) external view returns ((string,address,uint256)[] memory);
+
+    function transferOwnership(address new_owner) external;
+
+    function createList(string calldata name) external returns (uint256);
+
+    function shareList(uint256 list_id, address user, uint8 role) external;
+
+    function revoke(uint256 list_id, address user) external;
+
+    function listExists(uint256 list_id) external view returns (bool);
+
+    function getList(uint256 list_id) external view returns (string memory, address);
+
+    function getRole(uint256 list_id, address user) external view returns (uint8);
+
+    function getTodoList(uint256 id) external view returns (uint256);
+
+    error TodoNotFoundError();
+
+    error NotOwnerError();
+
+    error AlreadyCompletedError();
+
+    error EmptyTextError();
+
+    error UnauthorizedError();
+}
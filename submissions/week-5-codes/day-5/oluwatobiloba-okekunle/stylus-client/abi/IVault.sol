@@ -0,0 +1,23 @@
+/**
+ * This file was automatically generated by Stylus and represents a Rust program.
+ * For more information, please see [The Stylus SDK](https://github.com/OffchainLabs/stylus-sdk-rs).
+ */
+
+// SPDX-License-Identifier: MIT-OR-APACHE-2.0
+pragma solidity ^0.8.23;
+
+interface IReentrancyGuard  {
+    function isGuardActive() external view returns (bool);
+
+    function getStatus() external view returns (uint256);
+}
+
+interface IVault is IReentrancyGuard  {
+    function deposit() external payable;
+
+    function balanceOf(address account) external view returns (uint256);
+
+    function withdraw() external;
+
+    function withdrawSafe() external;
+}
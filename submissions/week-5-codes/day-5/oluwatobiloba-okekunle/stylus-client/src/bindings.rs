@@ -0,0 +1,71 @@
+//! Bindings generated from the `cargo stylus export-abi` output of each contract, kept in `abi/`.
+//!
+//! Each file becomes a module named after its interface, with a `<name>Call` struct and a
+//! `<name>Return` struct per function and an enum of all its calls and errors, e.g.
+//! `IERC20::balanceOfCall`, `IERC20::IERC20Calls` and `IERC20::IERC20Errors`. Overloaded
+//! functions are numbered in order, so ERC-6909's `name()` is `name_0Call` and `name(uint256)` is
+//! `name_1Call`.
+
+use alloy_sol_types::{sol, SolInterface};
+
+sol!(
+    #[sol(all_derives, extra_methods)]
+    "abi/IERC20.sol"
+);
+
+sol!(
+    #[sol(all_derives, extra_methods)]
+    "abi/IERC6909.sol"
+);
+
+sol!(
+    #[sol(all_derives, extra_methods)]
+    "abi/ITodoContract.sol"
+);
+
+// The guard has no entrypoint of its own, so its interface comes from the vault that embeds it
+sol!(
+    #[sol(all_derives, extra_methods)]
+    "abi/IVault.sol"
+);
+
+/// A contract interface from `abi/`, used to check at compile time that a call belongs to the
+/// contract it is sent to
+pub trait Interface {
+    type Calls: SolInterface;
+}
+
+/// The course ERC-20, `IERC20`
+pub struct Erc20;
+
+/// The course ERC-6909, `IERC6909`
+pub struct Erc6909;
+
+/// The todo list contract, `ITodoContract`
+pub struct Todo;
+
+/// Functions every contract embedding `ReentrancyGuard` inherits, `IReentrancyGuard`
+pub struct ReentrancyGuard;
+
+/// The reentrancy vault, `IVault`, without the functions it inherits from `ReentrancyGuard`
+pub struct Vault;
+
+impl Interface for Erc20 {
+    type Calls = IERC20::IERC20Calls;
+}
+
+impl Interface for Erc6909 {
+    type Calls = IERC6909::IERC6909Calls;
+}
+
+impl Interface for Todo {
+    type Calls = ITodoContract::ITodoContractCalls;
+}
+
+impl Interface for ReentrancyGuard {
+    type Calls = IReentrancyGuard::IReentrancyGuardCalls;
+}
+
+impl Interface for Vault {
+    type Calls = IVault::IVaultCalls;
+}
@@ -0,0 +1,155 @@
+use std::marker::PhantomData;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+
+use crate::{
+    bindings::Interface,
+    provider::Provider,
+    signer::Signer,
+    transaction::{CallRequest, TransactionReceipt, TransactionRequest},
+    ClientError,
+};
+
+/// A provider and the signer that sends transactions through it
+pub struct Client<P, S> {
+    provider: P,
+    signer: S,
+}
+
+impl<P: Provider, S: Signer> Client<P, S> {
+    pub fn new(provider: P, signer: S) -> Self {
+        Client { provider, signer }
+    }
+
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    pub fn signer(&self) -> &S {
+        &self.signer
+    }
+
+    /// Address transactions are sent from
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// The contract at `address`, through the bindings of interface `I`
+    pub fn contract<I: Interface>(&self, address: Address) -> Contract<'_, I, P, S> {
+        Contract {
+            address,
+            client: self,
+            interface: PhantomData,
+        }
+    }
+
+    /// Call `to` without sending a transaction and decode what it returns
+    pub fn call<C: SolCall>(&self, to: Address, call: &C) -> Result<C::Return, ClientError> {
+        let request = CallRequest {
+            from: self.address(),
+            to,
+            value: U256::ZERO,
+            input: call.abi_encode().into(),
+        };
+        let output = self.provider.call(&request)?;
+        Ok(C::abi_decode_returns(&output, true)?)
+    }
+
+    /// Send `call` to `to` in a signed transaction and wait for it to be included
+    ///
+    /// Fails with [`ClientError::Revert`] without sending anything if the call would revert, and
+    /// with [`ClientError::TransactionFailed`] if it reverts once included.
+    pub fn send<C: SolCall>(
+        &self,
+        to: Address,
+        call: &C,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send_with_value(to, call, U256::ZERO)
+    }
+
+    /// Like [`Client::send`], paying `value` wei to a payable function
+    pub fn send_with_value<C: SolCall>(
+        &self,
+        to: Address,
+        call: &C,
+        value: U256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        let from = self.address();
+        let request = CallRequest {
+            from,
+            to,
+            value,
+            input: call.abi_encode().into(),
+        };
+        let gas_limit = self.provider.estimate_gas(&request)?;
+
+        let transaction = TransactionRequest {
+            nonce: self.provider.get_transaction_count(from)?,
+            gas_price: self.provider.gas_price()?,
+            gas_limit,
+            to,
+            value,
+            input: request.input,
+            chain_id: self.provider.chain_id()?,
+        };
+        let signed = self.signer.sign_transaction(transaction)?;
+        let receipt = self.provider.send_raw_transaction(&signed.encode())?;
+        if !receipt.status {
+            return Err(ClientError::TransactionFailed(receipt.transaction_hash));
+        }
+        Ok(receipt)
+    }
+}
+
+/// A deployed contract with interface `I`, which only takes that interface's calls
+///
+/// ```ignore
+/// let token = client.contract::<Erc20>(TOKEN);
+/// token.send(&IERC20::transferCall { to: BOB, value })?;
+/// let balance = token.call(&IERC20::balanceOfCall { owner: BOB })?._0;
+/// ```
+pub struct Contract<'a, I, P, S> {
+    address: Address,
+    client: &'a Client<P, S>,
+    interface: PhantomData<I>,
+}
+
+impl<I, P, S> Contract<'_, I, P, S>
+where
+    I: Interface,
+    P: Provider,
+    S: Signer,
+{
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// See [`Client::call`]
+    pub fn call<C>(&self, call: &C) -> Result<C::Return, ClientError>
+    where
+        C: SolCall + Into<I::Calls>,
+    {
+        self.client.call(self.address, call)
+    }
+
+    /// See [`Client::send`]
+    pub fn send<C>(&self, call: &C) -> Result<TransactionReceipt, ClientError>
+    where
+        C: SolCall + Into<I::Calls>,
+    {
+        self.client.send(self.address, call)
+    }
+
+    /// See [`Client::send_with_value`]
+    pub fn send_with_value<C>(
+        &self,
+        call: &C,
+        value: U256,
+    ) -> Result<TransactionReceipt, ClientError>
+    where
+        C: SolCall + Into<I::Calls>,
+    {
+        self.client.send_with_value(self.address, call, value)
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use alloy_primitives::{Bytes, SignatureError, B256};
+use alloy_sol_types::SolInterface;
+
+/// Everything that can go wrong between building a call and reading its result
+#[derive(Debug)]
+pub enum ClientError {
+    /// The call reverted with this data, before or instead of being sent
+    Revert(Bytes),
+    /// The transaction was included but reverted
+    TransactionFailed(B256),
+    /// What the contract returned doesn't match the binding
+    Abi(alloy_sol_types::Error),
+    Signature(SignatureError),
+    Rlp(alloy_rlp::Error),
+    /// The transaction is for another chain
+    WrongChainId {
+        expected: u64,
+        found: u64,
+    },
+    /// The transaction's nonce isn't the sender's next one
+    WrongNonce {
+        expected: u64,
+        found: u64,
+    },
+    /// Anything a provider's connection reports
+    Transport(String),
+    Io(std::io::Error),
+}
+
+impl ClientError {
+    /// Revert data, if the call reverted
+    pub fn revert_data(&self) -> Option<&Bytes> {
+        match self {
+            ClientError::Revert(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Decode the revert data as one of the errors of a generated interface, e.g.
+    /// `IERC20::IERC20Errors`
+    pub fn decode_revert<E: SolInterface>(&self) -> Option<E> {
+        E::abi_decode(self.revert_data()?, true).ok()
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Revert(data) => write!(f, "execution reverted: {data}"),
+            ClientError::TransactionFailed(hash) => write!(f, "transaction {hash} reverted"),
+            ClientError::Abi(err) => write!(f, "ABI decoding failed: {err}"),
+            ClientError::Signature(err) => write!(f, "invalid signature: {err}"),
+            ClientError::Rlp(err) => write!(f, "invalid transaction encoding: {err}"),
+            ClientError::WrongChainId { expected, found } => {
+                write!(f, "wrong chain id: expected {expected}, found {found}")
+            }
+            ClientError::WrongNonce { expected, found } => {
+                write!(f, "wrong nonce: expected {expected}, found {found}")
+            }
+            ClientError::Transport(err) => write!(f, "transport error: {err}"),
+            ClientError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<alloy_sol_types::Error> for ClientError {
+    fn from(err: alloy_sol_types::Error) -> Self {
+        ClientError::Abi(err)
+    }
+}
+
+impl From<SignatureError> for ClientError {
+    fn from(err: SignatureError) -> Self {
+        ClientError::Signature(err)
+    }
+}
+
+impl From<alloy_rlp::Error> for ClientError {
+    fn from(err: alloy_rlp::Error) -> Self {
+        ClientError::Rlp(err)
+    }
+}
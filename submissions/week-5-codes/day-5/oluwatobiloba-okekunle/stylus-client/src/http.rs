@@ -0,0 +1,214 @@
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::{hex, Address, Bytes, B256, U256};
+use serde_json::{json, Value};
+
+use crate::{
+    provider::Provider,
+    transaction::{CallRequest, Log, TransactionReceipt},
+    ClientError,
+};
+
+/// Provider that sends JSON-RPC requests to a node over HTTP
+///
+/// Every method makes one blocking request. `send_raw_transaction` then polls
+/// `eth_getTransactionReceipt` until the transaction is included, or fails with
+/// [`ClientError::Transport`] once the receipt timeout has passed.
+///
+/// ```ignore
+/// let provider = HttpProvider::new("http://localhost:8547");
+/// let client = Client::new(provider, LocalSigner::from_file("key.txt")?);
+/// ```
+pub struct HttpProvider {
+    url: String,
+    http: reqwest::blocking::Client,
+    next_id: AtomicU64,
+    poll_interval: Duration,
+    receipt_timeout: Duration,
+}
+
+impl HttpProvider {
+    /// Provider for the node at `url`, polling for receipts every 500ms for up to 2 minutes
+    pub fn new(url: impl Into<String>) -> Self {
+        HttpProvider {
+            url: url.into(),
+            http: reqwest::blocking::Client::new(),
+            next_id: AtomicU64::new(1),
+            poll_interval: Duration::from_millis(500),
+            receipt_timeout: Duration::from_secs(120),
+        }
+    }
+
+    /// How long to wait between receipt polls
+    pub fn with_poll_interval(self, poll_interval: Duration) -> Self {
+        HttpProvider {
+            poll_interval,
+            ..self
+        }
+    }
+
+    /// How long to wait for a sent transaction to be included
+    pub fn with_receipt_timeout(self, receipt_timeout: Duration) -> Self {
+        HttpProvider {
+            receipt_timeout,
+            ..self
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Make one JSON-RPC request and return its result
+    ///
+    /// Errors that carry revert data, as nodes report a reverted `eth_call` or `eth_estimateGas`,
+    /// become [`ClientError::Revert`]. Every other failure is a [`ClientError::Transport`].
+    pub fn request(&self, method: &str, params: Value) -> Result<Value, ClientError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let body = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        let mut response: Value = self
+            .http
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.json())
+            .map_err(|err| ClientError::Transport(format!("{method}: {err}")))?;
+
+        if let Some(error) = response.get("error") {
+            if let Some(data) = error.get("data").and_then(Value::as_str) {
+                if let Ok(data) = Bytes::from_str(data) {
+                    return Err(ClientError::Revert(data));
+                }
+            }
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown error");
+            return Err(ClientError::Transport(format!("{method}: {message}")));
+        }
+        match response.get_mut("result") {
+            Some(result) => Ok(result.take()),
+            None => Err(ClientError::Transport(format!(
+                "{method}: response has no result"
+            ))),
+        }
+    }
+
+    fn call_params(request: &CallRequest) -> Value {
+        json!({
+            "from": request.from.to_string(),
+            "to": request.to.to_string(),
+            "value": format!("{:#x}", request.value),
+            "data": request.input.to_string(),
+        })
+    }
+
+    // `None` until the transaction is included
+    fn receipt(&self, hash: B256) -> Result<Option<TransactionReceipt>, ClientError> {
+        let receipt = self.request("eth_getTransactionReceipt", json!([hash.to_string()]))?;
+        if receipt.is_null() {
+            return Ok(None);
+        }
+
+        let logs = receipt["logs"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|log| {
+                let topics = log["topics"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(parse)
+                    .collect::<Result<_, _>>()?;
+                Ok(Log {
+                    address: parse(&log["address"])?,
+                    topics,
+                    data: parse(&log["data"])?,
+                })
+            })
+            .collect::<Result<_, ClientError>>()?;
+        Ok(Some(TransactionReceipt {
+            transaction_hash: hash,
+            from: parse(&receipt["from"])?,
+            // Only contract creations have no `to`, and the client doesn't make them
+            to: parse(&receipt["to"]).unwrap_or_default(),
+            status: parse_quantity(&receipt["status"])? == 1,
+            logs,
+        }))
+    }
+}
+
+impl Provider for HttpProvider {
+    fn chain_id(&self) -> Result<u64, ClientError> {
+        parse_quantity(&self.request("eth_chainId", json!([]))?)
+    }
+
+    fn get_transaction_count(&self, account: Address) -> Result<u64, ClientError> {
+        let params = json!([account.to_string(), "pending"]);
+        parse_quantity(&self.request("eth_getTransactionCount", params)?)
+    }
+
+    fn get_balance(&self, account: Address) -> Result<U256, ClientError> {
+        parse(&self.request("eth_getBalance", json!([account.to_string(), "latest"]))?)
+    }
+
+    fn gas_price(&self) -> Result<u128, ClientError> {
+        let price: U256 = parse(&self.request("eth_gasPrice", json!([]))?)?;
+        price
+            .try_into()
+            .map_err(|_| ClientError::Transport(format!("gas price {price} doesn't fit in u128")))
+    }
+
+    fn estimate_gas(&self, request: &CallRequest) -> Result<u64, ClientError> {
+        parse_quantity(&self.request("eth_estimateGas", json!([Self::call_params(request)]))?)
+    }
+
+    fn call(&self, request: &CallRequest) -> Result<Bytes, ClientError> {
+        parse(&self.request("eth_call", json!([Self::call_params(request), "latest"]))?)
+    }
+
+    fn send_raw_transaction(&self, raw: &[u8]) -> Result<TransactionReceipt, ClientError> {
+        let params = json!([hex::encode_prefixed(raw)]);
+        let hash: B256 = parse(&self.request("eth_sendRawTransaction", params)?)?;
+
+        let started = Instant::now();
+        loop {
+            if let Some(receipt) = self.receipt(hash)? {
+                return Ok(receipt);
+            }
+            if started.elapsed() >= self.receipt_timeout {
+                return Err(ClientError::Transport(format!(
+                    "transaction {hash} not included after {:?}",
+                    self.receipt_timeout
+                )));
+            }
+            thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+// A hex string from a JSON-RPC response, e.g. an address, hash or data
+fn parse<T: FromStr>(value: &Value) -> Result<T, ClientError> {
+    value
+        .as_str()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| ClientError::Transport(format!("unexpected value in response: {value}")))
+}
+
+// A `0x`-prefixed hex quantity that fits in a u64
+fn parse_quantity(value: &Value) -> Result<u64, ClientError> {
+    value
+        .as_str()
+        .and_then(|value| value.strip_prefix("0x"))
+        .and_then(|digits| u64::from_str_radix(digits, 16).ok())
+        .ok_or_else(|| ClientError::Transport(format!("unexpected quantity in response: {value}")))
+}
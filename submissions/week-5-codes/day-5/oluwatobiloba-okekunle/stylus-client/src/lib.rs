@@ -0,0 +1,42 @@
+//! stylus-client - Typed off-chain clients for the course Stylus contracts
+//!
+//! The bindings in [`bindings`] are generated by `sol!` from the Solidity interfaces that
+//! `cargo stylus export-abi` prints for the ERC-20, ERC-6909, todo and reentrancy vault
+//! contracts, so they follow the contracts instead of being written out by hand. A [`Client`]
+//! signs transactions with a [`Signer`] and sends them through a [`Provider`]:
+//!
+//! ```ignore
+//! let signer: LocalSigner = "0x…".parse()?;
+//! let client = Client::new(provider, signer);
+//!
+//! let token = client.contract::<Erc20>(TOKEN);
+//! token.send(&IERC20::transferCall { to: BOB, value })?;
+//! let balance = token.call(&IERC20::balanceOfCall { owner: BOB })?._0;
+//! ```
+//!
+//! With the `http` feature, [`HttpProvider`] sends them to a node over JSON-RPC. With the `mock`
+//! feature, on by default, [`MockProvider`] runs them against the contracts' Rust code in a
+//! stylus-testkit `World` instead, so client code can be tested in-process without a Nitro node.
+
+pub mod bindings;
+mod client;
+mod error;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "mock")]
+mod mock;
+mod provider;
+mod signer;
+mod transaction;
+
+pub use client::{Client, Contract};
+pub use error::ClientError;
+#[cfg(feature = "http")]
+pub use http::HttpProvider;
+#[cfg(feature = "mock")]
+pub use mock::MockProvider;
+pub use provider::Provider;
+pub use signer::{LocalSigner, Signer};
+pub use transaction::{
+    CallRequest, Log, SignedTransaction, TransactionReceipt, TransactionRequest,
+};
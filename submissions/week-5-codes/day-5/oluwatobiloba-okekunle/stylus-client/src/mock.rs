@@ -0,0 +1,127 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use alloy_primitives::{Address, Bytes, U256};
+use stylus_testkit::World;
+
+use crate::{
+    provider::Provider,
+    transaction::{CallRequest, Log, SignedTransaction, TransactionReceipt},
+    ClientError,
+};
+
+/// Provider that runs transactions in a stylus-testkit [`World`] instead of sending them to a
+/// node
+///
+/// The contracts are the Rust code of the Stylus contracts, deployed in the world up front. Raw
+/// transactions are decoded and their signature, chain id and nonce checked as a node would, then
+/// run from the recovered sender. Gas is not metered: estimates and the gas price are zero and the
+/// transaction's gas fields are ignored.
+///
+/// A transaction that reverts is still included, uses up its nonce and gets a receipt with a false
+/// status, as on chain.
+pub struct MockProvider {
+    world: World,
+    nonces: RefCell<HashMap<Address, u64>>,
+}
+
+impl MockProvider {
+    pub fn new(world: World) -> Self {
+        MockProvider {
+            world,
+            nonces: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// World the transactions run in, to deploy contracts and set balances and blocks
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    // Run `f` with the world's sender and value set to the call's, then put them back
+    fn with_context<R>(&self, from: Address, value: U256, f: impl FnOnce(&World) -> R) -> R {
+        let (sender, previous_value) = (self.world.sender(), self.world.value());
+        self.world.set_sender(from);
+        self.world.set_value(value);
+        let result = f(&self.world);
+        self.world.set_sender(sender);
+        self.world.set_value(previous_value);
+        result
+    }
+}
+
+impl Provider for MockProvider {
+    fn chain_id(&self) -> Result<u64, ClientError> {
+        Ok(self.world.chain_id())
+    }
+
+    fn get_transaction_count(&self, account: Address) -> Result<u64, ClientError> {
+        Ok(self
+            .nonces
+            .borrow()
+            .get(&account)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    fn get_balance(&self, account: Address) -> Result<U256, ClientError> {
+        Ok(self.world.balance(account))
+    }
+
+    fn gas_price(&self) -> Result<u128, ClientError> {
+        Ok(0)
+    }
+
+    fn estimate_gas(&self, request: &CallRequest) -> Result<u64, ClientError> {
+        self.call(request)?;
+        Ok(0)
+    }
+
+    fn call(&self, request: &CallRequest) -> Result<Bytes, ClientError> {
+        self.with_context(request.from, request.value, |world| {
+            world.simulate(request.to, &request.input)
+        })
+        .map(Bytes::from)
+        .map_err(|data| ClientError::Revert(data.into()))
+    }
+
+    fn send_raw_transaction(&self, raw: &[u8]) -> Result<TransactionReceipt, ClientError> {
+        let signed = SignedTransaction::decode(raw)?;
+        let from = signed.recover_signer()?;
+        let tx = &signed.transaction;
+
+        let chain_id = self.world.chain_id();
+        if tx.chain_id != chain_id {
+            return Err(ClientError::WrongChainId {
+                expected: chain_id,
+                found: tx.chain_id,
+            });
+        }
+        let nonce = self.get_transaction_count(from)?;
+        if tx.nonce != nonce {
+            return Err(ClientError::WrongNonce {
+                expected: nonce,
+                found: tx.nonce,
+            });
+        }
+        self.nonces.borrow_mut().insert(from, nonce + 1);
+
+        let first_log = self.world.logs().len();
+        let result = self.with_context(from, tx.value, |world| world.call(tx.to, &tx.input));
+        let logs = self.world.logs()[first_log..]
+            .iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics.clone(),
+                data: log.data.clone().into(),
+            })
+            .collect();
+
+        Ok(TransactionReceipt {
+            transaction_hash: signed.hash(),
+            from,
+            to: tx.to,
+            status: result.is_ok(),
+            logs,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use alloy_primitives::{Address, Bytes, U256};
+
+use crate::{
+    transaction::{CallRequest, TransactionReceipt},
+    ClientError,
+};
+
+/// Connection to a chain, one method per JSON-RPC call the client needs
+///
+/// Calls block until the node answers, and `send_raw_transaction` until the transaction is
+/// included, so the same client code runs against [`MockProvider`](crate::MockProvider) in tests
+/// and against a node with [`HttpProvider`](crate::HttpProvider).
+pub trait Provider {
+    /// `eth_chainId`
+    fn chain_id(&self) -> Result<u64, ClientError>;
+
+    /// `eth_getTransactionCount`, the account's next nonce
+    fn get_transaction_count(&self, account: Address) -> Result<u64, ClientError>;
+
+    /// `eth_getBalance`
+    fn get_balance(&self, account: Address) -> Result<U256, ClientError>;
+
+    /// `eth_gasPrice`
+    fn gas_price(&self) -> Result<u128, ClientError>;
+
+    /// `eth_estimateGas`, failing with [`ClientError::Revert`] if the call reverts
+    fn estimate_gas(&self, request: &CallRequest) -> Result<u64, ClientError>;
+
+    /// `eth_call`, failing with [`ClientError::Revert`] if the call reverts
+    fn call(&self, request: &CallRequest) -> Result<Bytes, ClientError>;
+
+    /// `eth_sendRawTransaction`, then `eth_getTransactionReceipt` once it is included
+    fn send_raw_transaction(&self, raw: &[u8]) -> Result<TransactionReceipt, ClientError>;
+}
+
+// Lets several clients share one provider, e.g. one client per test account on a `MockProvider`
+impl<P: Provider + ?Sized> Provider for &P {
+    fn chain_id(&self) -> Result<u64, ClientError> {
+        (**self).chain_id()
+    }
+
+    fn get_transaction_count(&self, account: Address) -> Result<u64, ClientError> {
+        (**self).get_transaction_count(account)
+    }
+
+    fn get_balance(&self, account: Address) -> Result<U256, ClientError> {
+        (**self).get_balance(account)
+    }
+
+    fn gas_price(&self) -> Result<u128, ClientError> {
+        (**self).gas_price()
+    }
+
+    fn estimate_gas(&self, request: &CallRequest) -> Result<u64, ClientError> {
+        (**self).estimate_gas(request)
+    }
+
+    fn call(&self, request: &CallRequest) -> Result<Bytes, ClientError> {
+        (**self).call(request)
+    }
+
+    fn send_raw_transaction(&self, raw: &[u8]) -> Result<TransactionReceipt, ClientError> {
+        (**self).send_raw_transaction(raw)
+    }
+}
@@ -0,0 +1,89 @@
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use alloy_primitives::{hex, Address, PrimitiveSignature, SignatureError, B256};
+use k256::ecdsa::SigningKey;
+
+use crate::{
+    transaction::{SignedTransaction, TransactionRequest},
+    ClientError,
+};
+
+/// Something that holds an account's key, e.g. a local key or a hardware wallet
+pub trait Signer {
+    /// Address of the account
+    fn address(&self) -> Address;
+
+    /// Sign a 32 byte hash as is, without any prefix
+    fn sign_hash(&self, hash: &B256) -> Result<PrimitiveSignature, ClientError>;
+
+    fn sign_transaction(
+        &self,
+        transaction: TransactionRequest,
+    ) -> Result<SignedTransaction, ClientError> {
+        let signature = self.sign_hash(&transaction.signature_hash())?;
+        Ok(SignedTransaction {
+            transaction,
+            signature,
+        })
+    }
+}
+
+/// A secp256k1 private key held in memory
+#[derive(Clone)]
+pub struct LocalSigner {
+    key: SigningKey,
+    address: Address,
+}
+
+impl LocalSigner {
+    pub fn from_bytes(key: &B256) -> Result<Self, ClientError> {
+        let key = SigningKey::from_bytes(key.as_slice().into()).map_err(SignatureError::from)?;
+        let address = Address::from_private_key(&key);
+        Ok(LocalSigner { key, address })
+    }
+
+    /// Read a hex private key from the first line of a file, as `cargo stylus` expects with
+    /// `--private-key-path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let contents = fs::read_to_string(path).map_err(ClientError::Io)?;
+        contents.lines().next().unwrap_or_default().parse()
+    }
+}
+
+impl FromStr for LocalSigner {
+    type Err = ClientError;
+
+    /// Parse a hex private key, with or without `0x`
+    fn from_str(s: &str) -> Result<Self, ClientError> {
+        let bytes = hex::decode(s.trim()).map_err(SignatureError::from)?;
+        let key = B256::try_from(bytes.as_slice())
+            .map_err(|_| SignatureError::FromBytes("private key must be 32 bytes"))?;
+        Self::from_bytes(&key)
+    }
+}
+
+impl Signer for LocalSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sign_hash(&self, hash: &B256) -> Result<PrimitiveSignature, ClientError> {
+        let (signature, recovery_id) = self
+            .key
+            .sign_prehash_recoverable(hash.as_slice())
+            .map_err(SignatureError::from)?;
+        Ok(PrimitiveSignature::from_signature_and_parity(
+            signature,
+            recovery_id.is_y_odd(),
+        ))
+    }
+}
+
+// Never print the key
+impl fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSigner")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
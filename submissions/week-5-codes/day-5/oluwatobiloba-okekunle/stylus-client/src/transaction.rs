@@ -0,0 +1,157 @@
+use alloy_primitives::{keccak256, Address, Bytes, PrimitiveSignature, B256, U256};
+use alloy_rlp::{Decodable, Encodable, Header};
+use alloy_sol_types::SolEvent;
+
+use crate::ClientError;
+
+/// A call that is not sent, for `eth_call` and `eth_estimateGas`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallRequest {
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+    pub input: Bytes,
+}
+
+/// An unsigned legacy transaction with EIP-155 replay protection
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionRequest {
+    pub nonce: u64,
+    pub gas_price: u128,
+    pub gas_limit: u64,
+    pub to: Address,
+    pub value: U256,
+    pub input: Bytes,
+    pub chain_id: u64,
+}
+
+impl TransactionRequest {
+    /// Hash the sender signs: the RLP of the fields, then the chain id and two zeros
+    pub fn signature_hash(&self) -> B256 {
+        let mut out = Vec::new();
+        let fields: [&dyn Encodable; 9] = [
+            &self.nonce,
+            &self.gas_price,
+            &self.gas_limit,
+            &self.to,
+            &self.value,
+            &self.input,
+            &self.chain_id,
+            &0u8,
+            &0u8,
+        ];
+        alloy_rlp::encode_list::<_, dyn Encodable>(&fields, &mut out);
+        keccak256(out)
+    }
+}
+
+/// A transaction with its sender's signature, ready for `eth_sendRawTransaction`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedTransaction {
+    pub transaction: TransactionRequest,
+    pub signature: PrimitiveSignature,
+}
+
+impl SignedTransaction {
+    /// The raw transaction: the RLP of the fields, then `v`, `r` and `s`
+    pub fn encode(&self) -> Bytes {
+        let tx = &self.transaction;
+        let v = self.signature.v() as u64 + 35 + tx.chain_id * 2;
+        let (r, s) = (self.signature.r(), self.signature.s());
+        let fields: [&dyn Encodable; 9] = [
+            &tx.nonce,
+            &tx.gas_price,
+            &tx.gas_limit,
+            &tx.to,
+            &tx.value,
+            &tx.input,
+            &v,
+            &r,
+            &s,
+        ];
+        let mut out = Vec::new();
+        alloy_rlp::encode_list::<_, dyn Encodable>(&fields, &mut out);
+        out.into()
+    }
+
+    /// Decode a raw transaction made by [`SignedTransaction::encode`]
+    pub fn decode(mut raw: &[u8]) -> Result<Self, ClientError> {
+        let buf = &mut raw;
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString.into());
+        }
+        let nonce = u64::decode(buf)?;
+        let gas_price = u128::decode(buf)?;
+        let gas_limit = u64::decode(buf)?;
+        let to = Address::decode(buf)?;
+        let value = U256::decode(buf)?;
+        let input = Bytes::decode(buf)?;
+        let v = u64::decode(buf)?;
+        let r = U256::decode(buf)?;
+        let s = U256::decode(buf)?;
+        if !buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength.into());
+        }
+        // Transactions without EIP-155 replay protection are not supported
+        if v < 35 {
+            return Err(alloy_rlp::Error::Custom("missing EIP-155 chain id").into());
+        }
+
+        let transaction = TransactionRequest {
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            input,
+            chain_id: (v - 35) / 2,
+        };
+        let signature = PrimitiveSignature::new(r, s, (v - 35) % 2 == 1);
+        Ok(SignedTransaction {
+            transaction,
+            signature,
+        })
+    }
+
+    /// Transaction hash, the hash of the raw transaction
+    pub fn hash(&self) -> B256 {
+        keccak256(self.encode())
+    }
+
+    /// Address that signed the transaction
+    pub fn recover_signer(&self) -> Result<Address, ClientError> {
+        let hash = self.transaction.signature_hash();
+        Ok(self.signature.recover_address_from_prehash(&hash)?)
+    }
+}
+
+/// Event emitted by the contract at `address`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Bytes,
+}
+
+/// Outcome of an included transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionReceipt {
+    pub transaction_hash: B256,
+    pub from: Address,
+    pub to: Address,
+    /// False if the transaction reverted, in which case it has no logs
+    pub status: bool,
+    pub logs: Vec<Log>,
+}
+
+impl TransactionReceipt {
+    /// Every `E` event in the receipt, in order
+    pub fn events<E: SolEvent>(&self) -> Vec<E> {
+        self.logs
+            .iter()
+            .filter(|log| log.topics.first() == Some(&E::SIGNATURE_HASH))
+            .filter_map(|log| E::decode_raw_log(log.topics.iter().copied(), &log.data, true).ok())
+            .collect()
+    }
+}
@@ -0,0 +1,89 @@
+//! Checks the files in `abi/` against what `cargo stylus export-abi` prints for each contract.
+
+use std::{fmt, fs, marker::PhantomData};
+
+use erc20_stylus::ERC20;
+use erc6909_stylus::ERC6909;
+use reentrancy_vault_stylus::Vault;
+use stylus_sdk::abi::export::GenerateAbi;
+use todo_stylus::TodoContract;
+
+// What `export-abi` prints before the interface, with its default license and pragma
+const HEADER: &str = "/**
+ * This file was automatically generated by Stylus and represents a Rust program.
+ * For more information, please see [The Stylus SDK](https://github.com/OffchainLabs/stylus-sdk-rs).
+ */
+
+// SPDX-License-Identifier: MIT-OR-APACHE-2.0
+pragma solidity ^0.8.23;
+
+";
+
+struct Abi<T>(PhantomData<T>);
+
+impl<T: GenerateAbi> fmt::Display for Abi<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_abi(f)
+    }
+}
+
+// `export-abi` prints an array of tuples as `returns (a,b)[] memory`, which `sol!` rejects, so
+// the committed files wrap the whole return type in parentheses
+fn wrap_tuple_array_returns(line: &str) -> String {
+    let Some((function, returns)) = line.split_once(" returns (") else {
+        return line.to_string();
+    };
+    match returns.strip_suffix(";") {
+        Some(returns) if returns.contains(")[]") => format!("{function} returns (({returns});"),
+        _ => line.to_string(),
+    }
+}
+
+// Fails with the first line that differs from the committed file
+fn check<T: GenerateAbi>(file: &str) {
+    let exported = format!("{HEADER}{}", Abi::<T>(PhantomData));
+    let expected: Vec<String> = exported.lines().map(wrap_tuple_array_returns).collect();
+    let path = format!("{}/abi/{file}", env!("CARGO_MANIFEST_DIR"));
+    let committed = fs::read_to_string(&path).unwrap();
+    let committed: Vec<&str> = committed.lines().collect();
+
+    for (line, (expected, committed)) in expected.iter().zip(&committed).enumerate() {
+        assert_eq!(
+            committed,
+            expected,
+            "abi/{file}:{} differs from export-abi, regenerate it",
+            line + 1
+        );
+    }
+    assert_eq!(
+        committed.len(),
+        expected.len(),
+        "abi/{file} has a different length than export-abi, regenerate it"
+    );
+}
+
+#[test]
+fn test_abi_files_match_contracts() {
+    check::<ERC20>("IERC20.sol");
+    check::<ERC6909>("IERC6909.sol");
+    check::<TodoContract>("ITodoContract.sol");
+    check::<Vault>("IVault.sol");
+}
+
+#[test]
+fn test_wrap_tuple_array_returns() {
+    let line = "    function f(uint256 id) external view returns (string,address)[] memory;";
+    assert_eq!(
+        wrap_tuple_array_returns(line),
+        "    function f(uint256 id) external view returns ((string,address)[] memory);"
+    );
+
+    // Every other line is kept as it is
+    for line in [
+        "    function g() external view returns (uint256, string memory);",
+        "    function h() external view returns (uint256[] memory);",
+        "    function i() external;",
+    ] {
+        assert_eq!(wrap_tuple_array_returns(line), line);
+    }
+}
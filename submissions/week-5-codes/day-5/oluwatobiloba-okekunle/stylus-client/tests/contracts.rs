@@ -0,0 +1,208 @@
+//! Each contract driven through its generated bindings, with signed transactions on a
+//! `MockProvider`.
+
+use alloy_primitives::{address, Address, B256, U256};
+use erc20_stylus::{Transfer, ERC20};
+use erc6909_stylus::{Transfer as ERC6909Transfer, ERC6909};
+use reentrancy_vault_stylus::Vault;
+use stylus_client::{
+    bindings::{
+        Erc20, Erc6909, IReentrancyGuard, ITodoContract, IVault, ReentrancyGuard, Todo,
+        Vault as VaultInterface, IERC20, IERC6909,
+    },
+    Client, LocalSigner, MockProvider, Provider, Signer,
+};
+use stylus_testkit::World;
+use todo_stylus::{ListCreated, TodoContract, TodoCreated, ROLE_EDITOR};
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const MULTI_TOKEN: Address = address!("0x6909000000000000000000000000000000000000");
+const TODO: Address = address!("0x7000000000000000000000000000000000000000");
+const VAULT: Address = address!("0x7a07000000000000000000000000000000000000");
+
+fn signer(byte: u8) -> LocalSigner {
+    LocalSigner::from_bytes(&B256::repeat_byte(byte)).unwrap()
+}
+
+// A provider on a fresh world, and the accounts that sign on it
+fn setup() -> (MockProvider, LocalSigner, LocalSigner) {
+    let provider = MockProvider::new(World::new());
+    (provider, signer(1), signer(2))
+}
+
+#[test]
+fn test_erc20() {
+    let (provider, owner, alice) = setup();
+    let world = provider.world();
+    world.set_sender(owner.address());
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+
+    let owner = Client::new(&provider, owner);
+    let alice = Client::new(&provider, alice);
+    let token = owner.contract::<Erc20>(TOKEN);
+    assert_eq!(token.call(&IERC20::symbolCall {}).unwrap()._0, "TKN");
+
+    let mint = IERC20::mintCall {
+        to: alice.address(),
+        value: U256::from(100),
+    };
+    token.send(&mint).unwrap();
+
+    let transfer = IERC20::transferCall {
+        to: owner.address(),
+        value: U256::from(40),
+    };
+    let receipt = alice.contract::<Erc20>(TOKEN).send(&transfer).unwrap();
+    assert_eq!(receipt.from, alice.address());
+    let transfers = receipt.events::<Transfer>();
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].from, alice.address());
+    assert_eq!(transfers[0].value, U256::from(40));
+
+    let balance = IERC20::balanceOfCall {
+        owner: alice.address(),
+    };
+    assert_eq!(token.call(&balance).unwrap()._0, U256::from(60));
+
+    // Only the owner mints, and the revert decodes into the interface's errors
+    let err = alice.contract::<Erc20>(TOKEN).send(&mint).unwrap_err();
+    assert_eq!(
+        err.decode_revert::<IERC20::IERC20Errors>(),
+        Some(IERC20::IERC20Errors::ERC20InvalidSender(
+            IERC20::ERC20InvalidSender {
+                _0: alice.address()
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_erc6909() {
+    let (provider, owner, alice) = setup();
+    let world = provider.world();
+    world.set_sender(owner.address());
+    let mut multi_token = world.deploy::<ERC6909>(MULTI_TOKEN);
+    multi_token.constructor("Items".into(), "ITM".into());
+
+    let owner = Client::new(&provider, owner);
+    let multi_token = owner.contract::<Erc6909>(MULTI_TOKEN);
+    let id = U256::from(7);
+
+    // Overloads are numbered: `name()` then `name(uint256)`
+    assert_eq!(
+        multi_token.call(&IERC6909::name_0Call {}).unwrap()._0,
+        "Items"
+    );
+
+    let mint = IERC6909::batchMintCall {
+        to: owner.address(),
+        ids: vec![id, U256::from(8)],
+        values: vec![U256::from(10), U256::from(20)],
+    };
+    multi_token.send(&mint).unwrap();
+    let transfer = IERC6909::transferCall {
+        receiver: alice.address(),
+        id,
+        value: U256::from(4),
+    };
+    let receipt = multi_token.send(&transfer).unwrap();
+    assert_eq!(receipt.events::<ERC6909Transfer>()[0].id, id);
+
+    let balance = IERC6909::balanceOfCall {
+        owner: alice.address(),
+        id,
+    };
+    assert_eq!(multi_token.call(&balance).unwrap()._0, U256::from(4));
+    let ids = IERC6909::tokenIdsCall {
+        offset: U256::ZERO,
+        limit: U256::from(10),
+    };
+    assert_eq!(multi_token.call(&ids).unwrap()._0, vec![id, U256::from(8)]);
+}
+
+#[test]
+fn test_todo() {
+    let (provider, owner, alice) = setup();
+    let world = provider.world();
+    world.set_sender(owner.address());
+    let mut todo = world.deploy::<TodoContract>(TODO);
+    todo.constructor();
+
+    let owner = Client::new(&provider, owner);
+    let alice = Client::new(&provider, alice);
+    let todo = owner.contract::<Todo>(TODO);
+
+    // Return values of transactions are not in the receipt, read them from the events
+    let create = ITodoContract::createListCall {
+        name: "Groceries".into(),
+    };
+    let receipt = todo.send(&create).unwrap();
+    let list_id = receipt.events::<ListCreated>()[0].list_id;
+    let share = ITodoContract::shareListCall {
+        list_id,
+        user: alice.address(),
+        role: ROLE_EDITOR,
+    };
+    todo.send(&share).unwrap();
+
+    let create = ITodoContract::createTodoInListCall {
+        list_id,
+        text: "Milk".into(),
+    };
+    let receipt = alice.contract::<Todo>(TODO).send(&create).unwrap();
+    let created = &receipt.events::<TodoCreated>()[0];
+    assert_eq!(created.list_id, list_id);
+    let update = ITodoContract::updateTodoCall {
+        id: created.id,
+        new_text: "Oat milk".into(),
+    };
+    todo.send(&update).unwrap();
+
+    let history = todo
+        .call(&ITodoContract::getTodoHistoryCall { id: created.id })
+        .unwrap()
+        ._0;
+    let editors: Vec<_> = history.iter().map(|revision| revision.1).collect();
+    assert_eq!(editors, vec![alice.address(), owner.address()]);
+    assert_eq!(history[1].0, "Oat milk");
+
+    let err = alice
+        .contract::<Todo>(TODO)
+        .send(&ITodoContract::createListCall { name: "".into() })
+        .unwrap_err();
+    assert!(matches!(
+        err.decode_revert::<ITodoContract::ITodoContractErrors>(),
+        Some(ITodoContract::ITodoContractErrors::EmptyTextError(_))
+    ));
+}
+
+#[test]
+fn test_vault_and_guard() {
+    let (provider, alice, _) = setup();
+    let world = provider.world();
+    world.deploy::<Vault>(VAULT);
+    world.set_balance(alice.address(), U256::from(1000));
+
+    let alice = Client::new(&provider, alice);
+    let vault = alice.contract::<VaultInterface>(VAULT);
+    vault
+        .send_with_value(&IVault::depositCall {}, U256::from(300))
+        .unwrap();
+    let balance = IVault::balanceOfCall {
+        account: alice.address(),
+    };
+    assert_eq!(vault.call(&balance).unwrap()._0, U256::from(300));
+    assert_eq!(provider.get_balance(VAULT).unwrap(), U256::from(300));
+
+    vault.send(&IVault::withdrawSafeCall {}).unwrap();
+    assert_eq!(
+        provider.get_balance(alice.address()).unwrap(),
+        U256::from(1000)
+    );
+
+    // The functions the vault inherits have their own binding
+    let guard = alice.contract::<ReentrancyGuard>(VAULT);
+    let active = guard.call(&IReentrancyGuard::isGuardActiveCall {}).unwrap();
+    assert!(!active._0);
+}
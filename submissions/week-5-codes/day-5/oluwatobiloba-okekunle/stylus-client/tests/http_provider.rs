@@ -0,0 +1,263 @@
+//! `HttpProvider` against a local server that answers with canned JSON-RPC responses.
+#![cfg(feature = "http")]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use alloy_primitives::{address, b256, bytes, hex, Address, B256, U256};
+use alloy_sol_types::{SolError, SolEvent};
+use erc20_stylus::Transfer;
+use serde_json::{json, Value};
+use stylus_client::{
+    bindings::{Erc20, IERC20},
+    CallRequest, Client, ClientError, HttpProvider, LocalSigner, Provider, SignedTransaction,
+    Signer,
+};
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+const BOB: Address = address!("0x3333333333333333333333333333333333333333");
+const HASH: B256 = b256!("0x1111111111111111111111111111111111111111111111111111111111111111");
+
+// Serves one connection per response, each answering the request with the response's `result`
+// or `error`, and returns the requests it got once all responses are sent
+fn serve(responses: Vec<Value>) -> (HttpProvider, JoinHandle<Vec<Value>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for mut response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let request: Value = serde_json::from_slice(&body).unwrap();
+
+            response["jsonrpc"] = json!("2.0");
+            response["id"] = request["id"].clone();
+            let response = response.to_string();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+            requests.push(request);
+        }
+        requests
+    });
+
+    let provider = HttpProvider::new(url).with_poll_interval(Duration::from_millis(1));
+    (provider, server)
+}
+
+fn methods(requests: &[Value]) -> Vec<&str> {
+    requests
+        .iter()
+        .map(|request| request["method"].as_str().unwrap())
+        .collect()
+}
+
+fn call_request() -> CallRequest {
+    CallRequest {
+        from: ALICE,
+        to: TOKEN,
+        value: U256::from(255),
+        input: bytes!("0x18160ddd"),
+    }
+}
+
+#[test]
+fn test_queries() {
+    let (provider, server) = serve(vec![
+        json!({ "result": "0xa4b1" }),
+        json!({ "result": "0x2a" }),
+        json!({ "result": "0xde0b6b3a7640000" }),
+        json!({ "result": "0x5f5e100" }),
+        json!({ "result": "0x5208" }),
+        json!({ "result": "0x0000000000000000000000000000000000000000000000000000000000000064" }),
+    ]);
+
+    assert_eq!(provider.chain_id().unwrap(), 42161);
+    assert_eq!(provider.get_transaction_count(ALICE).unwrap(), 42);
+    assert_eq!(
+        provider.get_balance(ALICE).unwrap(),
+        U256::from(10).pow(U256::from(18))
+    );
+    assert_eq!(provider.gas_price().unwrap(), 100_000_000);
+    assert_eq!(provider.estimate_gas(&call_request()).unwrap(), 21000);
+    let output = provider.call(&call_request()).unwrap();
+    assert_eq!(U256::from_be_slice(&output), U256::from(100));
+
+    let requests = server.join().unwrap();
+    assert_eq!(
+        methods(&requests),
+        [
+            "eth_chainId",
+            "eth_getTransactionCount",
+            "eth_getBalance",
+            "eth_gasPrice",
+            "eth_estimateGas",
+            "eth_call",
+        ]
+    );
+    // Each request has its own id
+    assert_eq!(requests[0]["id"], json!(1));
+    assert_eq!(requests[5]["id"], json!(6));
+
+    assert_eq!(requests[1]["params"], json!([ALICE.to_string(), "pending"]));
+    assert_eq!(requests[2]["params"], json!([ALICE.to_string(), "latest"]));
+    let call = json!({
+        "from": ALICE.to_string(),
+        "to": TOKEN.to_string(),
+        "value": "0xff",
+        "data": "0x18160ddd",
+    });
+    assert_eq!(requests[4]["params"], json!([call]));
+    assert_eq!(requests[5]["params"], json!([call, "latest"]));
+}
+
+#[test]
+fn test_errors() {
+    let revert = IERC20::ERC20InsufficientBalance {
+        _0: ALICE,
+        _1: U256::ZERO,
+        _2: U256::from(1),
+    };
+    let (provider, server) = serve(vec![
+        json!({ "error": {
+            "code": 3,
+            "message": "execution reverted",
+            "data": hex::encode_prefixed(revert.abi_encode()),
+        } }),
+        json!({ "error": { "code": -32000, "message": "insufficient funds" } }),
+        json!({ "result": "not a quantity" }),
+    ]);
+
+    // Revert data is decoded as with the mock provider
+    let err = provider.call(&call_request()).unwrap_err();
+    assert_eq!(
+        err.decode_revert::<IERC20::IERC20Errors>(),
+        Some(IERC20::IERC20Errors::ERC20InsufficientBalance(revert))
+    );
+
+    let err = provider.estimate_gas(&call_request()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "transport error: eth_estimateGas: insufficient funds"
+    );
+
+    let err = provider.chain_id().unwrap_err();
+    assert!(matches!(err, ClientError::Transport(_)));
+    server.join().unwrap();
+
+    // Nothing listens there any more
+    assert!(matches!(
+        provider.chain_id().unwrap_err(),
+        ClientError::Transport(_)
+    ));
+}
+
+#[test]
+fn test_send_polls_for_receipt() {
+    let signer = LocalSigner::from_bytes(&B256::repeat_byte(0x46)).unwrap();
+    let receipt = json!({
+        "transactionHash": HASH.to_string(),
+        "from": signer.address().to_string(),
+        "to": TOKEN.to_string(),
+        "status": "0x1",
+        "logs": [{
+            "address": TOKEN.to_string(),
+            "topics": [
+                Transfer::SIGNATURE_HASH.to_string(),
+                B256::left_padding_from(signer.address().as_slice()).to_string(),
+                B256::left_padding_from(BOB.as_slice()).to_string(),
+            ],
+            "data": B256::from(U256::from(5)).to_string(),
+        }],
+    });
+    let (provider, server) = serve(vec![
+        json!({ "result": "0xc350" }),
+        json!({ "result": "0x7" }),
+        json!({ "result": "0x5f5e100" }),
+        json!({ "result": "0xa4b1" }),
+        json!({ "result": HASH.to_string() }),
+        json!({ "result": null }),
+        json!({ "result": null }),
+        json!({ "result": receipt }),
+    ]);
+
+    let client = Client::new(provider, signer);
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(5),
+    };
+    let receipt = client.contract::<Erc20>(TOKEN).send(&transfer).unwrap();
+    assert!(receipt.status);
+    assert_eq!(receipt.transaction_hash, HASH);
+    assert_eq!(receipt.from, client.address());
+    let transfers = receipt.events::<Transfer>();
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].to, BOB);
+    assert_eq!(transfers[0].value, U256::from(5));
+
+    let requests = server.join().unwrap();
+    assert_eq!(
+        methods(&requests),
+        [
+            "eth_estimateGas",
+            "eth_getTransactionCount",
+            "eth_gasPrice",
+            "eth_chainId",
+            "eth_sendRawTransaction",
+            "eth_getTransactionReceipt",
+            "eth_getTransactionReceipt",
+            "eth_getTransactionReceipt",
+        ]
+    );
+    assert_eq!(requests[5]["params"], json!([HASH.to_string()]));
+
+    // The raw transaction is signed with what the node returned
+    let raw = hex::decode(requests[4]["params"][0].as_str().unwrap()).unwrap();
+    let sent = SignedTransaction::decode(&raw).unwrap();
+    assert_eq!(sent.recover_signer().unwrap(), client.address());
+    assert_eq!(sent.transaction.nonce, 7);
+    assert_eq!(sent.transaction.gas_price, 100_000_000);
+    assert_eq!(sent.transaction.gas_limit, 50_000);
+    assert_eq!(sent.transaction.chain_id, 42161);
+    assert_eq!(sent.transaction.to, TOKEN);
+}
+
+#[test]
+fn test_receipt_timeout() {
+    let (provider, server) = serve(vec![
+        json!({ "result": HASH.to_string() }),
+        json!({ "result": null }),
+    ]);
+    let provider = provider.with_receipt_timeout(Duration::ZERO);
+
+    let err = provider.send_raw_transaction(&[0xc0]).unwrap_err();
+    assert!(matches!(err, ClientError::Transport(_)));
+    let requests = server.join().unwrap();
+    assert_eq!(requests[0]["params"], json!(["0xc0"]));
+}
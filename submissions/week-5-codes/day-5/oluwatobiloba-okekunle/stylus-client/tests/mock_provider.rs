@@ -0,0 +1,203 @@
+//! Transaction signing and encoding, and the checks `MockProvider` makes on raw transactions.
+
+use alloy_primitives::{address, b256, bytes, hex, Address, Bytes, B256, U256};
+use alloy_sol_types::SolCall;
+use erc20_stylus::ERC20;
+use stylus_client::{
+    bindings::{Erc20, IERC20},
+    CallRequest, Client, ClientError, LocalSigner, MockProvider, Provider, SignedTransaction,
+    Signer, TransactionRequest,
+};
+use stylus_testkit::World;
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const BOB: Address = address!("0x3333333333333333333333333333333333333333");
+
+// The example transaction from EIP-155
+fn eip155_transaction() -> TransactionRequest {
+    TransactionRequest {
+        nonce: 9,
+        gas_price: 20_000_000_000,
+        gas_limit: 21000,
+        to: address!("0x3535353535353535353535353535353535353535"),
+        value: U256::from(10).pow(U256::from(18)),
+        input: Bytes::new(),
+        chain_id: 1,
+    }
+}
+
+fn eip155_signer() -> LocalSigner {
+    LocalSigner::from_bytes(&B256::repeat_byte(0x46)).unwrap()
+}
+
+fn balance_of(world: &World, owner: Address) -> U256 {
+    world.call_sol(TOKEN, &IERC20::balanceOfCall { owner }).unwrap()._0
+}
+
+#[test]
+fn test_eip155_transaction() {
+    let transaction = eip155_transaction();
+    assert_eq!(
+        transaction.signature_hash(),
+        b256!("0xdaf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+    );
+
+    let signer = eip155_signer();
+    assert_eq!(
+        signer.address(),
+        address!("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F")
+    );
+    let signed = signer.sign_transaction(transaction).unwrap();
+    let raw = bytes!("0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+    assert_eq!(signed.encode(), raw);
+
+    let decoded = SignedTransaction::decode(&raw).unwrap();
+    assert_eq!(decoded, signed);
+    assert_eq!(decoded.recover_signer().unwrap(), signer.address());
+}
+
+#[test]
+fn test_local_signer_parsing() {
+    let key = hex::encode(B256::repeat_byte(0x46));
+    let signer: LocalSigner = key.parse().unwrap();
+    assert_eq!(signer.address(), eip155_signer().address());
+    let signer: LocalSigner = format!("0x{key}\n").parse().unwrap();
+    assert_eq!(signer.address(), eip155_signer().address());
+
+    assert!("0x1234".parse::<LocalSigner>().is_err());
+    assert!("not hex".parse::<LocalSigner>().is_err());
+    // Zero is not a valid secp256k1 key
+    assert!(LocalSigner::from_bytes(&B256::ZERO).is_err());
+
+    // The key never shows up in debug output
+    assert!(!format!("{signer:?}").contains(&key));
+}
+
+#[test]
+fn test_mock_provider_checks_transactions() {
+    let world = World::new();
+    let provider = MockProvider::new(world.clone());
+    let signer = eip155_signer();
+    let from = signer.address();
+    world.set_balance(from, U256::from(100));
+
+    let transaction = TransactionRequest {
+        to: BOB,
+        value: U256::from(30),
+        chain_id: provider.chain_id().unwrap(),
+        ..Default::default()
+    };
+    let raw = signer
+        .sign_transaction(transaction.clone())
+        .unwrap()
+        .encode();
+    let receipt = provider.send_raw_transaction(&raw).unwrap();
+    assert!(receipt.status);
+    assert_eq!(receipt.from, from);
+    assert_eq!(provider.get_balance(BOB).unwrap(), U256::from(30));
+    assert_eq!(provider.get_transaction_count(from).unwrap(), 1);
+
+    // The same transaction can't be replayed
+    let err = provider.send_raw_transaction(&raw).unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::WrongNonce {
+            expected: 1,
+            found: 0
+        }
+    ));
+
+    let other_chain = TransactionRequest {
+        nonce: 1,
+        chain_id: 1,
+        ..transaction.clone()
+    };
+    let raw = signer.sign_transaction(other_chain).unwrap().encode();
+    let err = provider.send_raw_transaction(&raw).unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::WrongChainId {
+            expected: 42161,
+            found: 1
+        }
+    ));
+
+    // A tampered transaction recovers another sender, with no nonce used yet
+    let mut signed = signer
+        .sign_transaction(TransactionRequest {
+            nonce: 1,
+            ..transaction.clone()
+        })
+        .unwrap();
+    signed.transaction.value = U256::from(70);
+    let err = provider.send_raw_transaction(&signed.encode()).unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::WrongNonce {
+            expected: 0,
+            found: 1
+        }
+    ));
+
+    assert!(matches!(
+        provider.send_raw_transaction(&[0xc0]).unwrap_err(),
+        ClientError::Rlp(_)
+    ));
+    assert_eq!(world.balance(from), U256::from(70));
+}
+
+#[test]
+fn test_reverted_transactions_use_their_nonce() {
+    let world = World::new();
+    let provider = MockProvider::new(world.clone());
+    let signer = eip155_signer();
+    let from = signer.address();
+    world.set_sender(from);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+
+    // Calls are simulated and leave nothing behind
+    let client = Client::new(&provider, signer.clone());
+    let mint = IERC20::mintCall {
+        to: BOB,
+        value: U256::from(5),
+    };
+    let request = CallRequest {
+        from,
+        to: TOKEN,
+        value: U256::ZERO,
+        input: mint.abi_encode().into(),
+    };
+    provider.call(&request).unwrap();
+    assert_eq!(balance_of(&world, BOB), U256::ZERO);
+    assert_eq!(world.sender(), from);
+
+    // `send` won't send what would revert
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(1),
+    };
+    let err = client.send(TOKEN, &transfer).unwrap_err();
+    assert!(matches!(
+        err.decode_revert::<IERC20::IERC20Errors>(),
+        Some(IERC20::IERC20Errors::ERC20InsufficientBalance(_))
+    ));
+    assert_eq!(provider.get_transaction_count(from).unwrap(), 0);
+
+    // But a reverting transaction sent anyway is included and fails
+    let transaction = TransactionRequest {
+        to: TOKEN,
+        input: transfer.abi_encode().into(),
+        chain_id: 42161,
+        ..Default::default()
+    };
+    let raw = signer.sign_transaction(transaction).unwrap().encode();
+    let receipt = provider.send_raw_transaction(&raw).unwrap();
+    assert!(!receipt.status);
+    assert!(receipt.logs.is_empty());
+    assert_eq!(provider.get_transaction_count(from).unwrap(), 1);
+
+    client.contract::<Erc20>(TOKEN).send(&mint).unwrap();
+    assert_eq!(provider.get_transaction_count(from).unwrap(), 2);
+    assert_eq!(balance_of(&world, BOB), U256::from(5));
+}
@@ -0,0 +1,460 @@
+//! Differential harness for ERC-20 tokens.
+//!
+//! Random sequences of `transfer`, `approve`, `transferFrom`, `mint` and
+//! `burn` are applied to a token under test and to [`OzErc20`], a pure-Rust
+//! model of OpenZeppelin's `ERC20`. After every step the revert data, the
+//! emitted logs, the balances, the allowances and the total supply must match.
+//!
+//! Any Stylus token can be checked by implementing [`Erc20Subject`] for it
+//! (see `tests/erc20_differential.rs` in the course ERC-20) and running [`run`]
+//! over [`ops`]. Differences a token deliberately keeps are accepted with
+//! [`run_with`] and its [`Deviations`], so only new divergences fail.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use alloy_primitives::{hex, Address, LogData, U256};
+use alloy_sol_types::{sol, SolError, SolEvent};
+use proptest::prelude::*;
+
+sol! {
+    error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+    error ERC20InvalidSender(address sender);
+    error ERC20InvalidReceiver(address receiver);
+    error ERC20InsufficientAllowance(address spender, uint256 allowance, uint256 needed);
+    error ERC20InvalidApprover(address approver);
+    error ERC20InvalidSpender(address spender);
+
+    // Misspelled receiver error of tokens written before OpenZeppelin v5
+    error ERC20InvalidReciver(address reciver);
+
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+}
+
+/// Accounts used by generated operations, the zero address first
+pub const ACTORS: [Address; 5] = [
+    Address::ZERO,
+    Address::new([0xA1; 20]),
+    Address::new([0xB2; 20]),
+    Address::new([0xC3; 20]),
+    Address::new([0xD4; 20]),
+];
+
+/// A token driven by the harness
+///
+/// Failed calls return the ABI-encoded revert data and must leave no state
+/// changes or logs behind, as a reverted transaction would.
+pub trait Erc20Subject {
+    fn transfer(&mut self, sender: Address, to: Address, value: U256) -> Result<(), Vec<u8>>;
+    fn approve(&mut self, sender: Address, spender: Address, value: U256) -> Result<(), Vec<u8>>;
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Vec<u8>>;
+    /// Mint as whoever the token allows to mint
+    fn mint(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>>;
+    fn burn(&mut self, sender: Address, value: U256) -> Result<(), Vec<u8>>;
+
+    fn balance_of(&self, account: Address) -> U256;
+    fn allowance(&self, owner: Address, spender: Address) -> U256;
+    fn total_supply(&self) -> U256;
+
+    /// Logs emitted since the previous call
+    fn take_logs(&mut self) -> Vec<LogData>;
+}
+
+/// One call made by the harness, with accounts as indexes into [`ACTORS`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    Transfer { sender: usize, to: usize, value: U256 },
+    Approve { sender: usize, spender: usize, value: U256 },
+    TransferFrom { sender: usize, from: usize, to: usize, value: U256 },
+    Mint { to: usize, value: U256 },
+    Burn { sender: usize, value: U256 },
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Transfer { sender, to, value } => {
+                write!(f, "{} transfer({}, {})", ACTORS[*sender], ACTORS[*to], value)
+            }
+            Op::Approve { sender, spender, value } => {
+                write!(f, "{} approve({}, {})", ACTORS[*sender], ACTORS[*spender], value)
+            }
+            Op::TransferFrom { sender, from, to, value } => write!(
+                f,
+                "{} transferFrom({}, {}, {})",
+                ACTORS[*sender], ACTORS[*from], ACTORS[*to], value
+            ),
+            Op::Mint { to, value } => write!(f, "mint({}, {})", ACTORS[*to], value),
+            Op::Burn { sender, value } => write!(f, "{} burn({})", ACTORS[*sender], value),
+        }
+    }
+}
+
+impl Op {
+    /// Make this call on `subject`
+    pub fn apply(&self, subject: &mut impl Erc20Subject) -> Result<(), Vec<u8>> {
+        match *self {
+            Op::Transfer { sender, to, value } => subject.transfer(ACTORS[sender], ACTORS[to], value),
+            Op::Approve { sender, spender, value } => {
+                subject.approve(ACTORS[sender], ACTORS[spender], value)
+            }
+            Op::TransferFrom { sender, from, to, value } => {
+                subject.transfer_from(ACTORS[sender], ACTORS[from], ACTORS[to], value)
+            }
+            Op::Mint { to, value } => subject.mint(ACTORS[to], value),
+            Op::Burn { sender, value } => subject.burn(ACTORS[sender], value),
+        }
+    }
+}
+
+// Callers are never the zero address, every other account can be
+fn sender() -> impl Strategy<Value = usize> {
+    1..ACTORS.len()
+}
+
+fn account() -> impl Strategy<Value = usize> {
+    0..ACTORS.len()
+}
+
+// Mostly small amounts so balances run out, sometimes the maximum
+fn amount() -> impl Strategy<Value = U256> {
+    prop_oneof![
+        8 => (0u64..=1_000).prop_map(U256::from),
+        1 => Just(U256::MAX),
+    ]
+}
+
+/// A single random operation
+pub fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (sender(), account(), amount())
+            .prop_map(|(sender, to, value)| Op::Transfer { sender, to, value }),
+        2 => (sender(), account(), amount())
+            .prop_map(|(sender, spender, value)| Op::Approve { sender, spender, value }),
+        3 => (sender(), account(), account(), amount())
+            .prop_map(|(sender, from, to, value)| Op::TransferFrom { sender, from, to, value }),
+        // Minted amounts stay small so the supply never overflows
+        2 => (account(), 0u64..=5_000)
+            .prop_map(|(to, value)| Op::Mint { to, value: U256::from(value) }),
+        1 => (sender(), amount()).prop_map(|(sender, value)| Op::Burn { sender, value }),
+    ]
+}
+
+/// A random sequence of up to `max_len` operations
+pub fn ops(max_len: usize) -> impl Strategy<Value = Vec<Op>> {
+    prop::collection::vec(op(), 1..=max_len)
+}
+
+/// Known differences from OpenZeppelin a token keeps, applied to the model
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Deviations {
+    /// Reverts with `ERC20InvalidSender` for a zero approver,
+    /// `ERC20InvalidReciver` for a zero spender or receiver, and reports the
+    /// owner instead of the spender in `ERC20InsufficientAllowance`
+    pub legacy_errors: bool,
+    /// Spends `U256::MAX` allowances like any other instead of treating them as infinite
+    pub finite_max_allowance: bool,
+}
+
+/// The first point where the subject and the reference model disagree
+#[derive(Debug)]
+pub struct Divergence {
+    pub step: usize,
+    pub op: Op,
+    pub what: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "divergence at step {}: {}", self.step, self.op)?;
+        writeln!(f, "  {}", self.what)?;
+        writeln!(f, "  expected (OpenZeppelin): {}", self.expected)?;
+        write!(f, "  actual:                  {}", self.actual)
+    }
+}
+
+/// Apply `ops` to `subject` and a fresh [`OzErc20`], stopping at the first divergence
+pub fn run(subject: &mut impl Erc20Subject, ops: &[Op]) -> Result<(), Box<Divergence>> {
+    run_with(subject, ops, Deviations::default())
+}
+
+/// Like [`run`], with the model following `deviations`
+pub fn run_with(
+    subject: &mut impl Erc20Subject,
+    ops: &[Op],
+    deviations: Deviations,
+) -> Result<(), Box<Divergence>> {
+    let mut model = OzErc20::with_deviations(deviations);
+
+    for (step, op) in ops.iter().enumerate() {
+        let diverged = |what: String, expected: String, actual: String| {
+            Box::new(Divergence { step, op: op.clone(), what, expected, actual })
+        };
+
+        let expected = op.apply(&mut model);
+        let actual = op.apply(subject);
+        if expected != actual {
+            return Err(diverged(
+                "call result differs".into(),
+                describe_result(&expected),
+                describe_result(&actual),
+            ));
+        }
+
+        let expected_logs = model.take_logs();
+        let actual_logs = subject.take_logs();
+        if expected_logs != actual_logs {
+            return Err(diverged(
+                "emitted logs differ".into(),
+                describe_logs(&expected_logs),
+                describe_logs(&actual_logs),
+            ));
+        }
+
+        if model.total_supply() != subject.total_supply() {
+            return Err(diverged(
+                "totalSupply() differs".into(),
+                model.total_supply().to_string(),
+                subject.total_supply().to_string(),
+            ));
+        }
+        for owner in ACTORS {
+            if model.balance_of(owner) != subject.balance_of(owner) {
+                return Err(diverged(
+                    format!("balanceOf({}) differs", owner),
+                    model.balance_of(owner).to_string(),
+                    subject.balance_of(owner).to_string(),
+                ));
+            }
+            for spender in ACTORS {
+                if model.allowance(owner, spender) != subject.allowance(owner, spender) {
+                    return Err(diverged(
+                        format!("allowance({}, {}) differs", owner, spender),
+                        model.allowance(owner, spender).to_string(),
+                        subject.allowance(owner, spender).to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn describe_result(result: &Result<(), Vec<u8>>) -> String {
+    match result {
+        Ok(()) => "success".into(),
+        Err(data) => format!("revert {}", describe_revert(data)),
+    }
+}
+
+/// Name a revert by its OpenZeppelin error selector
+pub fn describe_revert(data: &[u8]) -> String {
+    let known = [
+        (ERC20InsufficientBalance::SELECTOR, ERC20InsufficientBalance::SIGNATURE),
+        (ERC20InvalidSender::SELECTOR, ERC20InvalidSender::SIGNATURE),
+        (ERC20InvalidReceiver::SELECTOR, ERC20InvalidReceiver::SIGNATURE),
+        (ERC20InsufficientAllowance::SELECTOR, ERC20InsufficientAllowance::SIGNATURE),
+        (ERC20InvalidApprover::SELECTOR, ERC20InvalidApprover::SIGNATURE),
+        (ERC20InvalidSpender::SELECTOR, ERC20InvalidSpender::SIGNATURE),
+        (ERC20InvalidReciver::SELECTOR, ERC20InvalidReciver::SIGNATURE),
+    ];
+    match known.iter().find(|(selector, _)| data.starts_with(selector)) {
+        Some((_, signature)) => format!("{} 0x{}", signature, hex::encode(&data[4..])),
+        None => format!("<unknown> 0x{}", hex::encode(data)),
+    }
+}
+
+fn describe_logs(logs: &[LogData]) -> String {
+    let logs: Vec<String> = logs
+        .iter()
+        .map(|log| {
+            let name = match log.topics().first() {
+                Some(topic) if *topic == Transfer::SIGNATURE_HASH => "Transfer",
+                Some(topic) if *topic == Approval::SIGNATURE_HASH => "Approval",
+                _ => "<unknown>",
+            };
+            let topics: Vec<String> = log.topics().iter().skip(1).map(|t| t.to_string()).collect();
+            format!("{}([{}], 0x{})", name, topics.join(", "), hex::encode(&log.data))
+        })
+        .collect();
+    format!("[{}]", logs.join(", "))
+}
+
+/// OpenZeppelin `ERC20` (v5) with public `mint` and `burn`
+#[derive(Clone, Debug, Default)]
+pub struct OzErc20 {
+    balances: HashMap<Address, U256>,
+    allowances: HashMap<(Address, Address), U256>,
+    total_supply: U256,
+    logs: Vec<LogData>,
+    deviations: Deviations,
+}
+
+impl OzErc20 {
+    pub fn with_deviations(deviations: Deviations) -> Self {
+        OzErc20 { deviations, ..Default::default() }
+    }
+
+    fn invalid_receiver(&self, receiver: Address) -> Vec<u8> {
+        if self.deviations.legacy_errors {
+            ERC20InvalidReciver { reciver: receiver }.abi_encode()
+        } else {
+            ERC20InvalidReceiver { receiver }.abi_encode()
+        }
+    }
+
+    fn _transfer(&mut self, from: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        if from.is_zero() {
+            return Err(ERC20InvalidSender { sender: from }.abi_encode());
+        }
+        if to.is_zero() {
+            return Err(self.invalid_receiver(to));
+        }
+        self._update(from, to, value)
+    }
+
+    fn _update(&mut self, from: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        if from.is_zero() {
+            self.total_supply += value;
+        } else {
+            let balance = self.balance_of(from);
+            if balance < value {
+                return Err(ERC20InsufficientBalance { sender: from, balance, needed: value }
+                    .abi_encode());
+            }
+            self.balances.insert(from, balance - value);
+        }
+
+        if to.is_zero() {
+            self.total_supply -= value;
+        } else {
+            let balance = self.balance_of(to);
+            self.balances.insert(to, balance + value);
+        }
+
+        self.logs.push(Transfer { from, to, value }.encode_log_data());
+        Ok(())
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        emit_event: bool,
+    ) -> Result<(), Vec<u8>> {
+        if owner.is_zero() {
+            if self.deviations.legacy_errors {
+                return Err(ERC20InvalidSender { sender: owner }.abi_encode());
+            }
+            return Err(ERC20InvalidApprover { approver: owner }.abi_encode());
+        }
+        if spender.is_zero() {
+            if self.deviations.legacy_errors {
+                return Err(self.invalid_receiver(spender));
+            }
+            return Err(ERC20InvalidSpender { spender }.abi_encode());
+        }
+        self.allowances.insert((owner, spender), value);
+        if emit_event {
+            self.logs.push(Approval { owner, spender, value }.encode_log_data());
+        }
+        Ok(())
+    }
+
+    // An allowance of `U256::MAX` is infinite and never decreases
+    fn _spend_allowance(&mut self, owner: Address, spender: Address, value: U256) -> Result<(), Vec<u8>> {
+        let current = self.allowance(owner, spender);
+        if current < U256::MAX || self.deviations.finite_max_allowance {
+            if current < value {
+                // The legacy error has the same selector, with the owner in place of the spender
+                let reported = if self.deviations.legacy_errors { owner } else { spender };
+                return Err(ERC20InsufficientAllowance {
+                    spender: reported,
+                    allowance: current,
+                    needed: value,
+                }
+                .abi_encode());
+            }
+            self._approve(owner, spender, current - value, false)?;
+        }
+        Ok(())
+    }
+
+    // Run `call`, rolling back state and logs if it reverts
+    fn transact(&mut self, call: impl FnOnce(&mut Self) -> Result<(), Vec<u8>>) -> Result<(), Vec<u8>> {
+        let before = self.clone();
+        let result = call(self);
+        if result.is_err() {
+            *self = before;
+        }
+        result
+    }
+}
+
+impl Erc20Subject for OzErc20 {
+    fn transfer(&mut self, sender: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.transact(|token| token._transfer(sender, to, value))
+    }
+
+    fn approve(&mut self, sender: Address, spender: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.transact(|token| token._approve(sender, spender, value, true))
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.transact(|token| {
+            token._spend_allowance(from, sender, value)?;
+            token._transfer(from, to, value)
+        })
+    }
+
+    fn mint(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.transact(|token| {
+            if to.is_zero() {
+                return Err(token.invalid_receiver(to));
+            }
+            token._update(Address::ZERO, to, value)
+        })
+    }
+
+    fn burn(&mut self, sender: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.transact(|token| {
+            if sender.is_zero() {
+                return Err(ERC20InvalidSender { sender }.abi_encode());
+            }
+            token._update(sender, Address::ZERO, value)
+        })
+    }
+
+    fn balance_of(&self, account: Address) -> U256 {
+        self.balances.get(&account).copied().unwrap_or_default()
+    }
+
+    fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        self.allowances.get(&(owner, spender)).copied().unwrap_or_default()
+    }
+
+    fn total_supply(&self) -> U256 {
+        self.total_supply
+    }
+
+    fn take_logs(&mut self) -> Vec<LogData> {
+        std::mem::take(&mut self.logs)
+    }
+}
@@ -0,0 +1,33 @@
+//! stylus-testkit - Multi-contract test harness for Stylus contracts
+//!
+//! `TestVM` runs one contract at a time and answers external calls from mocks. A [`World`]
+//! instead holds any number of contracts at their own addresses, each with its own storage, and
+//! routes calls between them through their real ABI routers, so contracts can be tested together
+//! without a node:
+//!
+//! ```ignore
+//! let world = World::new();
+//! world.set_sender(OWNER);
+//! let mut token = world.deploy::<ERC20>(TOKEN);
+//! token.constructor("Token".into(), "TKN".into());
+//!
+//! world.set_sender(ALICE);
+//! token.transact(|token| token.transfer(BOB, U256::from(1)))?;
+//! let transfers = world.events_from::<Transfer>(TOKEN);
+//! ```
+//!
+//! Calls that return an error roll back every storage write, balance change and log made during
+//! them, as on chain. Contracts revert when re-entered unless [`Deployed::allow_reentrancy`] is
+//! called, matching Stylus contracts built without the `reentrant` feature. With this crate's
+//! `reentrant` feature they can always be re-entered.
+//!
+//! With the `differential` feature, [`differential`] checks ERC-20 tokens against a model of
+//! OpenZeppelin's `ERC20`.
+
+#[cfg(feature = "differential")]
+pub mod differential;
+mod vm;
+mod world;
+
+pub use vm::{ContractVM, HandlerCall};
+pub use world::{Deployed, Log, World};
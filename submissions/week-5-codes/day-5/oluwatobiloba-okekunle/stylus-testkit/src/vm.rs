@@ -0,0 +1,454 @@
+use std::slice;
+
+use alloy_primitives::{keccak256, Address, B256, U256};
+use stylus_sdk::{
+    stylus_core::{
+        calls::{
+            errors::Error, CallAccess, CallContext, MutatingCallContext, StaticCallContext,
+            ValueTransfer,
+        },
+        deploy::DeploymentAccess,
+        host::*,
+    },
+    ArbResult,
+};
+
+use crate::world::{Frame, Log, World};
+
+// Code reported for contracts in the world, the prefix of deployed Stylus programs
+const STYLUS_CODE: [u8; 3] = [0xEF, 0xF0, 0x00];
+
+// Revert data of every attempt to deploy a contract from inside the world
+const DEPLOY_ERROR: &[u8] = b"deploy contracts with World::deploy";
+
+/// Host of one contract in a [`World`]
+///
+/// Storage reads and writes go to the contract at `address`, and calls are routed to the
+/// contracts registered in the world.
+#[derive(Clone)]
+pub struct ContractVM {
+    world: World,
+    address: Address,
+    calldata: Vec<u8>,
+}
+
+impl ContractVM {
+    pub(crate) fn new(world: World, address: Address, calldata: &[u8]) -> Self {
+        ContractVM {
+            world,
+            address,
+            calldata: calldata.to_vec(),
+        }
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    fn call_frame(&self, to: Address, value: U256, is_static: bool) -> Frame {
+        Frame {
+            address: to,
+            sender: self.address,
+            value,
+            is_static,
+        }
+    }
+
+    // Refuse a state change in a static call, which reverts the current call once it returns
+    fn check_writable(&self, what: &str) -> Result<(), Vec<u8>> {
+        let mut state = self.world.state.borrow_mut();
+        if !state.is_static() {
+            return Ok(());
+        }
+        let reason = format!("{what} in a static call to {}", self.address).into_bytes();
+        state.static_write = Some((state.frames.len(), reason.clone()));
+        Err(reason)
+    }
+
+    fn call_with_value(&self, to: Address, data: &[u8], value: U256) -> ArbResult {
+        if !value.is_zero() {
+            self.check_writable("value transfer")?;
+        }
+        let is_static = self.world.state.borrow().is_static();
+        let frame = self.call_frame(to, value, is_static);
+        self.world.execute(frame, to, data)
+    }
+
+    fn static_call_to(&self, to: Address, data: &[u8]) -> ArbResult {
+        let frame = self.call_frame(to, U256::ZERO, true);
+        self.world.execute(frame, to, data)
+    }
+
+    // Run the code at `to` on this contract's storage, keeping the current sender and value
+    fn delegate_call_to(&self, to: Address, data: &[u8]) -> ArbResult {
+        let frame = {
+            let state = self.world.state.borrow();
+            Frame {
+                address: self.address,
+                sender: state.msg_sender(),
+                value: state.msg_value(),
+                is_static: state.is_static(),
+            }
+        };
+        self.world.execute(frame, to, data)
+    }
+
+    // Status of a raw call, 0 if it succeeded and 1 if it reverted
+    fn raw_status(&self, result: ArbResult, outs_len: &mut usize) -> u8 {
+        let (status, data) = match result {
+            Ok(data) => (0, data),
+            Err(data) => (1, data),
+        };
+        *outs_len = data.len();
+        self.world.state.borrow_mut().return_data = data;
+        status
+    }
+
+    // Fail a raw deployment as if the constructor reverted, leaving the zero address
+    unsafe fn refuse_deploy(&self, contract: *mut u8, revert_data_len: *mut usize) {
+        contract.write_bytes(0, 20);
+        *revert_data_len = DEPLOY_ERROR.len();
+        self.world.state.borrow_mut().return_data = DEPLOY_ERROR.to_vec();
+    }
+}
+
+/// Context for calls made by handlers given to [`World::register`](crate::World::register)
+///
+/// Stands in for `Call::new()`, which doesn't exist with the `reentrant` feature.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HandlerCall {
+    value: U256,
+}
+
+impl HandlerCall {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send `value` with the call
+    pub fn with_value(value: U256) -> Self {
+        HandlerCall { value }
+    }
+}
+
+impl CallContext for HandlerCall {
+    fn gas(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl StaticCallContext for HandlerCall {}
+
+unsafe impl MutatingCallContext for HandlerCall {
+    fn value(&self) -> U256 {
+        self.value
+    }
+}
+
+impl Host for ContractVM {}
+
+impl CryptographyAccess for ContractVM {
+    fn native_keccak256(&self, input: &[u8]) -> B256 {
+        keccak256(input)
+    }
+}
+
+impl CalldataAccess for ContractVM {
+    fn read_args(&self, len: usize) -> Vec<u8> {
+        self.calldata[..len.min(self.calldata.len())].to_vec()
+    }
+
+    fn read_return_data(&self, offset: usize, size: Option<usize>) -> Vec<u8> {
+        let state = self.world.state.borrow();
+        let data = state.return_data.get(offset..).unwrap_or_default();
+        let size = size.unwrap_or(data.len()).min(data.len());
+        data[..size].to_vec()
+    }
+
+    fn return_data_size(&self) -> usize {
+        self.world.state.borrow().return_data.len()
+    }
+
+    fn write_result(&self, _data: &[u8]) {}
+}
+
+unsafe impl UnsafeDeploymentAccess for ContractVM {
+    unsafe fn create1(
+        &self,
+        _code: *const u8,
+        _code_len: usize,
+        _endowment: *const u8,
+        contract: *mut u8,
+        revert_data_len: *mut usize,
+    ) {
+        self.refuse_deploy(contract, revert_data_len)
+    }
+
+    unsafe fn create2(
+        &self,
+        _code: *const u8,
+        _code_len: usize,
+        _endowment: *const u8,
+        _salt: *const u8,
+        contract: *mut u8,
+        revert_data_len: *mut usize,
+    ) {
+        self.refuse_deploy(contract, revert_data_len)
+    }
+}
+
+impl StorageAccess for ContractVM {
+    fn storage_load_bytes32(&self, key: U256) -> B256 {
+        self.world.storage(self.address, key)
+    }
+
+    unsafe fn storage_cache_bytes32(&self, key: U256, value: B256) {
+        if self.check_writable("storage write").is_err() {
+            return;
+        }
+        let mut state = self.world.state.borrow_mut();
+        let storage = state.journal.storage.entry(self.address).or_default();
+        storage.insert(key, value);
+    }
+
+    fn flush_cache(&self, _clear: bool) {}
+}
+
+unsafe impl UnsafeCallAccess for ContractVM {
+    unsafe fn call_contract(
+        &self,
+        to: *const u8,
+        data: *const u8,
+        data_len: usize,
+        value: *const u8,
+        _gas: u64,
+        outs_len: &mut usize,
+    ) -> u8 {
+        let to = Address::from_slice(slice::from_raw_parts(to, 20));
+        let data = slice::from_raw_parts(data, data_len);
+        let value = U256::from_be_slice(slice::from_raw_parts(value, 32));
+        self.raw_status(self.call_with_value(to, data, value), outs_len)
+    }
+
+    unsafe fn delegate_call_contract(
+        &self,
+        to: *const u8,
+        data: *const u8,
+        data_len: usize,
+        _gas: u64,
+        outs_len: &mut usize,
+    ) -> u8 {
+        let to = Address::from_slice(slice::from_raw_parts(to, 20));
+        let data = slice::from_raw_parts(data, data_len);
+        self.raw_status(self.delegate_call_to(to, data), outs_len)
+    }
+
+    unsafe fn static_call_contract(
+        &self,
+        to: *const u8,
+        data: *const u8,
+        data_len: usize,
+        _gas: u64,
+        outs_len: &mut usize,
+    ) -> u8 {
+        let to = Address::from_slice(slice::from_raw_parts(to, 20));
+        let data = slice::from_raw_parts(data, data_len);
+        self.raw_status(self.static_call_to(to, data), outs_len)
+    }
+}
+
+impl BlockAccess for ContractVM {
+    fn block_basefee(&self) -> U256 {
+        U256::ZERO
+    }
+
+    fn block_coinbase(&self) -> Address {
+        Address::ZERO
+    }
+
+    fn block_number(&self) -> u64 {
+        self.world.block_number()
+    }
+
+    fn block_timestamp(&self) -> u64 {
+        self.world.block_timestamp()
+    }
+
+    fn block_gas_limit(&self) -> u64 {
+        30_000_000
+    }
+}
+
+impl ChainAccess for ContractVM {
+    fn chain_id(&self) -> u64 {
+        self.world.state.borrow().chain_id
+    }
+}
+
+impl AccountAccess for ContractVM {
+    fn balance(&self, account: Address) -> U256 {
+        self.world.balance(account)
+    }
+
+    fn contract_address(&self) -> Address {
+        self.address
+    }
+
+    fn code(&self, account: Address) -> Vec<u8> {
+        if self.world.state.borrow().is_contract(account) {
+            STYLUS_CODE.to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn code_size(&self, account: Address) -> usize {
+        self.code(account).len()
+    }
+
+    fn code_hash(&self, account: Address) -> B256 {
+        let code = self.code(account);
+        if code.is_empty() {
+            B256::ZERO
+        } else {
+            keccak256(code)
+        }
+    }
+}
+
+impl MemoryAccess for ContractVM {
+    fn pay_for_memory_grow(&self, _pages: u16) {}
+}
+
+impl MessageAccess for ContractVM {
+    fn msg_sender(&self) -> Address {
+        self.world.state.borrow().msg_sender()
+    }
+
+    fn msg_reentrant(&self) -> bool {
+        let state = self.world.state.borrow();
+        let calls = state
+            .frames
+            .iter()
+            .filter(|frame| frame.address == self.address);
+        calls.count() > 1
+    }
+
+    fn msg_value(&self) -> U256 {
+        self.world.state.borrow().msg_value()
+    }
+
+    fn tx_origin(&self) -> Address {
+        self.world.state.borrow().tx_origin()
+    }
+}
+
+impl MeteringAccess for ContractVM {
+    fn evm_gas_left(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn evm_ink_left(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn tx_gas_price(&self) -> U256 {
+        U256::ZERO
+    }
+
+    fn tx_ink_price(&self) -> u32 {
+        10_000
+    }
+}
+
+impl CallAccess for ContractVM {
+    fn static_call(
+        &self,
+        _context: &dyn StaticCallContext,
+        to: Address,
+        data: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        self.static_call_to(to, data).map_err(Error::Revert)
+    }
+
+    unsafe fn delegate_call(
+        &self,
+        _context: &dyn MutatingCallContext,
+        to: Address,
+        data: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        self.delegate_call_to(to, data).map_err(Error::Revert)
+    }
+
+    fn call(
+        &self,
+        context: &dyn MutatingCallContext,
+        to: Address,
+        data: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        self.call_with_value(to, data, context.value())
+            .map_err(Error::Revert)
+    }
+}
+
+impl ValueTransfer for ContractVM {
+    #[cfg(feature = "reentrant")]
+    fn transfer_eth(
+        &self,
+        _storage: &mut dyn stylus_sdk::stylus_core::storage::TopLevelStorage,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.call_with_value(to, &[], amount).map(|_| ())
+    }
+
+    #[cfg(not(feature = "reentrant"))]
+    fn transfer_eth(&self, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        self.call_with_value(to, &[], amount).map(|_| ())
+    }
+}
+
+impl DeploymentAccess for ContractVM {
+    #[cfg(feature = "reentrant")]
+    unsafe fn deploy(
+        &self,
+        _code: &[u8],
+        _endowment: U256,
+        _salt: Option<B256>,
+        _cache_policy: stylus_sdk::stylus_core::deploy::CachePolicy,
+    ) -> Result<Address, Vec<u8>> {
+        Err(DEPLOY_ERROR.to_vec())
+    }
+
+    #[cfg(not(feature = "reentrant"))]
+    unsafe fn deploy(
+        &self,
+        _code: &[u8],
+        _endowment: U256,
+        _salt: Option<B256>,
+    ) -> Result<Address, Vec<u8>> {
+        Err(DEPLOY_ERROR.to_vec())
+    }
+}
+
+impl LogAccess for ContractVM {
+    fn emit_log(&self, input: &[u8], num_topics: usize) {
+        let (topics, data) = input.split_at(num_topics * 32);
+        let topics: Vec<B256> = topics.chunks(32).map(B256::from_slice).collect();
+        // A log refused in a static call already reverts the call
+        let _ = self.raw_log(&topics, data);
+    }
+
+    fn raw_log(&self, topics: &[B256], data: &[u8]) -> Result<(), &'static str> {
+        if self.check_writable("log").is_err() {
+            return Err("log in a static call");
+        }
+        let log = Log {
+            address: self.address,
+            topics: topics.to_vec(),
+            data: data.to_vec(),
+        };
+        self.world.state.borrow_mut().journal.logs.push(log);
+        Ok(())
+    }
+}
@@ -0,0 +1,450 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::{SolCall, SolEvent};
+use stylus_sdk::{
+    abi::{router_entrypoint, Router},
+    host::VM,
+    prelude::*,
+    ArbResult,
+};
+
+use crate::vm::ContractVM;
+
+type Handler = Rc<dyn Fn(ContractVM, &[u8]) -> ArbResult>;
+
+/// Event emitted by the contract at `address`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Vec<u8>,
+}
+
+struct Account {
+    handler: Handler,
+    reentrant: bool,
+}
+
+// Everything a revert has to undo
+#[derive(Clone, Default)]
+pub(crate) struct Journal {
+    pub storage: HashMap<Address, HashMap<U256, B256>>,
+    pub balances: HashMap<Address, U256>,
+    pub logs: Vec<Log>,
+}
+
+// One call on the call stack: `sender` called `address` with `value`
+#[derive(Clone, Copy)]
+pub(crate) struct Frame {
+    pub address: Address,
+    pub sender: Address,
+    pub value: U256,
+    pub is_static: bool,
+}
+
+pub(crate) struct State {
+    pub journal: Journal,
+    accounts: HashMap<Address, Account>,
+    pub frames: Vec<Frame>,
+    pub sender: Address,
+    pub value: U256,
+    pub block_number: u64,
+    pub block_timestamp: u64,
+    pub chain_id: u64,
+    pub return_data: Vec<u8>,
+    // Call depth and revert data of a state change refused in a static call
+    pub static_write: Option<(usize, Vec<u8>)>,
+}
+
+impl State {
+    // Caller of the innermost call, or the configured sender outside of any call
+    pub fn msg_sender(&self) -> Address {
+        self.frames.last().map_or(self.sender, |frame| frame.sender)
+    }
+
+    pub fn msg_value(&self) -> U256 {
+        self.frames.last().map_or(self.value, |frame| frame.value)
+    }
+
+    pub fn tx_origin(&self) -> Address {
+        self.frames
+            .first()
+            .map_or(self.sender, |frame| frame.sender)
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.frames.iter().any(|frame| frame.is_static)
+    }
+
+    pub fn is_contract(&self, address: Address) -> bool {
+        self.accounts.contains_key(&address)
+    }
+
+    // Revert data of a state change the innermost call attempted in a static call, if any
+    fn take_static_write(&mut self) -> Option<Vec<u8>> {
+        let depth = self.frames.len();
+        match self.static_write.take() {
+            Some((at, reason)) if at == depth => Some(reason),
+            other => {
+                self.static_write = other;
+                None
+            }
+        }
+    }
+
+    fn move_value(&mut self, from: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        if value.is_zero() {
+            return Ok(());
+        }
+        let balances = &mut self.journal.balances;
+        let from_balance = balances.get(&from).copied().unwrap_or_default();
+        if from_balance < value {
+            return Err(b"insufficient funds for transfer".to_vec());
+        }
+        balances.insert(from, from_balance - value);
+        *balances.entry(to).or_default() += value;
+        Ok(())
+    }
+}
+
+/// A set of contracts sharing one chain state
+///
+/// Cloning a `World` gives another handle to the same state.
+#[derive(Clone)]
+pub struct World {
+    pub(crate) state: Rc<RefCell<State>>,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl World {
+    pub fn new() -> Self {
+        let state = State {
+            journal: Journal::default(),
+            accounts: HashMap::new(),
+            frames: Vec::new(),
+            sender: Address::ZERO,
+            value: U256::ZERO,
+            block_number: 1,
+            block_timestamp: 1,
+            chain_id: 42161,
+            return_data: Vec::new(),
+            static_write: None,
+        };
+        World {
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// Deploy a Stylus contract at `address` and return a handle to it
+    ///
+    /// Calls from other contracts reach it through its `#[public]` router. Its constructor is not
+    /// run, call it on the handle.
+    pub fn deploy<C>(&self, address: Address) -> Deployed<C>
+    where
+        C: Router<C> + StorageType + TopLevelStorage + ValueDenier + 'static,
+        C: std::borrow::BorrowMut<<C as Router<C>>::Storage>,
+    {
+        self.register(address, |vm, input| {
+            router_entrypoint::<C, C>(input.to_vec(), VM { host: Box::new(vm) })
+        });
+        let vm = ContractVM::new(self.clone(), address, &[]);
+        let contract = unsafe { C::new(U256::ZERO, 0, VM { host: Box::new(vm) }) };
+        Deployed {
+            world: self.clone(),
+            address,
+            contract,
+        }
+    }
+
+    /// Put a hand-written contract at `address`, e.g. a mock or a precompile
+    ///
+    /// `handler` gets the calldata and a VM for the call, through which it can read the sender and
+    /// value and call other contracts with a [`HandlerCall`](crate::HandlerCall).
+    pub fn register(
+        &self,
+        address: Address,
+        handler: impl Fn(ContractVM, &[u8]) -> ArbResult + 'static,
+    ) {
+        let account = Account {
+            handler: Rc::new(handler),
+            reentrant: cfg!(feature = "reentrant"),
+        };
+        self.state.borrow_mut().accounts.insert(address, account);
+    }
+
+    /// Let the contract at `address` be called again while it is already executing
+    ///
+    /// With the `reentrant` feature every contract can be re-entered already.
+    pub fn allow_reentrancy(&self, address: Address) {
+        if let Some(account) = self.state.borrow_mut().accounts.get_mut(&address) {
+            account.reentrant = true;
+        }
+    }
+
+    /// Address that calls contracts from outside, and the origin of every transaction
+    pub fn set_sender(&self, sender: Address) {
+        self.state.borrow_mut().sender = sender;
+    }
+
+    pub fn sender(&self) -> Address {
+        self.state.borrow().sender
+    }
+
+    /// Value sent with every following transaction, taken from the sender's balance
+    pub fn set_value(&self, value: U256) {
+        self.state.borrow_mut().value = value;
+    }
+
+    pub fn value(&self) -> U256 {
+        self.state.borrow().value
+    }
+
+    pub fn set_block_number(&self, block_number: u64) {
+        self.state.borrow_mut().block_number = block_number;
+    }
+
+    pub fn block_number(&self) -> u64 {
+        self.state.borrow().block_number
+    }
+
+    pub fn set_block_timestamp(&self, timestamp: u64) {
+        self.state.borrow_mut().block_timestamp = timestamp;
+    }
+
+    pub fn block_timestamp(&self) -> u64 {
+        self.state.borrow().block_timestamp
+    }
+
+    /// Move `blocks` blocks forward, 12 seconds each
+    pub fn advance_blocks(&self, blocks: u64) {
+        let mut state = self.state.borrow_mut();
+        state.block_number += blocks;
+        state.block_timestamp += blocks * 12;
+    }
+
+    pub fn set_chain_id(&self, chain_id: u64) {
+        self.state.borrow_mut().chain_id = chain_id;
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.state.borrow().chain_id
+    }
+
+    pub fn set_balance(&self, account: Address, balance: U256) {
+        self.state
+            .borrow_mut()
+            .journal
+            .balances
+            .insert(account, balance);
+    }
+
+    pub fn balance(&self, account: Address) -> U256 {
+        let state = self.state.borrow();
+        state
+            .journal
+            .balances
+            .get(&account)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Storage word `key` of the contract at `address`
+    pub fn storage(&self, address: Address, key: U256) -> B256 {
+        let state = self.state.borrow();
+        state
+            .journal
+            .storage
+            .get(&address)
+            .and_then(|storage| storage.get(&key).copied())
+            .unwrap_or_default()
+    }
+
+    /// Send a transaction with raw calldata from the sender to `to`
+    pub fn call(&self, to: Address, calldata: &[u8]) -> ArbResult {
+        let frame = self.top_frame(to);
+        self.execute(frame, to, calldata)
+    }
+
+    /// Run a call like [`World::call`] and roll back all its changes, like `eth_call`
+    pub fn simulate(&self, to: Address, calldata: &[u8]) -> ArbResult {
+        let snapshot = self.state.borrow().journal.clone();
+        let result = self.call(to, calldata);
+        self.state.borrow_mut().journal = snapshot;
+        result
+    }
+
+    /// Send a transaction encoded from a `sol!` call and decode what it returns
+    pub fn call_sol<T: SolCall>(&self, to: Address, call: &T) -> Result<T::Return, Vec<u8>> {
+        let output = self.call(to, &call.abi_encode())?;
+        T::abi_decode_returns(&output, true).map_err(|_| output)
+    }
+
+    /// Run `f` as a transaction from the sender to the contract at `to`
+    ///
+    /// If `f` returns an error, all its changes are rolled back.
+    pub fn transact<R, E>(&self, to: Address, f: impl FnOnce() -> Result<R, E>) -> Result<R, E> {
+        let frame = self.top_frame(to);
+        let snapshot = self.state.borrow().journal.clone();
+        {
+            let mut state = self.state.borrow_mut();
+            if state.move_value(frame.sender, to, frame.value).is_err() {
+                panic!("{} can't pay the value of the transaction", frame.sender);
+            }
+            state.frames.push(frame);
+        }
+
+        let result = f();
+
+        let mut state = self.state.borrow_mut();
+        state.frames.pop();
+        if result.is_err() {
+            state.journal = snapshot;
+        }
+        result
+    }
+
+    /// Every event emitted so far, oldest first
+    pub fn logs(&self) -> Vec<Log> {
+        self.state.borrow().journal.logs.clone()
+    }
+
+    pub fn clear_logs(&self) {
+        self.state.borrow_mut().journal.logs.clear();
+    }
+
+    /// Every `E` event emitted so far, by any contract
+    pub fn events<E: SolEvent>(&self) -> Vec<E> {
+        self.decode_events(|_| true)
+    }
+
+    /// Every `E` event emitted so far by the contract at `address`
+    pub fn events_from<E: SolEvent>(&self, address: Address) -> Vec<E> {
+        self.decode_events(|log| log.address == address)
+    }
+
+    fn decode_events<E: SolEvent>(&self, filter: impl Fn(&Log) -> bool) -> Vec<E> {
+        let state = self.state.borrow();
+        state
+            .journal
+            .logs
+            .iter()
+            .filter(|log| filter(log) && log.topics.first() == Some(&E::SIGNATURE_HASH))
+            .filter_map(|log| E::decode_raw_log(log.topics.iter().copied(), &log.data, true).ok())
+            .collect()
+    }
+
+    fn top_frame(&self, to: Address) -> Frame {
+        let state = self.state.borrow();
+        Frame {
+            address: to,
+            sender: state.sender,
+            value: state.value,
+            is_static: false,
+        }
+    }
+
+    // Run the code at `code` for `frame`, rolling back its changes if it fails
+    pub(crate) fn execute(&self, frame: Frame, code: Address, input: &[u8]) -> ArbResult {
+        let snapshot = self.state.borrow().journal.clone();
+        let result = self.run(frame, code, input);
+
+        let mut state = self.state.borrow_mut();
+        if result.is_err() {
+            state.journal = snapshot;
+        }
+        state.return_data = match &result {
+            Ok(data) | Err(data) => data.clone(),
+        };
+        result
+    }
+
+    fn run(&self, frame: Frame, code: Address, input: &[u8]) -> ArbResult {
+        let handler = {
+            let mut state = self.state.borrow_mut();
+            // Delegate calls run other code on the caller's storage with the value already paid to
+            // it, and are not re-entries
+            let delegated = code != frame.address;
+            if !delegated {
+                state.move_value(frame.sender, frame.address, frame.value)?;
+            }
+
+            // Calls to accounts without code just move value
+            let Some(account) = state.accounts.get(&code) else {
+                return Ok(Vec::new());
+            };
+            let handler = account.handler.clone();
+            let reentered = !delegated && state.frames.iter().any(|f| f.address == frame.address);
+            if reentered && !account.reentrant {
+                return Err(Vec::new());
+            }
+            state.frames.push(frame);
+            handler
+        };
+
+        let result = handler(ContractVM::new(self.clone(), frame.address, input), input);
+        let mut state = self.state.borrow_mut();
+        // A state change refused in a static call reverts the call that attempted it
+        let result = match state.take_static_write() {
+            Some(reason) => Err(reason),
+            None => result,
+        };
+        state.frames.pop();
+        result
+    }
+}
+
+/// Handle to a contract deployed in a [`World`]
+///
+/// Dereferences to the contract, so views can be called directly. Calls made directly run with
+/// the world's sender but are not rolled back on error, use [`Deployed::transact`] for that.
+pub struct Deployed<C> {
+    world: World,
+    address: Address,
+    contract: C,
+}
+
+impl<C> Deployed<C> {
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Call the contract in a transaction from the world's sender, see [`World::transact`]
+    pub fn transact<R, E>(&mut self, f: impl FnOnce(&mut C) -> Result<R, E>) -> Result<R, E> {
+        let contract = &mut self.contract;
+        self.world.transact(self.address, || f(contract))
+    }
+
+    /// Let this contract be re-entered, as if built with the `reentrant` feature
+    pub fn allow_reentrancy(&self) {
+        self.world.allow_reentrancy(self.address);
+    }
+}
+
+impl<C> Deref for Deployed<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.contract
+    }
+}
+
+impl<C> DerefMut for Deployed<C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.contract
+    }
+}
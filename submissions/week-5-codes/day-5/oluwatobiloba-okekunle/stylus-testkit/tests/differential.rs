@@ -0,0 +1,45 @@
+//! The ERC-20 differential harness, run against its own OpenZeppelin model.
+#![cfg(feature = "differential")]
+
+use alloy_primitives::U256;
+use stylus_testkit::differential::{self, Deviations, Op, OzErc20, ACTORS};
+
+#[test]
+fn test_model_matches_itself() {
+    let ops = [
+        Op::Mint { to: 1, value: U256::from(100) },
+        Op::Approve { sender: 1, spender: 2, value: U256::MAX },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(40) },
+        Op::Transfer { sender: 3, to: 0, value: U256::from(1) },
+        Op::Burn { sender: 1, value: U256::from(61) },
+    ];
+    let result = differential::run(&mut OzErc20::default(), &ops);
+    assert!(result.is_ok(), "{}", result.unwrap_err());
+}
+
+#[test]
+fn test_reports_first_divergence() {
+    let ops = [
+        Op::Mint { to: 1, value: U256::from(10) },
+        Op::Approve { sender: 1, spender: 2, value: U256::MAX },
+        Op::TransferFrom { sender: 2, from: 1, to: 3, value: U256::from(4) },
+    ];
+    let deviations = Deviations { legacy_errors: false, finite_max_allowance: true };
+    let mut subject = OzErc20::with_deviations(deviations);
+
+    // The subject spends the infinite allowance
+    let divergence = differential::run(&mut subject, &ops).unwrap_err();
+    assert_eq!(divergence.step, 2);
+    assert_eq!(divergence.op, ops[2]);
+    assert_eq!(
+        divergence.what,
+        format!("allowance({}, {}) differs", ACTORS[1], ACTORS[2])
+    );
+    assert_eq!(divergence.expected, U256::MAX.to_string());
+    assert_eq!(divergence.actual, (U256::MAX - U256::from(4)).to_string());
+
+    // Accepted once the model follows the same deviation
+    let mut subject = OzErc20::with_deviations(deviations);
+    let result = differential::run_with(&mut subject, &ops, deviations);
+    assert!(result.is_ok(), "{}", result.unwrap_err());
+}
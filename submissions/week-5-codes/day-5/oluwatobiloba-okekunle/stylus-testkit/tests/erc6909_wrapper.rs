@@ -0,0 +1,190 @@
+//! The ERC-6909 token, the course ERC-20 and the wrapper factory running together.
+
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::{sol, SolError};
+use erc20_stylus::{Transfer as ERC20Transfer, ERC20};
+use erc6909_stylus::{ERC6909InsufficientAllowance, Transfer as ERC6909Transfer, ERC6909};
+use erc6909_wrapper_stylus::{Deposit, ERC6909WrapperError, ERC6909WrapperFactory};
+use stylus_testkit::{Deployed, World};
+
+const MULTI_TOKEN: Address = address!("0x6909000000000000000000000000000000000000");
+const WRAPPER: Address = address!("0x2020000000000000000000000000000000000000");
+const FACTORY: Address = address!("0xFAC7000000000000000000000000000000000000");
+const OWNER: Address = address!("0x1111111111111111111111111111111111111111");
+const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+
+const ID: U256 = U256::from_limbs([7, 0, 0, 0]);
+
+// The token methods are only reachable through their ABI
+sol! {
+    interface IERC20 {
+        function balanceOf(address owner) external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+        function approve(address spender, uint256 value) external;
+        function mint(address to, uint256 value) external;
+        function owner() external view returns (address);
+        function transferOwnership(address new_owner) external;
+    }
+
+    interface IERC6909 {
+        function mint(address to, uint256 id, uint256 value) external;
+        function approve(address spender, uint256 id, uint256 value) external;
+        function balanceOf(address owner, uint256 id) external view returns (uint256);
+        function totalSupply(uint256 id) external view returns (uint256);
+    }
+}
+
+struct Setup {
+    world: World,
+    factory: Deployed<ERC6909WrapperFactory>,
+}
+
+fn setup() -> Setup {
+    let world = World::new();
+    world.set_sender(OWNER);
+
+    let mut multi_token = world.deploy::<ERC6909>(MULTI_TOKEN);
+    multi_token.constructor("Items".into(), "ITM".into());
+    let mut token = world.deploy::<ERC20>(WRAPPER);
+    token.constructor("Wrapped Item 7".into(), "wITM7".into());
+    let mut factory = world.deploy::<ERC6909WrapperFactory>(FACTORY);
+    factory.constructor(MULTI_TOKEN);
+
+    // Hand the wrapper to the factory, which checks it owns it
+    let transfer_ownership = IERC20::transferOwnershipCall { new_owner: FACTORY };
+    world.call_sol(WRAPPER, &transfer_ownership).unwrap();
+    factory
+        .transact(|factory| factory.set_wrapper(ID, WRAPPER))
+        .unwrap();
+
+    let mint = IERC6909::mintCall {
+        to: ALICE,
+        id: ID,
+        value: U256::from(100),
+    };
+    world.call_sol(MULTI_TOKEN, &mint).unwrap();
+
+    Setup { world, factory }
+}
+
+fn wrapped_balance(world: &World, owner: Address) -> U256 {
+    world.call_sol(WRAPPER, &IERC20::balanceOfCall { owner }).unwrap()._0
+}
+
+fn wrapped_supply(world: &World) -> U256 {
+    world.call_sol(WRAPPER, &IERC20::totalSupplyCall {}).unwrap()._0
+}
+
+fn multi_token_balance(world: &World, owner: Address) -> U256 {
+    let call = IERC6909::balanceOfCall { owner, id: ID };
+    world.call_sol(MULTI_TOKEN, &call).unwrap()._0
+}
+
+#[test]
+fn test_deposit_and_redeem() {
+    let Setup { world, mut factory } = setup();
+    world.set_sender(ALICE);
+
+    let approve = IERC6909::approveCall {
+        spender: FACTORY,
+        id: ID,
+        value: U256::from(60),
+    };
+    world.call_sol(MULTI_TOKEN, &approve).unwrap();
+    factory
+        .transact(|factory| factory.deposit(ID, U256::from(60)))
+        .unwrap();
+
+    assert_eq!(wrapped_balance(&world, ALICE), U256::from(60));
+    assert_eq!(wrapped_supply(&world), U256::from(60));
+    assert_eq!(multi_token_balance(&world, ALICE), U256::from(40));
+    assert_eq!(multi_token_balance(&world, FACTORY), U256::from(60));
+
+    // Each contract logged its own side of the deposit
+    let mints = world.events_from::<ERC20Transfer>(WRAPPER);
+    assert_eq!(mints.len(), 1);
+    assert_eq!((mints[0].from, mints[0].to), (Address::ZERO, ALICE));
+    let moves = world.events_from::<ERC6909Transfer>(MULTI_TOKEN);
+    assert_eq!(
+        (moves.last().unwrap().from, moves.last().unwrap().to),
+        (ALICE, FACTORY)
+    );
+    assert_eq!(world.events::<Deposit>().len(), 1);
+
+    let approve = IERC20::approveCall {
+        spender: FACTORY,
+        value: U256::from(25),
+    };
+    world.call_sol(WRAPPER, &approve).unwrap();
+    factory
+        .transact(|factory| factory.redeem(ID, U256::from(25)))
+        .unwrap();
+
+    assert_eq!(wrapped_balance(&world, ALICE), U256::from(35));
+    assert_eq!(wrapped_supply(&world), U256::from(35));
+    assert_eq!(multi_token_balance(&world, ALICE), U256::from(65));
+    assert_eq!(multi_token_balance(&world, FACTORY), U256::from(35));
+}
+
+#[test]
+fn test_failed_deposit_rolls_back() {
+    let Setup { world, mut factory } = setup();
+    world.set_sender(ALICE);
+
+    let approve = IERC6909::approveCall {
+        spender: FACTORY,
+        id: ID,
+        value: U256::from(10),
+    };
+    world.call_sol(MULTI_TOKEN, &approve).unwrap();
+    let logs = world.logs().len();
+
+    // The ERC-6909 revert comes back wrapped in the factory's error
+    let result = factory.transact(|factory| factory.deposit(ID, U256::from(11)));
+    let Err(ERC6909WrapperError::ERC6909WrapperCallFailed(error)) = result else {
+        panic!("deposit should fail");
+    };
+    assert_eq!(error.target, MULTI_TOKEN);
+    let reason = ERC6909InsufficientAllowance::abi_decode(&error.reason, true).unwrap();
+    assert_eq!(reason.allowance, U256::from(10));
+
+    assert_eq!(wrapped_supply(&world), U256::ZERO);
+    assert_eq!(multi_token_balance(&world, ALICE), U256::from(100));
+    assert_eq!(world.logs().len(), logs);
+}
+
+#[test]
+fn test_redeem_without_allowance_rolls_back() {
+    let Setup { world, mut factory } = setup();
+    world.set_sender(ALICE);
+
+    let approve = IERC6909::approveCall {
+        spender: FACTORY,
+        id: ID,
+        value: U256::from(50),
+    };
+    world.call_sol(MULTI_TOKEN, &approve).unwrap();
+    factory
+        .transact(|factory| factory.deposit(ID, U256::from(50)))
+        .unwrap();
+
+    assert!(factory
+        .transact(|factory| factory.redeem(ID, U256::from(50)))
+        .is_err());
+    assert_eq!(wrapped_balance(&world, ALICE), U256::from(50));
+    assert_eq!(multi_token_balance(&world, FACTORY), U256::from(50));
+}
+
+#[test]
+fn test_only_factory_mints_wrapper() {
+    let Setup { world, .. } = setup();
+
+    // The ERC-20 owner is now the factory, not the deployer
+    world.set_sender(OWNER);
+    let mint = IERC20::mintCall {
+        to: OWNER,
+        value: U256::from(1),
+    };
+    assert!(world.call_sol(WRAPPER, &mint).is_err());
+    assert_eq!(world.call_sol(WRAPPER, &IERC20::ownerCall {}).unwrap()._0, FACTORY);
+}
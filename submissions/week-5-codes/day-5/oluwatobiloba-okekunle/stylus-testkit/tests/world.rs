@@ -0,0 +1,284 @@
+//! Call routing, value transfers, reverts and reentrancy in a `World`.
+
+use alloy_primitives::{address, Address, B256, U256};
+use alloy_sol_types::{sol, SolCall};
+use erc20_stylus::ERC20;
+use stylus_sdk::{
+    prelude::*,
+    stylus_core::{
+        calls::CallAccess,
+        host::{UnsafeCallAccess, UnsafeDeploymentAccess},
+    },
+};
+use stylus_testkit::{HandlerCall, World};
+
+const TOKEN: Address = address!("0x2020000000000000000000000000000000000000");
+const PROBE: Address = address!("0x9999000000000000000000000000000000000000");
+const TARGET: Address = address!("0x8888000000000000000000000000000000000000");
+const OWNER: Address = address!("0x1111111111111111111111111111111111111111");
+const ALICE: Address = address!("0x2222222222222222222222222222222222222222");
+const BOB: Address = address!("0x3333333333333333333333333333333333333333");
+
+sol! {
+    interface IERC20 {
+        function symbol() external view returns (string memory);
+        function transfer(address to, uint256 value) external;
+        function balanceOf(address owner) external view returns (uint256);
+        function mint(address to, uint256 value) external;
+        function clock() external view returns (uint48);
+    }
+}
+
+fn balance_of(world: &World, owner: Address) -> U256 {
+    world
+        .call_sol(TOKEN, &IERC20::balanceOfCall { owner })
+        .unwrap()
+        ._0
+}
+
+// Contract at PROBE that stores who called it, then forwards its calldata to `target`
+fn register_forwarder(world: &World, target: Address) {
+    world.register(PROBE, move |vm, input| {
+        unsafe { vm.storage_cache_bytes32(U256::ZERO, vm.msg_sender().into_word()) };
+        vm.call(&HandlerCall::new(), target, input)
+            .map_err(Vec::from)
+    });
+}
+
+#[test]
+fn test_calls_are_routed_between_contracts() {
+    let world = World::new();
+    world.set_sender(OWNER);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+    let mint = IERC20::mintCall {
+        to: PROBE,
+        value: U256::from(10),
+    };
+    world.call_sol(TOKEN, &mint).unwrap();
+
+    // ALICE -> PROBE -> TOKEN, so the token sees PROBE as the sender
+    register_forwarder(&world, TOKEN);
+    world.set_sender(ALICE);
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(4),
+    };
+    world.call_sol(PROBE, &transfer).unwrap();
+
+    assert_eq!(world.storage(PROBE, U256::ZERO), ALICE.into_word());
+    assert_eq!(balance_of(&world, PROBE), U256::from(6));
+    assert_eq!(balance_of(&world, BOB), U256::from(4));
+}
+
+#[test]
+fn test_revert_rolls_back_the_whole_call() {
+    let world = World::new();
+    world.set_sender(OWNER);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+    register_forwarder(&world, TOKEN);
+
+    // PROBE writes its storage, then the token reverts for lack of balance
+    world.set_sender(ALICE);
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(1),
+    };
+    assert!(world.call(PROBE, &transfer.abi_encode()).is_err());
+    assert_eq!(world.storage(PROBE, U256::ZERO), B256::ZERO);
+
+    // Errors from direct calls are rolled back by `transact`
+    let result = token.transact(|token| {
+        token.constructor("Other".into(), "OTH".into());
+        Err::<(), _>(())
+    });
+    assert!(result.is_err());
+    let symbol = world.call_sol(TOKEN, &IERC20::symbolCall {}).unwrap()._0;
+    assert_eq!(symbol, "TKN");
+}
+
+#[test]
+fn test_simulate_keeps_no_changes() {
+    let world = World::new();
+    world.set_sender(OWNER);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+    let mint = IERC20::mintCall {
+        to: ALICE,
+        value: U256::from(10),
+    };
+    world.call_sol(TOKEN, &mint).unwrap();
+
+    world.set_sender(ALICE);
+    let logs = world.logs().len();
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(4),
+    };
+    assert!(world.simulate(TOKEN, &transfer.abi_encode()).is_ok());
+    assert_eq!(balance_of(&world, ALICE), U256::from(10));
+    assert_eq!(balance_of(&world, BOB), U256::ZERO);
+    assert_eq!(world.logs().len(), logs);
+
+    // Reverts come back as they would from a transaction
+    let transfer = IERC20::transferCall {
+        to: BOB,
+        value: U256::from(11),
+    };
+    assert!(world.simulate(TOKEN, &transfer.abi_encode()).is_err());
+}
+
+#[test]
+fn test_value_transfers() {
+    let world = World::new();
+    world.register(PROBE, |vm, _| Ok(vm.msg_value().to_be_bytes_vec()));
+    world.set_balance(ALICE, U256::from(100));
+
+    world.set_sender(ALICE);
+    world.set_value(U256::from(30));
+    let output = world.call(PROBE, &[]).unwrap();
+    assert_eq!(U256::from_be_slice(&output), U256::from(30));
+    assert_eq!(world.balance(ALICE), U256::from(70));
+    assert_eq!(world.balance(PROBE), U256::from(30));
+
+    // Plain accounts just receive value
+    world.call(BOB, &[]).unwrap();
+    assert_eq!(world.balance(BOB), U256::from(30));
+
+    world.set_value(U256::from(41));
+    assert!(world.call(BOB, &[]).is_err());
+    assert_eq!(world.balance(ALICE), U256::from(40));
+}
+
+#[test]
+fn test_static_calls_cant_change_state() {
+    let world = World::new();
+    world.set_sender(ALICE);
+    world.register(TARGET, |vm, _| {
+        unsafe { vm.storage_cache_bytes32(U256::ZERO, B256::repeat_byte(1)) };
+        Ok(Vec::new())
+    });
+
+    // PROBE static-calls TARGET, whose storage write reverts it, and returns the revert data
+    world.register(PROBE, |vm, _| {
+        let result = vm.static_call(&HandlerCall::new(), TARGET, &[]);
+        Ok(result.unwrap_err().into())
+    });
+    let reason = String::from_utf8(world.call(PROBE, &[]).unwrap()).unwrap();
+    assert!(reason.starts_with("storage write in a static call"));
+    assert_eq!(world.storage(TARGET, U256::ZERO), B256::ZERO);
+
+    world.call(TARGET, &[]).unwrap();
+    assert_eq!(world.storage(TARGET, U256::ZERO), B256::repeat_byte(1));
+}
+
+#[test]
+fn test_delegate_calls_keep_sender_and_value() {
+    let world = World::new();
+    world.register(TARGET, |vm, _| {
+        let mut output = vm.msg_sender().to_vec();
+        output.extend(vm.msg_value().to_be_bytes::<32>());
+        Ok(output)
+    });
+    world.register(PROBE, |vm, _| {
+        unsafe { vm.delegate_call(&HandlerCall::new(), TARGET, &[]) }.map_err(Vec::from)
+    });
+    world.set_balance(ALICE, U256::from(100));
+
+    world.set_sender(ALICE);
+    world.set_value(U256::from(30));
+    let output = world.call(PROBE, &[]).unwrap();
+    assert_eq!(Address::from_slice(&output[..20]), ALICE);
+    assert_eq!(U256::from_be_slice(&output[20..]), U256::from(30));
+
+    // The value is paid once, to the delegating contract
+    assert_eq!(world.balance(ALICE), U256::from(70));
+    assert_eq!(world.balance(PROBE), U256::from(30));
+    assert_eq!(world.balance(TARGET), U256::ZERO);
+}
+
+#[test]
+fn test_raw_hostios() {
+    let world = World::new();
+    world.set_sender(ALICE);
+
+    // TARGET returns the first two bytes of its calldata, PROBE calls it through the raw hostio
+    world.register(TARGET, |vm, _| Ok(vm.read_args(2)));
+    world.register(PROBE, |vm, input| {
+        let value = [0; 32];
+        let mut outs_len = 0;
+        let status = unsafe {
+            vm.call_contract(
+                TARGET.as_ptr(),
+                input.as_ptr(),
+                input.len(),
+                value.as_ptr(),
+                u64::MAX,
+                &mut outs_len,
+            )
+        };
+        let mut output = vec![status];
+        output.extend(vm.read_return_data(0, Some(outs_len)));
+        Ok(output)
+    });
+    assert_eq!(world.call(PROBE, &[7, 8, 9]).unwrap(), vec![0, 7, 8]);
+
+    // Deployments fail like a reverted constructor
+    world.register(PROBE, |vm, _| {
+        let mut contract = [0xFF; 20];
+        let mut revert_data_len = 0;
+        let endowment = [0; 32];
+        unsafe {
+            vm.create1(
+                [].as_ptr(),
+                0,
+                endowment.as_ptr(),
+                contract.as_mut_ptr(),
+                &mut revert_data_len,
+            )
+        };
+        assert_eq!(contract, [0; 20]);
+        Ok(vm.read_return_data(0, Some(revert_data_len)))
+    });
+    assert_eq!(
+        world.call(PROBE, &[]).unwrap(),
+        b"deploy contracts with World::deploy"
+    );
+}
+
+#[test]
+fn test_reentrancy_is_denied_by_default() {
+    let world = World::new();
+    world.set_sender(ALICE);
+
+    // PROBE calls itself once more
+    world.register(PROBE, |vm, input| {
+        if input.is_empty() {
+            return Ok(vec![1]);
+        }
+        vm.call(&HandlerCall::new(), PROBE, &[]).map_err(Vec::from)
+    });
+    // Contracts built with the `reentrant` feature can always be re-entered
+    assert_eq!(world.call(PROBE, &[0]).is_ok(), cfg!(feature = "reentrant"));
+
+    world.allow_reentrancy(PROBE);
+    assert_eq!(world.call(PROBE, &[0]).unwrap(), vec![1]);
+}
+
+#[test]
+fn test_blocks() {
+    let world = World::new();
+    world.set_sender(OWNER);
+    let mut token = world.deploy::<ERC20>(TOKEN);
+    token.constructor("Token".into(), "TKN".into());
+
+    let clock = |world: &World| world.call_sol(TOKEN, &IERC20::clockCall {}).unwrap()._0;
+    world.set_block_number(100);
+    assert_eq!(clock(&world).to::<u64>(), 100);
+
+    let timestamp = world.block_timestamp();
+    world.advance_blocks(5);
+    assert_eq!(clock(&world).to::<u64>(), 105);
+    assert_eq!(world.block_timestamp(), timestamp + 60);
+}
@@ -1,13 +1,38 @@
-use crate::interface::{EmployeeManagementInterface, EmployeeManagementAdminInterface};
+use crate::interface::{
+    EmployeeManagementAdminInterface, EmployeeManagementApprovalInterface,
+    EmployeeManagementInterface, EmployeeManagementLeaveInterface,
+    EmployeeManagementPerformanceInterface, EmployeeManagementRankInterface,
+    EmployeeManagementSelfServiceInterface,
+};
 use crate::storage::{
-    address_is_employee, decrement_employee_count, employee_exists, get_employee_count,
-    get_employee_id_by_address, get_next_employee_id, increment_employee_count, is_initialized,
-    read_admin, read_employee, read_institution, remove_employee, set_initialized, write_admin,
-    write_employee, write_institution,
+    add_unpaid_leave_days, address_is_employee, decrement_employee_count,
+    decrement_rank_employee_count, get_employee_count, get_employee_id_by_address,
+    get_institution_count, get_last_employee_id, get_next_employee_id, get_next_institution_id,
+    get_next_leave_request_id, get_next_proposal_id, get_rank_employee_count,
+    get_unpaid_leave_days, increment_employee_count, increment_rank_employee_count,
+    is_initialized, read_admin,
+    read_approval_policy, read_deposit_token, read_employee, read_final_pay, read_institution,
+    read_leave_request, read_leave_usage, read_notice_period, read_open_leave_requests, read_paid_through,
+    read_performance_reviews,
+    read_proposal, read_rank_ladder, read_registration_deposit, read_resignation,
+    read_reviewers, remove_approval_policy, remove_employee, remove_employee_address,
+    read_deposits_held, remove_final_pay, remove_institution, remove_resignation, set_initialized, write_admin,
+    write_approval_policy, write_deposit_token, write_deposits_held, write_employee,
+    write_final_pay, write_institution, write_leave_request, write_leave_usage, write_notice_period, write_open_leave_requests, write_paid_through,
+    write_performance_reviews, write_proposal, write_rank_ladder,
+    write_registration_deposit, write_resignation, write_reviewers,
 };
 use crate::types::{
-    Employee, EmployeeAddedEvent, EmployeePromotedEvent, EmployeeRank, EmployeeStatus,
-    EmployeeStatusChangedEvent, Institution, SalaryPaidEvent,
+    average_score, default_rank_ladder, find_rank, leave_year, next_rank, pay_period,
+    ApprovalPolicy,
+    Employee, EmployeeAddedEvent, EmployeeAddressRotatedEvent, EmployeePromotedEvent,
+    EmployeeStatus, EmployeeStatusChangedEvent, EmployeeUpdatedEvent, FinalPay, Institution,
+    InstitutionRegisteredEvent, LeaveBalance, LeaveDecidedEvent, LeaveRequest,
+    LeaveRequestedEvent, LeaveStatus, LeaveType, PerformanceReview, PerformanceScoredEvent,
+    Proposal, ProposalAction, ProposalApprovedEvent, ProposalCreatedEvent,
+    ProposalExecutedEvent, ProposalStatus, RankConfig, Resignation, ResignationSubmittedEvent,
+    SalaryPaidEvent, LEDGERS_PER_DAY, LEDGERS_PER_SALARY_PERIOD,
+    MAX_CANDIDATE_SCAN, MAX_PERFORMANCE_SCORE, PERFORMANCE_WINDOW,
 };
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 use soroban_sdk::token::TokenClient;
@@ -15,22 +40,348 @@ use soroban_sdk::token::TokenClient;
 #[contract]
 pub struct EmployeeManagement;
 
+/// Load an institution or panic if it does not exist
+fn get_institution_or_panic(env: &Env, institution_id: u64) -> Institution {
+    read_institution(env, institution_id).unwrap_or_else(|| {
+        panic!("institution not found");
+    })
+}
+
+/// Load the employee registered under an address or panic if there is none
+fn get_employee_by_address_or_panic(env: &Env, institution_id: u64, address: &Address) -> Employee {
+    get_employee_id_by_address(env, institution_id, address)
+        .and_then(|employee_id| read_employee(env, institution_id, employee_id))
+        .unwrap_or_else(|| {
+            panic!("employee not found");
+        })
+}
+
+/// Panic if HR actions in the institution must go through proposals
+fn require_no_approval_policy(env: &Env, institution_id: u64) {
+    if read_approval_policy(env, institution_id).is_some() {
+        panic!("action requires approval");
+    }
+}
+
+/// Panic if an approval policy can't work as configured
+fn validate_approval_policy(policy: &ApprovalPolicy) {
+    if policy.proposers.is_empty() {
+        panic!("at least one proposer is required");
+    }
+
+    for (index, approver) in policy.approvers.iter().enumerate() {
+        if policy.approvers.last_index_of(&approver) != Some(index as u32) {
+            panic!("duplicate approver");
+        }
+    }
+
+    if policy.quorum == 0 || policy.quorum > policy.approvers.len() {
+        panic!("quorum must be between 1 and the number of approvers");
+    }
+
+    // Proposers can't approve their own proposals, so the quorum must be
+    // reachable by the other approvers
+    for proposer in policy.proposers.iter() {
+        let others = policy.approvers.iter().filter(|approver| *approver != proposer).count();
+        if policy.quorum > others as u32 {
+            panic!("quorum must be reachable without the proposer");
+        }
+    }
+
+    if policy.approval_window == 0 {
+        panic!("approval window must be positive");
+    }
+}
+
+/// Replace an institution's approval policy
+fn set_policy(env: &Env, institution_id: u64, policy: &ApprovalPolicy) {
+    write_approval_policy(env, institution_id, policy);
+
+    env.events().publish(("approval_policy_set", institution_id), policy.clone());
+}
+
+/// Remove an institution's approval policy, returning HR actions to its admin
+fn remove_policy(env: &Env, institution_id: u64) {
+    remove_approval_policy(env, institution_id);
+
+    env.events().publish(("approval_policy_removed", institution_id), ());
+}
+
+/// Load an employee's rank from the institution's ladder or panic if it is missing
+fn get_employee_rank(env: &Env, employee: &Employee) -> RankConfig {
+    let ladder = read_rank_ladder(env, employee.institution_id);
+    find_rank(&ladder, employee.rank_id).unwrap_or_else(|| {
+        panic!("rank not found");
+    })
+}
+
+/// Promote an employee to the next rank
+fn promote(env: &Env, institution: &Institution, employee_id: u64) {
+    let institution_id = institution.id;
+    let mut employee = read_employee(env, institution_id, employee_id).unwrap_or_else(|| {
+        panic!("employee not found");
+    });
+
+    let ladder = read_rank_ladder(env, institution_id);
+    let current_time = env.ledger().sequence() as u64;
+    let score = average_score(&read_performance_reviews(env, institution_id, employee_id));
+
+    // Check if employee is eligible for promotion
+    if !employee.is_promotion_eligible(&ladder, current_time, institution.min_promotion_interval, score) {
+        panic!("employee not eligible for promotion");
+    }
+
+    let old_rank_id = employee.rank_id;
+    let new_rank = next_rank(&ladder, old_rank_id).unwrap_or_else(|| {
+        panic!("employee is already at the highest rank");
+    });
+
+    employee.rank_id = new_rank.id;
+    employee.last_promotion = Some(current_time);
+
+    write_employee(env, &employee);
+    decrement_rank_employee_count(env, institution_id, old_rank_id);
+    increment_rank_employee_count(env, institution_id, new_rank.id);
+
+    // Emit event
+    let event = EmployeePromotedEvent {
+        institution_id,
+        employee_id,
+        old_rank_id,
+        new_rank_id: new_rank.id,
+        new_salary: employee.current_salary(&new_rank),
+    };
+    env.events().publish(("employee_promoted", institution_id, employee_id), event);
+}
+
+/// Suspend an active employee
+fn suspend(env: &Env, institution_id: u64, employee_id: u64) {
+    let mut employee = read_employee(env, institution_id, employee_id).unwrap_or_else(|| {
+        panic!("employee not found");
+    });
+
+    if employee.status == EmployeeStatus::Suspended {
+        panic!("employee is already suspended");
+    }
+
+    if employee.status == EmployeeStatus::Terminated {
+        panic!("cannot suspend terminated employee");
+    }
+
+    let old_status = employee.status.clone();
+    employee.status = EmployeeStatus::Suspended;
+
+    write_employee(env, &employee);
+
+    // Emit event
+    let event = EmployeeStatusChangedEvent {
+        institution_id,
+        employee_id,
+        old_status,
+        new_status: EmployeeStatus::Suspended,
+    };
+    env.events().publish(("employee_status_changed", institution_id, employee_id), event);
+}
+
+/// Return a suspended employee to active status
+fn reactivate(env: &Env, institution_id: u64, employee_id: u64) {
+    let mut employee = read_employee(env, institution_id, employee_id).unwrap_or_else(|| {
+        panic!("employee not found");
+    });
+
+    if employee.status == EmployeeStatus::Active {
+        panic!("employee is already active");
+    }
+
+    if employee.status == EmployeeStatus::Terminated {
+        panic!("cannot reactivate terminated employee");
+    }
+
+    let old_status = employee.status.clone();
+    employee.status = EmployeeStatus::Active;
+
+    write_employee(env, &employee);
+
+    // Emit event
+    let event = EmployeeStatusChangedEvent {
+        institution_id,
+        employee_id,
+        old_status,
+        new_status: EmployeeStatus::Active,
+    };
+    env.events().publish(("employee_status_changed", institution_id, employee_id), event);
+}
+
+/// Terminate an employee, keeping their record
+fn terminate(env: &Env, institution_id: u64, employee_id: u64) {
+    let mut employee = read_employee(env, institution_id, employee_id).unwrap_or_else(|| {
+        panic!("employee not found");
+    });
+
+    if employee.status == EmployeeStatus::Terminated {
+        panic!("employee is already terminated");
+    }
+
+    let old_status = employee.status.clone();
+    employee.status = EmployeeStatus::Terminated;
+
+    write_employee(env, &employee);
+
+    // Emit event
+    let event = EmployeeStatusChangedEvent {
+        institution_id,
+        employee_id,
+        old_status,
+        new_status: EmployeeStatus::Terminated,
+    };
+    env.events().publish(("employee_status_changed", institution_id, employee_id), event);
+}
+
+/// Pay an employee's salary for the current period, deducting the unpaid
+/// leave taken in that period
+fn pay(env: &Env, institution: &Institution, token_client: &TokenClient, employee: &Employee) {
+    let institution_id = institution.id;
+    let employee_id = employee.id;
+
+    let period = pay_period(env.ledger().sequence() as u64);
+    let unpaid_leave_days = get_unpaid_leave_days(env, institution_id, employee_id, period);
+
+    let rank = get_employee_rank(env, employee);
+    let salary_amount = employee.salary_for_period(&rank, unpaid_leave_days);
+
+    // Transfer tokens from institution admin to employee
+    token_client.transfer(&institution.admin, &employee.address, &salary_amount);
+
+    // The salary covers the whole period, so the final pay starts after it
+    let period_end = (period + 1) * LEDGERS_PER_SALARY_PERIOD;
+    if read_paid_through(env, institution_id, employee_id).map_or(true, |paid| paid < period_end) {
+        write_paid_through(env, institution_id, employee_id, period_end);
+    }
+
+    // Emit event
+    let event = SalaryPaidEvent {
+        institution_id,
+        employee_id,
+        amount: salary_amount,
+        period: env.ledger().sequence() as u64,
+        unpaid_leave_days,
+    };
+    env.events().publish(("salary_paid", institution_id, employee_id), event);
+}
+
+/// Record the final pay owed to a leaving employee for the ledgers between the
+/// end of their last paid period (or their hire date) and `until`, prorated per
+/// pay period with the unpaid leave taken in each period deducted
+///
+/// Nothing is transferred here; the admin settles the amount with
+/// `pay_final_salary`.
+fn record_final_pay(env: &Env, employee: &Employee, until: u64) {
+    let institution_id = employee.institution_id;
+    let employee_id = employee.id;
+
+    let mut from = read_paid_through(env, institution_id, employee_id).unwrap_or(employee.hire_date);
+    if from >= until {
+        return;
+    }
+
+    let salary = employee.current_salary(&get_employee_rank(env, employee));
+    let mut amount = 0i128;
+    let mut unpaid_leave_days = 0u32;
+    while from < until {
+        let period = pay_period(from);
+        let to = ((period + 1) * LEDGERS_PER_SALARY_PERIOD).min(until);
+
+        let days = get_unpaid_leave_days(env, institution_id, employee_id, period);
+        let worked = (to - from).saturating_sub(days as u64 * LEDGERS_PER_DAY);
+        amount += salary * worked as i128 / LEDGERS_PER_SALARY_PERIOD as i128;
+        unpaid_leave_days += days;
+        from = to;
+    }
+    write_paid_through(env, institution_id, employee_id, until);
+
+    write_final_pay(
+        env,
+        &FinalPay {
+            institution_id,
+            employee_id,
+            amount,
+            unpaid_leave_days,
+        },
+    );
+}
+
+/// Panic if unpaid leave starting at `start` falls in a pay period the employee
+/// has already been paid for, as its days could no longer be deducted
+fn require_unpaid_period(env: &Env, institution_id: u64, employee_id: u64, start: u64) {
+    if read_paid_through(env, institution_id, employee_id).is_some_and(|paid| start < paid) {
+        panic!("unpaid leave falls in a paid period");
+    }
+}
+
+/// Record each day of an unpaid leave request against the pay period it falls in
+fn record_unpaid_leave(env: &Env, request: &LeaveRequest) {
+    let mut day = 0u32;
+    while day < request.days {
+        let day_start = request.start + day as u64 * LEDGERS_PER_DAY;
+        let period = pay_period(day_start);
+
+        // Days of the leave starting before this period ends
+        let period_end = (period + 1) * LEDGERS_PER_SALARY_PERIOD;
+        let remaining = request.days - day;
+        let days = ((period_end - day_start).div_ceil(LEDGERS_PER_DAY) as u32).min(remaining);
+
+        add_unpaid_leave_days(env, request.institution_id, request.employee_id, period, days);
+        day += days;
+    }
+}
+
+/// Build an employee's leave balance for a leave year
+fn leave_balance(env: &Env, employee: &Employee, year: u32) -> LeaveBalance {
+    let rank = get_employee_rank(env, employee);
+    let usage = read_leave_usage(env, employee.institution_id, employee.id, year);
+    LeaveBalance {
+        year,
+        annual_entitlement: rank.annual_leave_days,
+        annual_used: usage.annual,
+        sick_entitlement: rank.sick_leave_days,
+        sick_used: usage.sick,
+        unpaid_used: usage.unpaid,
+    }
+}
+
 #[contractimpl]
 impl EmployeeManagementInterface for EmployeeManagement {
-    fn initialize(
+    fn initialize(env: Env, admin: Address, deposit_token: Address, registration_deposit: i128) {
+        if is_initialized(&env) {
+            panic!("contract already initialized");
+        }
+
+        if registration_deposit < 0 {
+            panic!("registration deposit cannot be negative");
+        }
+
+        write_admin(&env, &admin);
+        write_deposit_token(&env, &deposit_token);
+        write_registration_deposit(&env, registration_deposit);
+        set_initialized(&env);
+    }
+
+    fn register_institution(
         env: Env,
         admin: Address,
         institution_name: String,
         token_contract: Address,
         base_salary_amount: i128,
         min_promotion_interval: u64,
-    ) {
-        if is_initialized(&env) {
-            panic!("contract already initialized");
+    ) -> u64 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
         }
 
+        admin.require_auth();
+
         // Validate inputs
-        if institution_name.len() == 0 || institution_name.len() > 64 {
+        if institution_name.is_empty() || institution_name.len() > 64 {
             panic!("institution name must be 1-64 characters");
         }
 
@@ -42,56 +393,85 @@ impl EmployeeManagementInterface for EmployeeManagement {
             panic!("promotion interval must be positive");
         }
 
+        // Collect the registration deposit from the institution admin
+        let deposit = read_registration_deposit(&env);
+        if deposit > 0 {
+            let deposit_token = read_deposit_token(&env).unwrap_or_else(|| {
+                panic!("deposit token not configured");
+            });
+            let deposit_client = TokenClient::new(&env, &deposit_token);
+            deposit_client.transfer(&admin, &env.current_contract_address(), &deposit);
+            write_deposits_held(&env, read_deposits_held(&env) + deposit);
+        }
+
+        let institution_id = get_next_institution_id(&env);
         let institution = Institution {
-            name: institution_name,
+            id: institution_id,
+            name: institution_name.clone(),
             admin: admin.clone(),
             token_contract,
             base_salary_amount,
             min_promotion_interval,
+            deposit,
         };
 
         write_institution(&env, &institution);
-        write_admin(&env, &admin);
-        set_initialized(&env);
+        write_rank_ladder(&env, institution_id, &default_rank_ladder(&env));
+
+        // Emit event
+        let event = InstitutionRegisteredEvent {
+            institution_id,
+            admin,
+            name: institution_name,
+            deposit,
+        };
+        env.events().publish(("institution_registered", institution_id), event);
+
+        institution_id
     }
 
     fn add_employee(
         env: Env,
+        institution_id: u64,
         employee_address: Address,
         name: String,
-        rank: EmployeeRank,
+        rank_id: u32,
         department: String,
     ) -> u64 {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
         // Validate inputs
-        if name.len() == 0 || name.len() > 64 {
+        if name.is_empty() || name.len() > 64 {
             panic!("employee name must be 1-64 characters");
         }
 
-        if department.len() == 0 || department.len() > 32 {
+        if department.is_empty() || department.len() > 32 {
             panic!("department must be 1-32 characters");
         }
 
         // Check if address is already an employee
-        if address_is_employee(&env, &employee_address) {
+        if address_is_employee(&env, institution_id, &employee_address) {
             panic!("address is already an employee");
         }
 
-        let institution = read_institution(&env);
-        let employee_id = get_next_employee_id(&env);
+        let rank = find_rank(&read_rank_ladder(&env, institution_id), rank_id).unwrap_or_else(|| {
+            panic!("rank not found");
+        });
+
+        let employee_id = get_next_employee_id(&env, institution_id);
         let current_time = env.ledger().sequence() as u64;
 
         let employee = Employee {
             id: employee_id,
+            institution_id,
             address: employee_address.clone(),
             name,
-            rank: rank.clone(),
+            rank_id,
             status: EmployeeStatus::Active,
             base_salary: institution.base_salary_amount,
             hire_date: current_time,
@@ -100,40 +480,53 @@ impl EmployeeManagementInterface for EmployeeManagement {
         };
 
         write_employee(&env, &employee);
-        increment_employee_count(&env);
+        increment_employee_count(&env, institution_id);
+        increment_rank_employee_count(&env, institution_id, rank_id);
 
         // Emit event
         let event = EmployeeAddedEvent {
+            institution_id,
             employee_id,
             employee_address,
-            rank,
-            salary: employee.current_salary(),
+            name: employee.name.clone(),
+            rank_id,
+            salary: employee.current_salary(&rank),
+            department: employee.department.clone(),
         };
-        env.events().publish(("employee_added", employee_id), event);
+        env.events().publish(("employee_added", institution_id, employee_id), event);
 
         employee_id
     }
 
-    fn remove_employee(env: Env, employee_id: u64) {
+    fn remove_employee(env: Env, institution_id: u64, employee_id: u64) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        if !employee_exists(&env, employee_id) {
+        require_no_approval_policy(&env, institution_id);
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
             panic!("employee not found");
+        });
+
+        if read_final_pay(&env, institution_id, employee_id).is_some() {
+            panic!("final pay still owed");
         }
 
-        remove_employee(&env, employee_id);
-        decrement_employee_count(&env);
+        remove_employee(&env, institution_id, employee_id);
+        remove_resignation(&env, institution_id, employee_id);
+        decrement_employee_count(&env, institution_id);
+        decrement_rank_employee_count(&env, institution_id, employee.rank_id);
 
-        env.events().publish(("employee_removed", employee_id), ());
+        env.events().publish(("employee_removed", institution_id, employee_id), ());
     }
 
     fn update_employee(
         env: Env,
+        institution_id: u64,
         employee_id: u64,
         name: Option<String>,
         department: Option<String>,
@@ -143,23 +536,23 @@ impl EmployeeManagementInterface for EmployeeManagement {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        let mut employee = read_employee(&env, employee_id).unwrap_or_else(|| {
+        let mut employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
             panic!("employee not found");
         });
 
         // Update fields if provided
         if let Some(new_name) = name {
-            if new_name.len() == 0 || new_name.len() > 64 {
+            if new_name.is_empty() || new_name.len() > 64 {
                 panic!("employee name must be 1-64 characters");
             }
             employee.name = new_name;
         }
 
         if let Some(new_department) = department {
-            if new_department.len() == 0 || new_department.len() > 32 {
+            if new_department.is_empty() || new_department.len() > 32 {
                 panic!("department must be 1-32 characters");
             }
             employee.department = new_department;
@@ -174,126 +567,67 @@ impl EmployeeManagementInterface for EmployeeManagement {
 
         write_employee(&env, &employee);
 
-        env.events().publish(("employee_updated", employee_id), ());
-    }
-
-    fn promote_employee(env: Env, employee_id: u64) {
-        if !is_initialized(&env) {
-            panic!("contract not initialized");
-        }
-
-        let admin = read_admin(&env);
-        admin.require_auth();
-
-        let mut employee = read_employee(&env, employee_id).unwrap_or_else(|| {
-            panic!("employee not found");
-        });
-
-        let institution = read_institution(&env);
-        let current_time = env.ledger().sequence() as u64;
-
-        // Check if employee is eligible for promotion
-        if !employee.is_promotion_eligible(current_time, institution.min_promotion_interval) {
-            panic!("employee not eligible for promotion");
-        }
-
-        let old_rank = employee.rank.clone();
-        let new_rank = employee.rank.next_rank().unwrap_or_else(|| {
-            panic!("employee is already at the highest rank");
-        });
-
-        employee.rank = new_rank.clone();
-        employee.last_promotion = Some(current_time);
-
-        write_employee(&env, &employee);
-
         // Emit event
-        let event = EmployeePromotedEvent {
+        let salary = employee.current_salary(&get_employee_rank(&env, &employee));
+        let event = EmployeeUpdatedEvent {
+            institution_id,
             employee_id,
-            old_rank,
-            new_rank,
-            new_salary: employee.current_salary(),
+            name: employee.name,
+            department: employee.department,
+            base_salary: employee.base_salary,
+            salary,
         };
-        env.events().publish(("employee_promoted", employee_id), event);
+        env.events().publish(("employee_updated", institution_id, employee_id), event);
     }
 
-    fn suspend_employee(env: Env, employee_id: u64) {
+    fn promote_employee(env: Env, institution_id: u64, employee_id: u64) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
-
-        let mut employee = read_employee(&env, employee_id).unwrap_or_else(|| {
-            panic!("employee not found");
-        });
-
-        if employee.status == EmployeeStatus::Suspended {
-            panic!("employee is already suspended");
-        }
-
-        if employee.status == EmployeeStatus::Terminated {
-            panic!("cannot suspend terminated employee");
-        }
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        let old_status = employee.status.clone();
-        employee.status = EmployeeStatus::Suspended;
-
-        write_employee(&env, &employee);
+        require_no_approval_policy(&env, institution_id);
 
-        // Emit event
-        let event = EmployeeStatusChangedEvent {
-            employee_id,
-            old_status,
-            new_status: EmployeeStatus::Suspended,
-        };
-        env.events().publish(("employee_status_changed", employee_id), event);
+        promote(&env, &institution, employee_id);
     }
 
-    fn reactivate_employee(env: Env, employee_id: u64) {
+    fn suspend_employee(env: Env, institution_id: u64, employee_id: u64) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        let mut employee = read_employee(&env, employee_id).unwrap_or_else(|| {
-            panic!("employee not found");
-        });
+        require_no_approval_policy(&env, institution_id);
 
-        if employee.status == EmployeeStatus::Active {
-            panic!("employee is already active");
-        }
+        suspend(&env, institution_id, employee_id);
+    }
 
-        if employee.status == EmployeeStatus::Terminated {
-            panic!("cannot reactivate terminated employee");
+    fn reactivate_employee(env: Env, institution_id: u64, employee_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
         }
 
-        let old_status = employee.status.clone();
-        employee.status = EmployeeStatus::Active;
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        write_employee(&env, &employee);
+        require_no_approval_policy(&env, institution_id);
 
-        // Emit event
-        let event = EmployeeStatusChangedEvent {
-            employee_id,
-            old_status,
-            new_status: EmployeeStatus::Active,
-        };
-        env.events().publish(("employee_status_changed", employee_id), event);
+        reactivate(&env, institution_id, employee_id);
     }
 
-    fn pay_salary(env: Env, employee_id: u64) {
+    fn pay_salary(env: Env, institution_id: u64, employee_id: u64) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        let employee = read_employee(&env, employee_id).unwrap_or_else(|| {
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
             panic!("employee not found");
         });
 
@@ -301,74 +635,62 @@ impl EmployeeManagementInterface for EmployeeManagement {
             panic!("can only pay salary to active employees");
         }
 
-        let institution = read_institution(&env);
         let token_client = TokenClient::new(&env, &institution.token_contract);
-        let salary_amount = employee.current_salary();
-
-        // Transfer tokens from admin to employee
-        token_client.transfer(&admin, &employee.address, &salary_amount);
-
-        // Emit event
-        let event = SalaryPaidEvent {
-            employee_id,
-            amount: salary_amount,
-            period: env.ledger().sequence() as u64,
-        };
-        env.events().publish(("salary_paid", employee_id), event);
+        pay(&env, &institution, &token_client, &employee);
     }
 
-    fn pay_all_salaries(env: Env) {
+    fn pay_all_salaries(env: Env, institution_id: u64) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        let admin = read_admin(&env);
-        admin.require_auth();
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
 
-        let total_employees = get_employee_count(&env);
-        let institution = read_institution(&env);
+        let last_employee_id = get_last_employee_id(&env, institution_id);
         let token_client = TokenClient::new(&env, &institution.token_contract);
 
         // Pay salary to all active employees
-        for employee_id in 1..=total_employees {
-            if let Some(employee) = read_employee(&env, employee_id) {
+        for employee_id in 1..=last_employee_id {
+            if let Some(employee) = read_employee(&env, institution_id, employee_id) {
                 if employee.status == EmployeeStatus::Active {
-                    let salary_amount = employee.current_salary();
-
-                    // Transfer tokens from admin to employee
-                    token_client.transfer(&admin, &employee.address, &salary_amount);
-
-                    // Emit event
-                    let event = SalaryPaidEvent {
-                        employee_id,
-                        amount: salary_amount,
-                        period: env.ledger().sequence() as u64,
-                    };
-                    env.events().publish(("salary_paid", employee_id), event);
+                    pay(&env, &institution, &token_client, &employee);
                 }
             }
         }
     }
 
-    fn get_employee(env: Env, employee_id: u64) -> Employee {
+    fn get_employee(env: Env, institution_id: u64, employee_id: u64) -> Employee {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        read_employee(&env, employee_id).unwrap_or_else(|| {
+        read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
             panic!("employee not found");
         })
     }
 
-    fn get_employee_by_address(env: Env, employee_address: Address) -> Option<u64> {
+    fn get_employee_salary(env: Env, institution_id: u64, employee_id: u64) -> i128 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        employee.current_salary(&get_employee_rank(&env, &employee))
+    }
+
+    fn get_employee_by_address(env: Env, institution_id: u64, employee_address: Address) -> Option<u64> {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        get_employee_id_by_address(&env, &employee_address)
+        get_employee_id_by_address(&env, institution_id, &employee_address)
     }
 
-    fn get_employees(env: Env, start_id: u64, limit: u32) -> Vec<Employee> {
+    fn get_employees(env: Env, institution_id: u64, start_id: u64, limit: u32) -> Vec<Employee> {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
@@ -378,7 +700,7 @@ impl EmployeeManagementInterface for EmployeeManagement {
         let mut current_id = start_id;
 
         while count < limit {
-            if let Some(employee) = read_employee(&env, current_id) {
+            if let Some(employee) = read_employee(&env, institution_id, current_id) {
                 employees.push_back(employee);
                 count += 1;
             }
@@ -393,20 +715,28 @@ impl EmployeeManagementInterface for EmployeeManagement {
         employees
     }
 
-    fn get_institution(env: Env) -> Institution {
+    fn get_institution(env: Env, institution_id: u64) -> Institution {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id)
+    }
+
+    fn get_institution_count(env: Env) -> u64 {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        read_institution(&env)
+        get_institution_count(&env)
     }
 
-    fn get_employee_count(env: Env) -> u64 {
+    fn get_employee_count(env: Env, institution_id: u64) -> u64 {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
 
-        get_employee_count(&env)
+        get_employee_count(&env, institution_id)
     }
 
     fn initialized(env: Env) -> bool {
@@ -426,11 +756,6 @@ impl EmployeeManagementAdminInterface for EmployeeManagement {
 
         write_admin(&env, &new_admin);
 
-        // Update institution admin as well
-        let mut institution = read_institution(&env);
-        institution.admin = new_admin.clone();
-        write_institution(&env, &institution);
-
         env.events().publish(("admin_changed", &admin, &new_admin), ());
     }
 
@@ -442,13 +767,7 @@ impl EmployeeManagementAdminInterface for EmployeeManagement {
         read_admin(&env)
     }
 
-    fn update_institution(
-        env: Env,
-        name: Option<String>,
-        token_contract: Option<Address>,
-        base_salary_amount: Option<i128>,
-        min_promotion_interval: Option<u64>,
-    ) {
+    fn set_registration_deposit(env: Env, amount: i128) {
         if !is_initialized(&env) {
             panic!("contract not initialized");
         }
@@ -456,36 +775,982 @@ impl EmployeeManagementAdminInterface for EmployeeManagement {
         let admin = read_admin(&env);
         admin.require_auth();
 
-        let mut institution = read_institution(&env);
+        if amount < 0 {
+            panic!("registration deposit cannot be negative");
+        }
 
-        // Update fields if provided
-        if let Some(new_name) = name {
-            if new_name.len() == 0 || new_name.len() > 64 {
-                panic!("institution name must be 1-64 characters");
-            }
-            institution.name = new_name;
+        if amount > 0 && read_deposit_token(&env).is_none() {
+            panic!("deposit token not configured");
         }
 
-        if let Some(new_token_contract) = token_contract {
-            institution.token_contract = new_token_contract;
+        write_registration_deposit(&env, amount);
+
+        env.events().publish(("registration_deposit_changed",), amount);
+    }
+
+    fn get_registration_deposit(env: Env) -> i128 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
         }
 
-        if let Some(new_base_salary) = base_salary_amount {
-            if new_base_salary <= 0 {
-                panic!("base salary must be positive");
-            }
-            institution.base_salary_amount = new_base_salary;
+        read_registration_deposit(&env)
+    }
+
+    fn set_deposit_token(env: Env, token: Address) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
         }
 
-        if let Some(new_interval) = min_promotion_interval {
-            if new_interval == 0 {
-                panic!("promotion interval must be positive");
-            }
-            institution.min_promotion_interval = new_interval;
+        let admin = read_admin(&env);
+        admin.require_auth();
+
+        // Deposits already held are refunded in the configured token
+        if read_deposits_held(&env) > 0 {
+            panic!("deposits are held in the current token");
         }
 
-        write_institution(&env, &institution);
+        write_deposit_token(&env, &token);
+
+        env.events().publish(("deposit_token_set",), token);
+    }
+
+    fn get_deposit_token(env: Env) -> Option<Address> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_deposit_token(&env)
+    }
+
+    fn set_institution_admin(env: Env, institution_id: u64, new_admin: Address) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let mut institution = get_institution_or_panic(&env, institution_id);
+        let old_admin = institution.admin.clone();
+        old_admin.require_auth();
+
+        institution.admin = new_admin.clone();
+        write_institution(&env, &institution);
+
+        env.events().publish(
+            ("institution_admin_changed", institution_id),
+            (old_admin, new_admin),
+        );
+    }
+
+    fn update_institution(
+        env: Env,
+        institution_id: u64,
+        name: Option<String>,
+        token_contract: Option<Address>,
+        base_salary_amount: Option<i128>,
+        min_promotion_interval: Option<u64>,
+    ) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let mut institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        // Update fields if provided
+        if let Some(new_name) = name {
+            if new_name.is_empty() || new_name.len() > 64 {
+                panic!("institution name must be 1-64 characters");
+            }
+            institution.name = new_name;
+        }
+
+        if let Some(new_token_contract) = token_contract {
+            institution.token_contract = new_token_contract;
+        }
+
+        if let Some(new_base_salary) = base_salary_amount {
+            if new_base_salary <= 0 {
+                panic!("base salary must be positive");
+            }
+            institution.base_salary_amount = new_base_salary;
+        }
+
+        if let Some(new_interval) = min_promotion_interval {
+            if new_interval == 0 {
+                panic!("promotion interval must be positive");
+            }
+            institution.min_promotion_interval = new_interval;
+        }
+
+        write_institution(&env, &institution);
+
+        env.events().publish(("institution_updated", institution_id), ());
+    }
+
+    fn close_institution(env: Env, institution_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        if get_employee_count(&env, institution_id) > 0 {
+            panic!("institution still has employees");
+        }
+
+        remove_institution(&env, institution_id);
+
+        // Refund the registration deposit to the institution admin
+        if institution.deposit > 0 {
+            let deposit_token = read_deposit_token(&env).unwrap_or_else(|| {
+                panic!("deposit token not configured");
+            });
+            let deposit_client = TokenClient::new(&env, &deposit_token);
+            deposit_client.transfer(
+                &env.current_contract_address(),
+                &institution.admin,
+                &institution.deposit,
+            );
+            write_deposits_held(&env, read_deposits_held(&env) - institution.deposit);
+        }
+
+        env.events().publish(("institution_closed", institution_id), institution.deposit);
+    }
+}
+
+#[contractimpl]
+impl EmployeeManagementApprovalInterface for EmployeeManagement {
+    fn set_approval_policy(
+        env: Env,
+        institution_id: u64,
+        proposers: Vec<Address>,
+        approvers: Vec<Address>,
+        quorum: u32,
+        approval_window: u64,
+    ) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        // Once a policy is active, changing it needs approval too
+        require_no_approval_policy(&env, institution_id);
+
+        let policy = ApprovalPolicy {
+            proposers,
+            approvers,
+            quorum,
+            approval_window,
+        };
+        validate_approval_policy(&policy);
+
+        set_policy(&env, institution_id, &policy);
+    }
+
+    fn get_approval_policy(env: Env, institution_id: u64) -> Option<ApprovalPolicy> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_approval_policy(&env, institution_id)
+    }
+
+    fn propose_action(
+        env: Env,
+        institution_id: u64,
+        proposer: Address,
+        employee_id: u64,
+        action: ProposalAction,
+    ) -> u64 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        proposer.require_auth();
+
+        get_institution_or_panic(&env, institution_id);
+        let policy = read_approval_policy(&env, institution_id).unwrap_or_else(|| {
+            panic!("approval policy not found");
+        });
+
+        if !policy.proposers.contains(&proposer) {
+            panic!("not an authorized proposer");
+        }
+
+        if action.targets_employee() {
+            let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+                panic!("employee not found");
+            });
+
+            if employee.status == EmployeeStatus::Terminated {
+                panic!("employee is terminated");
+            }
+        }
+
+        if let ProposalAction::SetPolicy(new_policy) = &action {
+            validate_approval_policy(new_policy);
+        }
+
+        let proposal_id = get_next_proposal_id(&env, institution_id);
+        let current_time = env.ledger().sequence() as u64;
+        let deadline = current_time + policy.approval_window;
+
+        let proposal = Proposal {
+            id: proposal_id,
+            institution_id,
+            employee_id,
+            action: action.clone(),
+            proposer: proposer.clone(),
+            approvals: Vec::new(&env),
+            quorum: policy.quorum,
+            created_at: current_time,
+            deadline,
+            status: ProposalStatus::Pending,
+        };
+
+        write_proposal(&env, &proposal);
+
+        // Emit event
+        let event = ProposalCreatedEvent {
+            institution_id,
+            proposal_id,
+            employee_id,
+            action,
+            proposer,
+            deadline,
+        };
+        env.events().publish(("proposal_created", institution_id, proposal_id), event);
+
+        proposal_id
+    }
+
+    fn approve_proposal(env: Env, institution_id: u64, approver: Address, proposal_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        approver.require_auth();
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        let policy = read_approval_policy(&env, institution_id).unwrap_or_else(|| {
+            panic!("approval policy not found");
+        });
+
+        if !policy.approvers.contains(&approver) {
+            panic!("not an authorized approver");
+        }
+
+        let mut proposal = read_proposal(&env, institution_id, proposal_id).unwrap_or_else(|| {
+            panic!("proposal not found");
+        });
+
+        if proposal.status != ProposalStatus::Pending {
+            panic!("proposal is not pending");
+        }
+
+        if proposal.is_expired(env.ledger().sequence() as u64) {
+            panic!("proposal expired");
+        }
+
+        if proposal.proposer == approver {
+            panic!("proposer cannot approve own proposal");
+        }
+
+        if proposal.approvals.contains(&approver) {
+            panic!("proposal already approved by this approver");
+        }
+
+        proposal.approvals.push_back(approver.clone());
+
+        // The policy may have changed since the proposal was created, so count
+        // only the approvals of its current approvers against its current quorum
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approval| policy.approvers.contains(approval))
+            .count() as u32;
+        proposal.quorum = policy.quorum;
+
+        // Emit event
+        let event = ProposalApprovedEvent {
+            institution_id,
+            proposal_id,
+            approver,
+            approvals,
+            quorum: proposal.quorum,
+        };
+        env.events().publish(("proposal_approved", institution_id, proposal_id), event);
+
+        if approvals >= proposal.quorum {
+            match &proposal.action {
+                ProposalAction::Promote => promote(&env, &institution, proposal.employee_id),
+                ProposalAction::Suspend => suspend(&env, institution_id, proposal.employee_id),
+                ProposalAction::Terminate => terminate(&env, institution_id, proposal.employee_id),
+                ProposalAction::Reactivate => reactivate(&env, institution_id, proposal.employee_id),
+                ProposalAction::SetPolicy(new_policy) => set_policy(&env, institution_id, new_policy),
+                ProposalAction::RemovePolicy => remove_policy(&env, institution_id),
+            }
+
+            proposal.status = ProposalStatus::Executed;
+
+            // Emit event
+            let event = ProposalExecutedEvent {
+                institution_id,
+                proposal_id,
+                employee_id: proposal.employee_id,
+                action: proposal.action.clone(),
+            };
+            env.events().publish(("proposal_executed", institution_id, proposal_id), event);
+        }
+
+        write_proposal(&env, &proposal);
+    }
+
+    fn cancel_proposal(env: Env, institution_id: u64, proposal_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let mut proposal = read_proposal(&env, institution_id, proposal_id).unwrap_or_else(|| {
+            panic!("proposal not found");
+        });
+
+        proposal.proposer.require_auth();
+
+        if proposal.status != ProposalStatus::Pending {
+            panic!("proposal is not pending");
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        write_proposal(&env, &proposal);
+
+        env.events().publish(("proposal_cancelled", institution_id, proposal_id), ());
+    }
+
+    fn get_proposal(env: Env, institution_id: u64, proposal_id: u64) -> Proposal {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_proposal(&env, institution_id, proposal_id).unwrap_or_else(|| {
+            panic!("proposal not found");
+        })
+    }
+}
+
+#[contractimpl]
+impl EmployeeManagementLeaveInterface for EmployeeManagement {
+    fn request_leave(
+        env: Env,
+        institution_id: u64,
+        employee_id: u64,
+        leave_type: LeaveType,
+        start: u64,
+        days: u32,
+    ) -> u64 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id);
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        employee.address.require_auth();
+
+        if employee.status != EmployeeStatus::Active {
+            panic!("only active employees can request leave");
+        }
+
+        if days == 0 {
+            panic!("leave must be at least one day");
+        }
+
+        let current_time = env.ledger().sequence() as u64;
+        if start < current_time {
+            panic!("leave cannot start in the past");
+        }
+
+        if leave_type == LeaveType::Unpaid {
+            require_unpaid_period(&env, institution_id, employee_id, start);
+        }
+
+        // Check the entitlement up front so obviously invalid requests fail fast
+        let year = leave_year(start);
+        let balance = leave_balance(&env, &employee, year);
+        if let Some(remaining) = balance.remaining(&leave_type) {
+            if days > remaining {
+                panic!("insufficient leave balance");
+            }
+        }
+
+        // Drop rejected and finished requests, then check the rest for overlap
+        let end = start + days as u64 * LEDGERS_PER_DAY;
+        let mut open_requests = Vec::new(&env);
+        for open_id in read_open_leave_requests(&env, institution_id, employee_id).iter() {
+            let Some(open) = read_leave_request(&env, institution_id, open_id) else {
+                continue;
+            };
+            if open.status == LeaveStatus::Rejected || open.end() <= current_time {
+                continue;
+            }
+            if open.overlaps(start, end) {
+                panic!("leave overlaps another request");
+            }
+            open_requests.push_back(open_id);
+        }
+
+        let request_id = get_next_leave_request_id(&env, institution_id);
+        open_requests.push_back(request_id);
+        write_open_leave_requests(&env, institution_id, employee_id, &open_requests);
+
+        let request = LeaveRequest {
+            id: request_id,
+            institution_id,
+            employee_id,
+            leave_type: leave_type.clone(),
+            start,
+            days,
+            year,
+            status: LeaveStatus::Pending,
+            requested_at: current_time,
+        };
+
+        write_leave_request(&env, &request);
+
+        // Emit event
+        let event = LeaveRequestedEvent {
+            institution_id,
+            request_id,
+            employee_id,
+            leave_type,
+            start,
+            days,
+        };
+        env.events().publish(("leave_requested", institution_id, request_id), event);
+
+        request_id
+    }
+
+    fn approve_leave(env: Env, institution_id: u64, request_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        let mut request = read_leave_request(&env, institution_id, request_id).unwrap_or_else(|| {
+            panic!("leave request not found");
+        });
+
+        if request.status != LeaveStatus::Pending {
+            panic!("leave request is not pending");
+        }
+
+        let employee = read_employee(&env, institution_id, request.employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        // Re-check the balance, other requests may have been approved since
+        let balance = leave_balance(&env, &employee, request.year);
+        if let Some(remaining) = balance.remaining(&request.leave_type) {
+            if request.days > remaining {
+                panic!("insufficient leave balance");
+            }
+        }
+
+        let mut usage = read_leave_usage(&env, institution_id, employee.id, request.year);
+        match request.leave_type {
+            LeaveType::Annual => usage.annual += request.days,
+            LeaveType::Sick => usage.sick += request.days,
+            LeaveType::Unpaid => {
+                // The period may have been paid since the leave was requested
+                require_unpaid_period(&env, institution_id, employee.id, request.start);
+                usage.unpaid += request.days;
+                record_unpaid_leave(&env, &request);
+            }
+        }
+        write_leave_usage(&env, institution_id, employee.id, request.year, &usage);
+
+        request.status = LeaveStatus::Approved;
+        write_leave_request(&env, &request);
+
+        // Emit event
+        let event = LeaveDecidedEvent {
+            institution_id,
+            request_id,
+            employee_id: request.employee_id,
+            status: LeaveStatus::Approved,
+        };
+        env.events().publish(("leave_approved", institution_id, request_id), event);
+    }
+
+    fn reject_leave(env: Env, institution_id: u64, request_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        let mut request = read_leave_request(&env, institution_id, request_id).unwrap_or_else(|| {
+            panic!("leave request not found");
+        });
+
+        if request.status != LeaveStatus::Pending {
+            panic!("leave request is not pending");
+        }
+
+        request.status = LeaveStatus::Rejected;
+        write_leave_request(&env, &request);
+
+        // Emit event
+        let event = LeaveDecidedEvent {
+            institution_id,
+            request_id,
+            employee_id: request.employee_id,
+            status: LeaveStatus::Rejected,
+        };
+        env.events().publish(("leave_rejected", institution_id, request_id), event);
+    }
+
+    fn get_leave_request(env: Env, institution_id: u64, request_id: u64) -> LeaveRequest {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_leave_request(&env, institution_id, request_id).unwrap_or_else(|| {
+            panic!("leave request not found");
+        })
+    }
+
+    fn get_leave_balance(env: Env, institution_id: u64, employee_id: u64, year: u32) -> LeaveBalance {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        leave_balance(&env, &employee, year)
+    }
+
+    fn get_unpaid_leave_days(env: Env, institution_id: u64, employee_id: u64, period: u64) -> u32 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_unpaid_leave_days(&env, institution_id, employee_id, period)
+    }
+}
+
+#[contractimpl]
+impl EmployeeManagementPerformanceInterface for EmployeeManagement {
+    fn set_reviewers(env: Env, institution_id: u64, reviewers: Vec<Address>) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        write_reviewers(&env, institution_id, &reviewers);
+
+        env.events().publish(("reviewers_set", institution_id), reviewers);
+    }
+
+    fn get_reviewers(env: Env, institution_id: u64) -> Vec<Address> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_reviewers(&env, institution_id)
+    }
+
+    fn submit_performance_score(
+        env: Env,
+        institution_id: u64,
+        reviewer: Address,
+        employee_id: u64,
+        period: u64,
+        score: u32,
+    ) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        reviewer.require_auth();
+
+        get_institution_or_panic(&env, institution_id);
+        if !read_reviewers(&env, institution_id).contains(&reviewer) {
+            panic!("not an authorized reviewer");
+        }
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        if employee.address == reviewer {
+            panic!("reviewer cannot score themselves");
+        }
+
+        if employee.status == EmployeeStatus::Terminated {
+            panic!("employee is terminated");
+        }
+
+        if score > MAX_PERFORMANCE_SCORE {
+            panic!("score must be between 0 and 100");
+        }
+
+        let mut reviews = read_performance_reviews(&env, institution_id, employee_id);
+        if let Some(last) = reviews.last() {
+            if period <= last.period {
+                panic!("review period must be after the last review");
+            }
+        }
+
+        reviews.push_back(PerformanceReview {
+            period,
+            score,
+            reviewer: reviewer.clone(),
+            submitted_at: env.ledger().sequence() as u64,
+        });
+
+        // Only the rolling window is kept
+        if reviews.len() > PERFORMANCE_WINDOW {
+            reviews.pop_front();
+        }
+
+        write_performance_reviews(&env, institution_id, employee_id, &reviews);
+
+        // Emit event
+        let event = PerformanceScoredEvent {
+            institution_id,
+            employee_id,
+            reviewer,
+            period,
+            score,
+            average_score: average_score(&reviews),
+        };
+        env.events().publish(("performance_scored", institution_id, employee_id), event);
+    }
+
+    fn get_performance_reviews(env: Env, institution_id: u64, employee_id: u64) -> Vec<PerformanceReview> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_performance_reviews(&env, institution_id, employee_id)
+    }
+
+    fn get_average_score(env: Env, institution_id: u64, employee_id: u64) -> Option<u32> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        average_score(&read_performance_reviews(&env, institution_id, employee_id))
+    }
+
+    fn promotion_candidates(env: Env, institution_id: u64, start_id: u64, limit: u32) -> Vec<Employee> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        if limit > MAX_CANDIDATE_SCAN {
+            panic!("limit exceeds the maximum scan size");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        let ladder = read_rank_ladder(&env, institution_id);
+        let current_time = env.ledger().sequence() as u64;
+        let end_id = start_id
+            .saturating_add(limit as u64)
+            .min(get_last_employee_id(&env, institution_id) + 1);
+
+        let mut candidates = Vec::new(&env);
+        for employee_id in start_id.max(1)..end_id {
+            if let Some(employee) = read_employee(&env, institution_id, employee_id) {
+                let score = average_score(&read_performance_reviews(&env, institution_id, employee_id));
+                let interval = institution.min_promotion_interval;
+                if employee.is_promotion_eligible(&ladder, current_time, interval, score) {
+                    candidates.push_back(employee);
+                }
+            }
+        }
+
+        candidates
+    }
+}
+
+#[contractimpl]
+impl EmployeeManagementRankInterface for EmployeeManagement {
+    fn set_rank_ladder(env: Env, institution_id: u64, ranks: Vec<RankConfig>) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        // Validate inputs
+        if ranks.is_empty() {
+            panic!("rank ladder cannot be empty");
+        }
+
+        for (index, rank) in ranks.iter().enumerate() {
+            if rank.name.is_empty() || rank.name.len() > 32 {
+                panic!("rank name must be 1-32 characters");
+            }
+
+            if rank.salary_multiplier == 0 {
+                panic!("salary multiplier must be positive");
+            }
+
+            if rank.promotion_score_threshold > MAX_PERFORMANCE_SCORE {
+                panic!("promotion score threshold must be between 0 and 100");
+            }
+
+            if ranks.iter().skip(index + 1).any(|other| other.id == rank.id) {
+                panic!("duplicate rank id");
+            }
+        }
+
+        // Every rank in use must survive the change
+        for rank in read_rank_ladder(&env, institution_id).iter() {
+            if find_rank(&ranks, rank.id).is_none()
+                && get_rank_employee_count(&env, institution_id, rank.id) > 0
+            {
+                panic!("rank in use missing from ladder");
+            }
+        }
+
+        write_rank_ladder(&env, institution_id, &ranks);
+
+        env.events().publish(("rank_ladder_set", institution_id), ranks);
+    }
+
+    fn get_rank_ladder(env: Env, institution_id: u64) -> Vec<RankConfig> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id);
+        read_rank_ladder(&env, institution_id)
+    }
+}
+
+#[contractimpl]
+impl EmployeeManagementSelfServiceInterface for EmployeeManagement {
+    fn update_my_profile(env: Env, institution_id: u64, employee_address: Address, name: String) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id);
+        employee_address.require_auth();
+
+        let mut employee = get_employee_by_address_or_panic(&env, institution_id, &employee_address);
+
+        if employee.status == EmployeeStatus::Terminated {
+            panic!("employee is terminated");
+        }
+
+        if name.is_empty() || name.len() > 64 {
+            panic!("employee name must be 1-64 characters");
+        }
+        employee.name = name;
+
+        write_employee(&env, &employee);
+
+        // Emit event
+        let salary = employee.current_salary(&get_employee_rank(&env, &employee));
+        let event = EmployeeUpdatedEvent {
+            institution_id,
+            employee_id: employee.id,
+            name: employee.name,
+            department: employee.department,
+            base_salary: employee.base_salary,
+            salary,
+        };
+        env.events().publish(("employee_updated", institution_id, event.employee_id), event);
+    }
+
+    fn rotate_address(env: Env, institution_id: u64, old_address: Address, new_address: Address) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id);
+        old_address.require_auth();
+        new_address.require_auth();
+
+        let mut employee = get_employee_by_address_or_panic(&env, institution_id, &old_address);
+
+        if employee.status == EmployeeStatus::Terminated {
+            panic!("employee is terminated");
+        }
+
+        if address_is_employee(&env, institution_id, &new_address) {
+            panic!("address is already an employee");
+        }
+
+        employee.address = new_address.clone();
+        remove_employee_address(&env, institution_id, &old_address);
+        write_employee(&env, &employee);
+
+        // Emit event
+        let event = EmployeeAddressRotatedEvent {
+            institution_id,
+            employee_id: employee.id,
+            old_address,
+            new_address,
+        };
+        env.events().publish(("employee_address_rotated", institution_id, employee.id), event);
+    }
+
+    fn resign(env: Env, institution_id: u64, employee_address: Address) -> u64 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        get_institution_or_panic(&env, institution_id);
+        employee_address.require_auth();
+
+        let employee = get_employee_by_address_or_panic(&env, institution_id, &employee_address);
+
+        if employee.status == EmployeeStatus::Terminated {
+            panic!("employee is terminated");
+        }
+
+        if read_resignation(&env, institution_id, employee.id).is_some() {
+            panic!("resignation already submitted");
+        }
+
+        let current_time = env.ledger().sequence() as u64;
+        let resignation = Resignation {
+            institution_id,
+            employee_id: employee.id,
+            submitted_at: current_time,
+            effective_at: current_time + read_notice_period(&env, institution_id),
+        };
+        write_resignation(&env, &resignation);
+
+        // Emit event
+        let event = ResignationSubmittedEvent {
+            institution_id,
+            employee_id: employee.id,
+            effective_at: resignation.effective_at,
+        };
+        env.events().publish(("resignation_submitted", institution_id, employee.id), event);
+
+        resignation.effective_at
+    }
+
+    fn finalize_resignation(env: Env, institution_id: u64, caller: Address, employee_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        caller.require_auth();
+
+        let resignation = read_resignation(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("no resignation submitted");
+        });
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        if caller != employee.address && caller != institution.admin {
+            panic!("caller must be the employee or the institution admin");
+        }
+
+        if (env.ledger().sequence() as u64) < resignation.effective_at {
+            panic!("notice period has not ended");
+        }
+
+        // Record the final salary up to the resignation date before the employee leaves
+        if employee.status == EmployeeStatus::Active {
+            record_final_pay(&env, &employee, resignation.effective_at);
+        }
+
+        remove_resignation(&env, institution_id, employee_id);
+        terminate(&env, institution_id, employee_id);
+    }
+
+    fn get_resignation(env: Env, institution_id: u64, employee_id: u64) -> Option<Resignation> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_resignation(&env, institution_id, employee_id)
+    }
+
+    fn pay_final_salary(env: Env, institution_id: u64, employee_id: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        let final_pay = read_final_pay(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("no final pay owed");
+        });
+
+        let employee = read_employee(&env, institution_id, employee_id).unwrap_or_else(|| {
+            panic!("employee not found");
+        });
+
+        remove_final_pay(&env, institution_id, employee_id);
+
+        let token_client = TokenClient::new(&env, &institution.token_contract);
+        token_client.transfer(&institution.admin, &employee.address, &final_pay.amount);
+
+        // Emit event
+        let event = SalaryPaidEvent {
+            institution_id,
+            employee_id,
+            amount: final_pay.amount,
+            period: env.ledger().sequence() as u64,
+            unpaid_leave_days: final_pay.unpaid_leave_days,
+        };
+        env.events().publish(("salary_paid", institution_id, employee_id), event);
+    }
+
+    fn get_final_pay(env: Env, institution_id: u64, employee_id: u64) -> Option<FinalPay> {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        read_final_pay(&env, institution_id, employee_id)
+    }
+
+    fn set_notice_period(env: Env, institution_id: u64, ledgers: u64) {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
+
+        let institution = get_institution_or_panic(&env, institution_id);
+        institution.admin.require_auth();
+
+        write_notice_period(&env, institution_id, ledgers);
+
+        env.events().publish(("notice_period_set", institution_id), ledgers);
+    }
+
+    fn get_notice_period(env: Env, institution_id: u64) -> u64 {
+        if !is_initialized(&env) {
+            panic!("contract not initialized");
+        }
 
-        env.events().publish(("institution_updated",), ());
+        get_institution_or_panic(&env, institution_id);
+        read_notice_period(&env, institution_id)
     }
 }
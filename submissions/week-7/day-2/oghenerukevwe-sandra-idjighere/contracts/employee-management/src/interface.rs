@@ -1,12 +1,28 @@
 use soroban_sdk::{Address, Env, String, Vec};
-use crate::types::{Employee, EmployeeRank, Institution};
+use crate::types::{
+    ApprovalPolicy, Employee, FinalPay, Institution, LeaveBalance, LeaveRequest, LeaveType,
+    PerformanceReview, Proposal, ProposalAction, RankConfig, Resignation,
+};
 
 /// Employee Management Interface
 /// 
-/// This trait defines the interface for managing employees in an institution
-/// with integration to SEP-41 token for salary payments.
+/// This trait defines the interface for managing employees across the institutions
+/// hosted by the contract, with integration to SEP-41 tokens for salary payments.
 pub trait EmployeeManagementInterface {
-    /// Initialize the employee management system
+    /// Initialize the employee management platform
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The platform admin address
+    /// * `deposit_token` - Address of the SEP-41 token used for registration deposits
+    /// * `registration_deposit` - Deposit required to register an institution (may be zero)
+    fn initialize(env: Env, admin: Address, deposit_token: Address, registration_deposit: i128);
+
+    /// Register a new institution
+    ///
+    /// Open to anyone: `admin` must authorize the call and funds the current
+    /// registration deposit, which is held by the contract until the
+    /// institution is closed.
     ///
     /// # Arguments
     ///
@@ -15,22 +31,32 @@ pub trait EmployeeManagementInterface {
     /// * `token_contract` - Address of the SEP-41 token contract for salary payments
     /// * `base_salary_amount` - Base salary amount (will be multiplied by rank multiplier)
     /// * `min_promotion_interval` - Minimum ledger sequences between promotions
-    fn initialize(
+    ///
+    /// # Returns
+    ///
+    /// Institution ID of the newly registered institution
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["institution_registered", institution_id: u64]`,
+    /// data = `InstitutionRegisteredEvent`
+    fn register_institution(
         env: Env,
         admin: Address,
         institution_name: String,
         token_contract: Address,
         base_salary_amount: i128,
         min_promotion_interval: u64,
-    );
+    ) -> u64;
 
     /// Add a new employee to the institution
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_address` - Address of the new employee
     /// * `name` - Employee's name
-    /// * `rank` - Initial rank of the employee
+    /// * `rank_id` - Initial rank of the employee, from the institution's rank ladder
     /// * `department` - Department the employee belongs to
     ///
     /// # Returns
@@ -39,37 +65,49 @@ pub trait EmployeeManagementInterface {
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["employee_added", employee_id: u64]`,
+    /// Emits an event with topics `["employee_added", institution_id: u64, employee_id: u64]`,
     /// data = `EmployeeAddedEvent`
     fn add_employee(
         env: Env,
+        institution_id: u64,
         employee_address: Address,
         name: String,
-        rank: EmployeeRank,
+        rank_id: u32,
         department: String,
     ) -> u64;
 
     /// Remove an employee from the institution
     ///
+    /// Not available once the institution has an approval policy; terminate
+    /// through `propose_action` instead.
+    ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to remove
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["employee_removed", employee_id: u64]`
-    fn remove_employee(env: Env, employee_id: u64);
+    /// Emits an event with topics `["employee_removed", institution_id: u64, employee_id: u64]`
+    fn remove_employee(env: Env, institution_id: u64, employee_id: u64);
 
     /// Update employee information
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to update
     /// * `name` - New name (optional)
     /// * `department` - New department (optional)
     /// * `base_salary` - New base salary (optional)
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["employee_updated", institution_id: u64, employee_id: u64]`,
+    /// data = `EmployeeUpdatedEvent`
     fn update_employee(
         env: Env,
+        institution_id: u64,
         employee_id: u64,
         name: Option<String>,
         department: Option<String>,
@@ -78,106 +116,156 @@ pub trait EmployeeManagementInterface {
 
     /// Promote an employee to the next rank
     ///
+    /// The employee must have served the minimum promotion interval and have a
+    /// rolling average performance score at or above their rank's threshold.
+    ///
+    /// Not available once the institution has an approval policy; use
+    /// `propose_action` instead.
+    ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to promote
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["employee_promoted", employee_id: u64]`,
+    /// Emits an event with topics `["employee_promoted", institution_id: u64, employee_id: u64]`,
     /// data = `EmployeePromotedEvent`
-    fn promote_employee(env: Env, employee_id: u64);
+    fn promote_employee(env: Env, institution_id: u64, employee_id: u64);
 
     /// Suspend an employee
     ///
+    /// Not available once the institution has an approval policy; use
+    /// `propose_action` instead.
+    ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to suspend
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["employee_status_changed", employee_id: u64]`,
+    /// Emits an event with topics `["employee_status_changed", institution_id: u64, employee_id: u64]`,
     /// data = `EmployeeStatusChangedEvent`
-    fn suspend_employee(env: Env, employee_id: u64);
+    fn suspend_employee(env: Env, institution_id: u64, employee_id: u64);
 
     /// Reactivate a suspended employee
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to reactivate
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["employee_status_changed", employee_id: u64]`,
+    /// Emits an event with topics `["employee_status_changed", institution_id: u64, employee_id: u64]`,
     /// data = `EmployeeStatusChangedEvent`
-    fn reactivate_employee(env: Env, employee_id: u64);
+    fn reactivate_employee(env: Env, institution_id: u64, employee_id: u64);
 
     /// Pay salary to an employee using the SEP-41 token
     ///
+    /// Approved unpaid leave days falling in the current pay period are
+    /// deducted, prorated over the period's 30 days.
+    ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee to pay
     ///
     /// # Events
     ///
-    /// Emits an event with topics `["salary_paid", employee_id: u64]`,
+    /// Emits an event with topics `["salary_paid", institution_id: u64, employee_id: u64]`,
     /// data = `SalaryPaidEvent`
-    fn pay_salary(env: Env, employee_id: u64);
+    fn pay_salary(env: Env, institution_id: u64, employee_id: u64);
 
-    /// Pay salary to all active employees
+    /// Pay salary to all active employees of an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
     ///
     /// # Events
     ///
     /// Emits multiple `salary_paid` events for each employee
-    fn pay_all_salaries(env: Env);
+    fn pay_all_salaries(env: Env, institution_id: u64);
 
     /// Get employee information by ID
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_id` - ID of the employee
     ///
     /// # Returns
     ///
     /// Employee information
-    fn get_employee(env: Env, employee_id: u64) -> Employee;
+    fn get_employee(env: Env, institution_id: u64, employee_id: u64) -> Employee;
+
+    /// Get the current salary of an employee
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    ///
+    /// # Returns
+    ///
+    /// Base salary multiplied by the multiplier of the employee's rank
+    fn get_employee_salary(env: Env, institution_id: u64, employee_id: u64) -> i128;
 
     /// Get employee ID by address
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `employee_address` - Address of the employee
     ///
     /// # Returns
     ///
     /// Employee ID if found
-    fn get_employee_by_address(env: Env, employee_address: Address) -> Option<u64>;
+    fn get_employee_by_address(env: Env, institution_id: u64, employee_address: Address) -> Option<u64>;
 
     /// Get all employees (paginated)
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `start_id` - Starting employee ID for pagination
     /// * `limit` - Maximum number of employees to return
     ///
     /// # Returns
     ///
     /// Vector of employees
-    fn get_employees(env: Env, start_id: u64, limit: u32) -> Vec<Employee>;
+    fn get_employees(env: Env, institution_id: u64, start_id: u64, limit: u32) -> Vec<Employee>;
 
     /// Get institution information
     ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
     /// # Returns
     ///
     /// Institution information
-    fn get_institution(env: Env) -> Institution;
+    fn get_institution(env: Env, institution_id: u64) -> Institution;
 
-    /// Get total number of employees
+    /// Get number of institutions registered so far
+    ///
+    /// # Returns
+    ///
+    /// Total institution count, including closed institutions
+    fn get_institution_count(env: Env) -> u64;
+
+    /// Get total number of employees in an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
     ///
     /// # Returns
     ///
     /// Total employee count
-    fn get_employee_count(env: Env) -> u64;
+    fn get_employee_count(env: Env, institution_id: u64) -> u64;
 
     /// Check if the contract is initialized
     ///
@@ -189,33 +277,590 @@ pub trait EmployeeManagementInterface {
 
 /// Administrative interface for the employee management system
 pub trait EmployeeManagementAdminInterface {
-    /// Set a new admin for the system
+    /// Set a new platform admin
     ///
     /// # Arguments
     ///
-    /// * `new_admin` - Address of the new admin
+    /// * `new_admin` - Address of the new platform admin
     fn set_admin(env: Env, new_admin: Address);
 
-    /// Get the current admin address
+    /// Get the current platform admin address
     ///
     /// # Returns
     ///
-    /// Current admin address
+    /// Current platform admin address
     fn get_admin(env: Env) -> Address;
 
+    /// Set the deposit required to register an institution
+    ///
+    /// Only affects institutions registered afterwards. A non-zero deposit
+    /// requires a configured deposit token.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - New registration deposit (may be zero)
+    fn set_registration_deposit(env: Env, amount: i128);
+
+    /// Get the deposit currently required to register an institution
+    ///
+    /// # Returns
+    ///
+    /// Registration deposit amount
+    fn get_registration_deposit(env: Env) -> i128;
+
+    /// Set the token registration deposits are paid in
+    ///
+    /// Only possible while no registration deposits are held, since those
+    /// are refunded in the token they were paid in.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Address of the SEP-41 token used for registration deposits
+    fn set_deposit_token(env: Env, token: Address);
+
+    /// Get the token registration deposits are paid in
+    ///
+    /// # Returns
+    ///
+    /// Deposit token address, or `None` if not configured
+    fn get_deposit_token(env: Env) -> Option<Address>;
+
+    /// Set a new admin for an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `new_admin` - Address of the new institution admin
+    fn set_institution_admin(env: Env, institution_id: u64, new_admin: Address);
+
     /// Update institution information
     ///
     /// # Arguments
     ///
+    /// * `institution_id` - ID of the institution
     /// * `name` - New institution name (optional)
     /// * `token_contract` - New token contract address (optional)
     /// * `base_salary_amount` - New base salary amount (optional)
     /// * `min_promotion_interval` - New minimum promotion interval (optional)
     fn update_institution(
         env: Env,
+        institution_id: u64,
         name: Option<String>,
         token_contract: Option<Address>,
         base_salary_amount: Option<i128>,
         min_promotion_interval: Option<u64>,
     );
+
+    /// Close an institution and refund its registration deposit to the
+    /// institution admin
+    ///
+    /// The institution must have no remaining employees.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["institution_closed", institution_id: u64]`,
+    /// data = refunded deposit `i128`
+    fn close_institution(env: Env, institution_id: u64);
+}
+
+/// Multi-party approval interface for HR actions
+///
+/// Once an institution sets an approval policy, promotions, suspensions,
+/// reactivations and terminations go through proposals: a proposer opens one,
+/// and it executes automatically when the quorum of approvers has signed off
+/// before the deadline. Replacing or removing the policy itself is proposed the
+/// same way.
+pub trait EmployeeManagementApprovalInterface {
+    /// Set the first approval policy for an institution
+    ///
+    /// Only possible while no policy is active; afterwards the policy changes
+    /// through `ProposalAction::SetPolicy` and `ProposalAction::RemovePolicy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `proposers` - Addresses allowed to propose actions (e.g. managers)
+    /// * `approvers` - Addresses allowed to approve actions (e.g. HR, department
+    ///   leads); must not contain duplicates
+    /// * `quorum` - Number of approvals required to execute a proposal; proposers
+    ///   can't approve their own proposals, so it must not exceed the number of
+    ///   approvers other than any one proposer
+    /// * `approval_window` - Ledger sequences a proposal stays open for approval
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["approval_policy_set", institution_id: u64]`,
+    /// data = `ApprovalPolicy`
+    fn set_approval_policy(
+        env: Env,
+        institution_id: u64,
+        proposers: Vec<Address>,
+        approvers: Vec<Address>,
+        quorum: u32,
+        approval_window: u64,
+    );
+
+    /// Get the approval policy of an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
+    /// # Returns
+    ///
+    /// Approval policy if one is set
+    fn get_approval_policy(env: Env, institution_id: u64) -> Option<ApprovalPolicy>;
+
+    /// Propose an HR action against an employee, or a change of the policy
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `proposer` - Address of the proposer (must be in the policy's proposers)
+    /// * `employee_id` - ID of the employee the action applies to (ignored for
+    ///   policy changes)
+    /// * `action` - Promotion, suspension, reactivation, termination or policy change
+    ///
+    /// # Returns
+    ///
+    /// Proposal ID of the newly created proposal
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["proposal_created", institution_id: u64, proposal_id: u64]`,
+    /// data = `ProposalCreatedEvent`
+    fn propose_action(
+        env: Env,
+        institution_id: u64,
+        proposer: Address,
+        employee_id: u64,
+        action: ProposalAction,
+    ) -> u64;
+
+    /// Approve a pending proposal, executing it once quorum is reached
+    ///
+    /// Approvals are counted against the current policy: those of addresses
+    /// that are no longer approvers don't count, and the quorum is the current
+    /// one rather than the one when the proposal was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `approver` - Address of the approver (must be in the policy's approvers)
+    /// * `proposal_id` - ID of the proposal
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["proposal_approved", institution_id: u64, proposal_id: u64]`,
+    /// data = `ProposalApprovedEvent`. At quorum, also emits the event of the
+    /// executed action and `["proposal_executed", institution_id: u64, proposal_id: u64]`,
+    /// data = `ProposalExecutedEvent`
+    fn approve_proposal(env: Env, institution_id: u64, approver: Address, proposal_id: u64);
+
+    /// Cancel a pending proposal
+    ///
+    /// Only the original proposer can cancel.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `proposal_id` - ID of the proposal
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["proposal_cancelled", institution_id: u64, proposal_id: u64]`
+    fn cancel_proposal(env: Env, institution_id: u64, proposal_id: u64);
+
+    /// Get a proposal by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `proposal_id` - ID of the proposal
+    ///
+    /// # Returns
+    ///
+    /// Proposal information
+    fn get_proposal(env: Env, institution_id: u64, proposal_id: u64) -> Proposal;
+}
+
+/// Leave and attendance interface
+///
+/// Employees request annual, sick or unpaid leave; the institution admin
+/// approves or rejects it. Annual and sick leave are capped by yearly
+/// entitlements derived from the employee's rank, and approved unpaid leave is
+/// deducted from the salary of the pay period each day falls in.
+pub trait EmployeeManagementLeaveInterface {
+    /// Request leave
+    ///
+    /// The leave must not overlap another pending or approved request of the
+    /// employee, and unpaid leave can't start in a pay period the employee has
+    /// already been paid for.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the requesting employee (must authorize)
+    /// * `leave_type` - Annual, sick or unpaid leave
+    /// * `start` - Ledger sequence the leave starts at; its leave year is charged
+    /// * `days` - Number of leave days
+    ///
+    /// # Returns
+    ///
+    /// Leave request ID
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["leave_requested", institution_id: u64, request_id: u64]`,
+    /// data = `LeaveRequestedEvent`
+    fn request_leave(
+        env: Env,
+        institution_id: u64,
+        employee_id: u64,
+        leave_type: LeaveType,
+        start: u64,
+        days: u32,
+    ) -> u64;
+
+    /// Approve a pending leave request
+    ///
+    /// Unpaid leave is rejected if its first pay period has been paid since it
+    /// was requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `request_id` - ID of the leave request
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["leave_approved", institution_id: u64, request_id: u64]`,
+    /// data = `LeaveDecidedEvent`
+    fn approve_leave(env: Env, institution_id: u64, request_id: u64);
+
+    /// Reject a pending leave request
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `request_id` - ID of the leave request
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["leave_rejected", institution_id: u64, request_id: u64]`,
+    /// data = `LeaveDecidedEvent`
+    fn reject_leave(env: Env, institution_id: u64, request_id: u64);
+
+    /// Get a leave request by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `request_id` - ID of the leave request
+    ///
+    /// # Returns
+    ///
+    /// Leave request information
+    fn get_leave_request(env: Env, institution_id: u64, request_id: u64) -> LeaveRequest;
+
+    /// Get an employee's leave balance for a leave year
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    /// * `year` - Leave year (ledger sequence / `LEDGERS_PER_YEAR`)
+    ///
+    /// # Returns
+    ///
+    /// Entitlements for the employee's current rank and approved days used
+    fn get_leave_balance(env: Env, institution_id: u64, employee_id: u64, year: u32) -> LeaveBalance;
+
+    /// Get the approved unpaid leave days falling in a pay period
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    /// * `period` - Pay period, the ledger sequence divided by `LEDGERS_PER_SALARY_PERIOD`
+    ///
+    /// # Returns
+    ///
+    /// Unpaid leave days deducted from salary paid during the period
+    fn get_unpaid_leave_days(env: Env, institution_id: u64, employee_id: u64, period: u64) -> u32;
+}
+
+/// Performance review interface
+///
+/// Reviewers appointed by the institution admin submit periodic scores for
+/// employees. Promotion eligibility requires the rolling average of the most
+/// recent scores to reach the threshold of the employee's current rank.
+pub trait EmployeeManagementPerformanceInterface {
+    /// Set the addresses allowed to submit performance scores
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `reviewers` - Reviewer addresses (e.g. managers)
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["reviewers_set", institution_id: u64]`,
+    /// data = `Vec<Address>`
+    fn set_reviewers(env: Env, institution_id: u64, reviewers: Vec<Address>);
+
+    /// Get the addresses allowed to submit performance scores
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
+    /// # Returns
+    ///
+    /// Reviewer addresses
+    fn get_reviewers(env: Env, institution_id: u64) -> Vec<Address>;
+
+    /// Submit a performance score for an employee
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `reviewer` - Address of the reviewer (must authorize)
+    /// * `employee_id` - ID of the reviewed employee
+    /// * `period` - Review period, strictly increasing per employee
+    /// * `score` - Score from 0 to 100
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["performance_scored", institution_id: u64, employee_id: u64]`,
+    /// data = `PerformanceScoredEvent`
+    fn submit_performance_score(
+        env: Env,
+        institution_id: u64,
+        reviewer: Address,
+        employee_id: u64,
+        period: u64,
+        score: u32,
+    );
+
+    /// Get the most recent performance reviews of an employee
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    ///
+    /// # Returns
+    ///
+    /// Up to `PERFORMANCE_WINDOW` reviews, oldest first
+    fn get_performance_reviews(env: Env, institution_id: u64, employee_id: u64) -> Vec<PerformanceReview>;
+
+    /// Get the rolling average performance score of an employee
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    ///
+    /// # Returns
+    ///
+    /// Average score, or `None` if the employee has too few reviews
+    fn get_average_score(env: Env, institution_id: u64, employee_id: u64) -> Option<u32>;
+
+    /// List the employees currently eligible for promotion (paginated)
+    ///
+    /// Scans the employee IDs `start_id..start_id + limit`; the next page
+    /// starts at `start_id + limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `start_id` - First employee ID to scan
+    /// * `limit` - Number of employee IDs to scan, at most `MAX_CANDIDATE_SCAN`
+    ///
+    /// # Returns
+    ///
+    /// Employees in the range passing the tenure and performance checks
+    fn promotion_candidates(env: Env, institution_id: u64, start_id: u64, limit: u32) -> Vec<Employee>;
+}
+
+/// Rank ladder interface
+///
+/// Each institution has its own ordered career ladder. New institutions start
+/// with the default `EmployeeRank` ladder, which the institution admin can
+/// replace without redeploying the contract.
+pub trait EmployeeManagementRankInterface {
+    /// Replace the rank ladder of an institution
+    ///
+    /// Every rank currently held by an employee must remain in the ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `ranks` - Ranks ordered from lowest to highest
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["rank_ladder_set", institution_id: u64]`,
+    /// data = `Vec<RankConfig>`
+    fn set_rank_ladder(env: Env, institution_id: u64, ranks: Vec<RankConfig>);
+
+    /// Get the rank ladder of an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
+    /// # Returns
+    ///
+    /// Ranks ordered from lowest to highest
+    fn get_rank_ladder(env: Env, institution_id: u64) -> Vec<RankConfig>;
+}
+
+/// Employee self-service interface
+///
+/// Entry points authorized by the employee rather than the institution admin.
+/// Employees look themselves up by address, can move their record to a new
+/// key, and can resign with a notice period after which the admin settles
+/// their final pay.
+pub trait EmployeeManagementSelfServiceInterface {
+    /// Update the caller's own profile
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_address` - Address of the employee (must authorize)
+    /// * `name` - New name
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["employee_updated", institution_id: u64, employee_id: u64]`,
+    /// data = `EmployeeUpdatedEvent`
+    fn update_my_profile(env: Env, institution_id: u64, employee_address: Address, name: String);
+
+    /// Move an employee record to a new address
+    ///
+    /// Both addresses must authorize. The record keeps its employee ID, rank,
+    /// history and pending leave.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `old_address` - Current address of the employee
+    /// * `new_address` - Address to pay and authorize with from now on
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["employee_address_rotated", institution_id: u64, employee_id: u64]`,
+    /// data = `EmployeeAddressRotatedEvent`
+    fn rotate_address(env: Env, institution_id: u64, old_address: Address, new_address: Address);
+
+    /// Resign, effective once the institution's notice period has passed
+    ///
+    /// The employee keeps their status and salary during the notice period.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_address` - Address of the employee (must authorize)
+    ///
+    /// # Returns
+    ///
+    /// Ledger sequence the resignation takes effect at
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["resignation_submitted", institution_id: u64, employee_id: u64]`,
+    /// data = `ResignationSubmittedEvent`
+    fn resign(env: Env, institution_id: u64, employee_address: Address) -> u64;
+
+    /// Settle a resignation whose notice period has ended
+    ///
+    /// Records the final pay owed to active employees for the time between the
+    /// end of their last paid period and the resignation date, then sets the
+    /// status to Terminated. The final pay is settled separately with
+    /// `pay_final_salary`, so finalizing never depends on the admin's balance
+    /// or allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `caller` - The resigning employee or the institution admin (must authorize)
+    /// * `employee_id` - ID of the resigning employee
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["employee_status_changed", institution_id: u64, employee_id: u64]`,
+    /// data = `EmployeeStatusChangedEvent`
+    fn finalize_resignation(env: Env, institution_id: u64, caller: Address, employee_id: u64);
+
+    /// Get an employee's pending resignation
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    ///
+    /// # Returns
+    ///
+    /// The resignation, or None if none is pending
+    fn get_resignation(env: Env, institution_id: u64, employee_id: u64) -> Option<Resignation>;
+
+    /// Pay the final salary recorded when a resignation was finalized
+    ///
+    /// Only the institution admin can call this; the salary is transferred from
+    /// the admin to the former employee.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the former employee
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["salary_paid", institution_id: u64, employee_id: u64]`,
+    /// data = `SalaryPaidEvent`
+    fn pay_final_salary(env: Env, institution_id: u64, employee_id: u64);
+
+    /// Get the final salary still owed to a former employee
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `employee_id` - ID of the employee
+    ///
+    /// # Returns
+    ///
+    /// The final pay, or None if nothing is owed
+    fn get_final_pay(env: Env, institution_id: u64, employee_id: u64) -> Option<FinalPay>;
+
+    /// Set the resignation notice period of an institution
+    ///
+    /// Applies to resignations submitted afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    /// * `ledgers` - Notice period in ledger sequences
+    ///
+    /// # Events
+    ///
+    /// Emits an event with topics `["notice_period_set", institution_id: u64]`,
+    /// data = notice period `u64`
+    fn set_notice_period(env: Env, institution_id: u64, ledgers: u64);
+
+    /// Get the resignation notice period of an institution
+    ///
+    /// # Arguments
+    ///
+    /// * `institution_id` - ID of the institution
+    ///
+    /// # Returns
+    ///
+    /// Notice period in ledger sequences
+    fn get_notice_period(env: Env, institution_id: u64) -> u64;
 }
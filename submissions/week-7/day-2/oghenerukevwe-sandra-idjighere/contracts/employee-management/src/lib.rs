@@ -3,8 +3,11 @@
 //! # Employee Management System
 //! 
 //! This contract implements a comprehensive employee management system that integrates
-//! with SEP-41 tokens for salary payments. The system provides the following features:
+//! with SEP-41 tokens for salary payments. A single deployment hosts many institutions,
+//! each with its own admin, salary token and promotion policy. The system provides the
+//! following features:
 //! 
+//! - Open institution registration backed by a refundable deposit
 //! - Employee registration with ranks and departments
 //! - Rank-based salary calculation and promotion system
 //! - Employee status management (active, suspended, terminated)
@@ -21,9 +24,26 @@
 //! - Suspend and reactivate employees
 //! 
 //! ### Rank System
-//! - 9-tier rank hierarchy: Intern → Junior → Mid → Senior → Lead → Manager → Director → VP → CEO
-//! - Salary multipliers based on rank (1x to 30x base salary)
-//! - Promotion eligibility based on time intervals
+//! - Per-institution rank ladder stored on-chain and editable by the institution admin
+//! - Each rank defines its name, salary multiplier, minimum tenure, promotion score
+//!   threshold and leave entitlements
+//! - Default 9-tier ladder: Intern → Junior → Mid → Senior → Lead → Manager → Director → VP → CEO
+//!   with salary multipliers from 1x to 30x base salary
+//! - Promotion eligibility based on time intervals and performance
+//! 
+//! ### Employee Self-Service
+//! - Employees update their own profile, authorized by their address
+//! - Address rotation moves a record to a new key without re-adding the employee
+//! - Resignation with a per-institution notice period, after which the employee
+//!   or the admin terminates the employee and records the prorated final pay,
+//!   which the admin then pays out
+//! 
+//! ### Performance Reviews
+//! - Appointed reviewers submit signed periodic scores (0-100) per employee
+//! - Rolling average over the most recent scores
+//! - Rank-specific score thresholds gate promotions
+//! - `promotion_candidates` lists everyone who currently qualifies, a page of
+//!   employee IDs at a time
 //! 
 //! ### Salary Management
 //! - Integration with SEP-41 token contracts
@@ -31,22 +51,44 @@
 //! - Individual and batch salary payments
 //! - Salary payment tracking and events
 //! 
+//! ### Institution Registry
+//! - Anyone can register an institution by funding the registration deposit
+//! - Institutions are keyed by ID and every employee call is scoped by institution
+//! - Per-institution admin, token contract, base salary and promotion interval
+//! - Closing an empty institution refunds its deposit
+//! 
+//! ### Leave and Attendance
+//! - Annual, sick and unpaid leave requested by employees and decided by the admin
+//! - Yearly annual and sick leave entitlements derived from rank
+//! - Leave balance queries per leave year
+//! - Approved unpaid leave deducted from the salary of the pay period it falls in
+//! 
+//! ### Multi-Party Approval
+//! - Optional per-institution approval policy with proposers, approvers and quorum
+//! - Promotions, suspensions, reactivations and terminations proposed by a manager
+//! - Policy changes and removal go through the same proposals
+//! - Automatic execution once the quorum approves within the ledger deadline
+//! - Every proposal step emits an event for auditability
+//! 
 //! ### Administrative Features
-//! - Institution setup and configuration
-//! - Admin role management
+//! - Platform admin manages the registration deposit
+//! - Institution admins manage their own institution configuration
 //! - Token contract integration
 //! - Promotion interval configuration
 //! 
 //! ## Usage
 //! 
-//! 1. Initialize the contract with institution details and token contract
-//! 2. Add employees with their initial ranks and departments
-//! 3. Manage employee lifecycle (promotions, status changes, updates)
-//! 4. Process salary payments using the integrated token system
+//! 1. Initialize the contract with the platform admin and deposit token
+//! 2. Register an institution with its details and salary token contract
+//! 3. Add employees with their initial ranks and departments
+//! 4. Manage employee lifecycle (promotions, status changes, updates)
+//! 5. Process salary payments using the integrated token system
 //! 
 //! ## Security Features
 //! 
 //! - Authorization required for all administrative operations
+//! - No single key can promote, suspend, reactivate, fire or drop the approval
+//!   policy once one is set
 //! - Input validation on all parameters
 //! - Proper event emission for transparency
 //! - Protection against duplicate employee addresses
@@ -56,11 +98,25 @@ mod interface;
 mod storage;
 mod types;
 
-pub use contract::EmployeeManagement;
-pub use interface::{EmployeeManagementInterface, EmployeeManagementAdminInterface};
+pub use contract::{EmployeeManagement, EmployeeManagementClient};
+pub use interface::{
+    EmployeeManagementInterface, EmployeeManagementAdminInterface,
+    EmployeeManagementApprovalInterface, EmployeeManagementLeaveInterface,
+    EmployeeManagementPerformanceInterface, EmployeeManagementRankInterface,
+    EmployeeManagementSelfServiceInterface,
+};
 pub use types::{
     Employee, EmployeeRank, EmployeeStatus, Institution,
-    EmployeeAddedEvent, EmployeePromotedEvent, EmployeeStatusChangedEvent, SalaryPaidEvent,
+    InstitutionRegisteredEvent, EmployeeAddedEvent, EmployeeUpdatedEvent, EmployeePromotedEvent, EmployeeStatusChangedEvent, SalaryPaidEvent,
+    ApprovalPolicy, Proposal, ProposalAction, ProposalStatus,
+    ProposalCreatedEvent, ProposalApprovedEvent, ProposalExecutedEvent,
+    LeaveType, LeaveStatus, LeaveRequest, LeaveUsage, LeaveBalance,
+    LeaveRequestedEvent, LeaveDecidedEvent, LEDGERS_PER_YEAR, DAYS_PER_SALARY_PERIOD,
+    LEDGERS_PER_DAY, LEDGERS_PER_SALARY_PERIOD, pay_period,
+    PerformanceReview, PerformanceScoredEvent, PERFORMANCE_WINDOW, MIN_PERFORMANCE_REVIEWS,
+    MAX_PERFORMANCE_SCORE, MAX_CANDIDATE_SCAN, RankConfig,
+    default_rank_ladder, find_rank, next_rank,
+    Resignation, FinalPay, EmployeeAddressRotatedEvent, ResignationSubmittedEvent, DEFAULT_NOTICE_PERIOD,
 };
 
 #[cfg(test)]
@@ -1,97 +1,400 @@
-use soroban_sdk::{contracttype, Address, Env};
-use crate::types::{Employee, Institution};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::types::{
+    default_rank_ladder, ApprovalPolicy, Employee, FinalPay, Institution, LeaveRequest, LeaveUsage,
+    PerformanceReview, Proposal, RankConfig, Resignation, DEFAULT_NOTICE_PERIOD,
+};
 
 /// Storage keys for the employee management contract
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    /// Institution information
-    Institution,
-    /// Employee by ID
-    Employee(u64),
-    /// Employee ID by address (for reverse lookup)
-    EmployeeByAddress(Address),
-    /// Next employee ID counter
-    NextEmployeeId,
-    /// Total number of employees
-    EmployeeCount,
+    /// Institution information by ID
+    Institution(u64),
+    /// Next institution ID counter
+    NextInstitutionId,
+    /// Rank ladder per institution
+    RankLadder(u64),
+    /// Employee by (institution ID, employee ID)
+    Employee(u64, u64),
+    /// Employee ID by (institution ID, address) (for reverse lookup)
+    EmployeeByAddress(u64, Address),
+    /// Next employee ID counter per institution
+    NextEmployeeId(u64),
+    /// Total number of employees per institution
+    EmployeeCount(u64),
+    /// Number of employee records holding a rank by (institution ID, rank ID)
+    RankEmployeeCount(u64, u32),
+    /// Approval policy per institution
+    ApprovalPolicy(u64),
+    /// Proposal by (institution ID, proposal ID)
+    Proposal(u64, u64),
+    /// Next proposal ID counter per institution
+    NextProposalId(u64),
+    /// Leave request by (institution ID, request ID)
+    LeaveRequest(u64, u64),
+    /// Next leave request ID counter per institution
+    NextLeaveRequestId(u64),
+    /// Pending and approved leave requests that haven't ended, by (institution ID, employee ID)
+    OpenLeaveRequests(u64, u64),
+    /// Approved leave usage by (institution ID, employee ID, leave year)
+    LeaveUsage(u64, u64, u32),
+    /// Unpaid leave days by (institution ID, employee ID, pay period)
+    UnpaidLeaveDays(u64, u64, u64),
+    /// Ledger an employee's salary is paid up to by (institution ID, employee ID)
+    PaidThrough(u64, u64),
+    /// Performance reviewers per institution
+    Reviewers(u64),
+    /// Most recent performance reviews by (institution ID, employee ID)
+    PerformanceReviews(u64, u64),
+    /// Resignation notice period per institution
+    NoticePeriod(u64),
+    /// Pending resignation by (institution ID, employee ID)
+    Resignation(u64, u64),
+    /// Unpaid final salary by (institution ID, employee ID)
+    FinalPay(u64, u64),
     /// Initialization status
     Initialized,
-    /// Admin address
+    /// Platform admin address
     Admin,
+    /// Token used for institution registration deposits
+    DepositToken,
+    /// Deposit required to register an institution
+    RegistrationDeposit,
+    /// Total registration deposits held for open institutions
+    DepositsHeld,
 }
 
-/// Storage operations for institution
-pub fn read_institution(env: &Env) -> Institution {
-    let key = DataKey::Institution;
-    env.storage().instance().get(&key).unwrap()
+/// Storage operations for institutions
+pub fn read_institution(env: &Env, institution_id: u64) -> Option<Institution> {
+    let key = DataKey::Institution(institution_id);
+    env.storage().persistent().get(&key)
 }
 
 pub fn write_institution(env: &Env, institution: &Institution) {
-    let key = DataKey::Institution;
-    env.storage().instance().set(&key, institution);
+    let key = DataKey::Institution(institution.id);
+    env.storage().persistent().set(&key, institution);
+}
+
+pub fn remove_institution(env: &Env, institution_id: u64) {
+    let key = DataKey::Institution(institution_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Storage operations for institution ID counter
+pub fn get_next_institution_id(env: &Env) -> u64 {
+    let key = DataKey::NextInstitutionId;
+    let next_id = env.storage().instance().get(&key).unwrap_or(1u64);
+    env.storage().instance().set(&key, &(next_id + 1));
+    next_id
+}
+
+/// Number of institutions registered so far (including closed ones)
+pub fn get_institution_count(env: &Env) -> u64 {
+    let key = DataKey::NextInstitutionId;
+    env.storage().instance().get(&key).unwrap_or(1u64) - 1
+}
+
+/// Storage operations for rank ladders
+///
+/// Institutions without a stored ladder use the default `EmployeeRank` ladder.
+pub fn read_rank_ladder(env: &Env, institution_id: u64) -> Vec<RankConfig> {
+    let key = DataKey::RankLadder(institution_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| default_rank_ladder(env))
+}
+
+pub fn write_rank_ladder(env: &Env, institution_id: u64, ladder: &Vec<RankConfig>) {
+    let key = DataKey::RankLadder(institution_id);
+    env.storage().persistent().set(&key, ladder);
 }
 
 /// Storage operations for employees
-pub fn read_employee(env: &Env, employee_id: u64) -> Option<Employee> {
-    let key = DataKey::Employee(employee_id);
+pub fn read_employee(env: &Env, institution_id: u64, employee_id: u64) -> Option<Employee> {
+    let key = DataKey::Employee(institution_id, employee_id);
     env.storage().persistent().get(&key)
 }
 
 pub fn write_employee(env: &Env, employee: &Employee) {
-    let key = DataKey::Employee(employee.id);
+    let key = DataKey::Employee(employee.institution_id, employee.id);
     env.storage().persistent().set(&key, employee);
-    
+
     // Also store reverse lookup (address -> employee_id)
-    let address_key = DataKey::EmployeeByAddress(employee.address.clone());
+    let address_key = DataKey::EmployeeByAddress(employee.institution_id, employee.address.clone());
     env.storage().persistent().set(&address_key, &employee.id);
 }
 
-pub fn remove_employee(env: &Env, employee_id: u64) {
-    if let Some(employee) = read_employee(env, employee_id) {
-        let key = DataKey::Employee(employee_id);
+pub fn remove_employee(env: &Env, institution_id: u64, employee_id: u64) {
+    if let Some(employee) = read_employee(env, institution_id, employee_id) {
+        let key = DataKey::Employee(institution_id, employee_id);
         env.storage().persistent().remove(&key);
-        
+
         // Remove reverse lookup
-        let address_key = DataKey::EmployeeByAddress(employee.address);
+        let address_key = DataKey::EmployeeByAddress(institution_id, employee.address);
         env.storage().persistent().remove(&address_key);
     }
 }
 
+/// Drop the reverse lookup of an address the employee no longer uses
+pub fn remove_employee_address(env: &Env, institution_id: u64, address: &Address) {
+    let key = DataKey::EmployeeByAddress(institution_id, address.clone());
+    env.storage().persistent().remove(&key);
+}
+
 /// Get employee ID by address
-pub fn get_employee_id_by_address(env: &Env, address: &Address) -> Option<u64> {
-    let key = DataKey::EmployeeByAddress(address.clone());
+pub fn get_employee_id_by_address(env: &Env, institution_id: u64, address: &Address) -> Option<u64> {
+    let key = DataKey::EmployeeByAddress(institution_id, address.clone());
     env.storage().persistent().get(&key)
 }
 
 /// Storage operations for employee ID counter
-pub fn get_next_employee_id(env: &Env) -> u64 {
-    let key = DataKey::NextEmployeeId;
-    let next_id = env.storage().instance().get(&key).unwrap_or(1u64);
-    env.storage().instance().set(&key, &(next_id + 1));
+pub fn get_next_employee_id(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::NextEmployeeId(institution_id);
+    let next_id = env.storage().persistent().get(&key).unwrap_or(1u64);
+    env.storage().persistent().set(&key, &(next_id + 1));
     next_id
 }
 
+/// Highest employee ID ever assigned in an institution
+pub fn get_last_employee_id(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::NextEmployeeId(institution_id);
+    env.storage().persistent().get(&key).unwrap_or(1u64) - 1
+}
+
 /// Storage operations for employee count
-pub fn get_employee_count(env: &Env) -> u64 {
-    let key = DataKey::EmployeeCount;
-    env.storage().instance().get(&key).unwrap_or(0u64)
+pub fn get_employee_count(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::EmployeeCount(institution_id);
+    env.storage().persistent().get(&key).unwrap_or(0u64)
 }
 
-pub fn increment_employee_count(env: &Env) {
-    let key = DataKey::EmployeeCount;
-    let count = get_employee_count(env);
-    env.storage().instance().set(&key, &(count + 1));
+pub fn increment_employee_count(env: &Env, institution_id: u64) {
+    let key = DataKey::EmployeeCount(institution_id);
+    let count = get_employee_count(env, institution_id);
+    env.storage().persistent().set(&key, &(count + 1));
 }
 
-pub fn decrement_employee_count(env: &Env) {
-    let key = DataKey::EmployeeCount;
-    let count = get_employee_count(env);
+pub fn decrement_employee_count(env: &Env, institution_id: u64) {
+    let key = DataKey::EmployeeCount(institution_id);
+    let count = get_employee_count(env, institution_id);
     if count > 0 {
-        env.storage().instance().set(&key, &(count - 1));
+        env.storage().persistent().set(&key, &(count - 1));
+    }
+}
+
+/// Storage operations for per-rank employee counts
+///
+/// Terminated employees keep their record and rank, so they are counted until removed.
+pub fn get_rank_employee_count(env: &Env, institution_id: u64, rank_id: u32) -> u64 {
+    let key = DataKey::RankEmployeeCount(institution_id, rank_id);
+    env.storage().persistent().get(&key).unwrap_or(0u64)
+}
+
+pub fn increment_rank_employee_count(env: &Env, institution_id: u64, rank_id: u32) {
+    let key = DataKey::RankEmployeeCount(institution_id, rank_id);
+    let count = get_rank_employee_count(env, institution_id, rank_id);
+    env.storage().persistent().set(&key, &(count + 1));
+}
+
+pub fn decrement_rank_employee_count(env: &Env, institution_id: u64, rank_id: u32) {
+    let key = DataKey::RankEmployeeCount(institution_id, rank_id);
+    let count = get_rank_employee_count(env, institution_id, rank_id);
+    if count > 1 {
+        env.storage().persistent().set(&key, &(count - 1));
+    } else {
+        env.storage().persistent().remove(&key);
     }
 }
 
+/// Storage operations for approval policies
+pub fn read_approval_policy(env: &Env, institution_id: u64) -> Option<ApprovalPolicy> {
+    let key = DataKey::ApprovalPolicy(institution_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_approval_policy(env: &Env, institution_id: u64, policy: &ApprovalPolicy) {
+    let key = DataKey::ApprovalPolicy(institution_id);
+    env.storage().persistent().set(&key, policy);
+}
+
+pub fn remove_approval_policy(env: &Env, institution_id: u64) {
+    let key = DataKey::ApprovalPolicy(institution_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Storage operations for proposals
+pub fn read_proposal(env: &Env, institution_id: u64, proposal_id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(institution_id, proposal_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.institution_id, proposal.id);
+    env.storage().persistent().set(&key, proposal);
+}
+
+/// Storage operations for proposal ID counter
+pub fn get_next_proposal_id(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::NextProposalId(institution_id);
+    let next_id = env.storage().persistent().get(&key).unwrap_or(1u64);
+    env.storage().persistent().set(&key, &(next_id + 1));
+    next_id
+}
+
+/// Storage operations for leave requests
+pub fn read_leave_request(env: &Env, institution_id: u64, request_id: u64) -> Option<LeaveRequest> {
+    let key = DataKey::LeaveRequest(institution_id, request_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_leave_request(env: &Env, request: &LeaveRequest) {
+    let key = DataKey::LeaveRequest(request.institution_id, request.id);
+    env.storage().persistent().set(&key, request);
+}
+
+/// Storage operations for an employee's open leave request IDs
+pub fn read_open_leave_requests(env: &Env, institution_id: u64, employee_id: u64) -> Vec<u64> {
+    let key = DataKey::OpenLeaveRequests(institution_id, employee_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn write_open_leave_requests(
+    env: &Env,
+    institution_id: u64,
+    employee_id: u64,
+    request_ids: &Vec<u64>,
+) {
+    let key = DataKey::OpenLeaveRequests(institution_id, employee_id);
+    env.storage().persistent().set(&key, request_ids);
+}
+
+/// Storage operations for leave request ID counter
+pub fn get_next_leave_request_id(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::NextLeaveRequestId(institution_id);
+    let next_id = env.storage().persistent().get(&key).unwrap_or(1u64);
+    env.storage().persistent().set(&key, &(next_id + 1));
+    next_id
+}
+
+/// Storage operations for leave usage
+pub fn read_leave_usage(env: &Env, institution_id: u64, employee_id: u64, year: u32) -> LeaveUsage {
+    let key = DataKey::LeaveUsage(institution_id, employee_id, year);
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub fn write_leave_usage(
+    env: &Env,
+    institution_id: u64,
+    employee_id: u64,
+    year: u32,
+    usage: &LeaveUsage,
+) {
+    let key = DataKey::LeaveUsage(institution_id, employee_id, year);
+    env.storage().persistent().set(&key, usage);
+}
+
+/// Storage operations for unpaid leave days per pay period
+pub fn get_unpaid_leave_days(env: &Env, institution_id: u64, employee_id: u64, period: u64) -> u32 {
+    let key = DataKey::UnpaidLeaveDays(institution_id, employee_id, period);
+    env.storage().persistent().get(&key).unwrap_or(0u32)
+}
+
+pub fn read_paid_through(env: &Env, institution_id: u64, employee_id: u64) -> Option<u64> {
+    let key = DataKey::PaidThrough(institution_id, employee_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_paid_through(env: &Env, institution_id: u64, employee_id: u64, ledger: u64) {
+    let key = DataKey::PaidThrough(institution_id, employee_id);
+    env.storage().persistent().set(&key, &ledger);
+}
+
+pub fn add_unpaid_leave_days(
+    env: &Env,
+    institution_id: u64,
+    employee_id: u64,
+    period: u64,
+    days: u32,
+) {
+    let key = DataKey::UnpaidLeaveDays(institution_id, employee_id, period);
+    let recorded = get_unpaid_leave_days(env, institution_id, employee_id, period);
+    env.storage().persistent().set(&key, &(recorded + days));
+}
+
+/// Storage operations for performance reviewers
+pub fn read_reviewers(env: &Env, institution_id: u64) -> Vec<Address> {
+    let key = DataKey::Reviewers(institution_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn write_reviewers(env: &Env, institution_id: u64, reviewers: &Vec<Address>) {
+    let key = DataKey::Reviewers(institution_id);
+    env.storage().persistent().set(&key, reviewers);
+}
+
+/// Storage operations for performance reviews
+pub fn read_performance_reviews(
+    env: &Env,
+    institution_id: u64,
+    employee_id: u64,
+) -> Vec<PerformanceReview> {
+    let key = DataKey::PerformanceReviews(institution_id, employee_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn write_performance_reviews(
+    env: &Env,
+    institution_id: u64,
+    employee_id: u64,
+    reviews: &Vec<PerformanceReview>,
+) {
+    let key = DataKey::PerformanceReviews(institution_id, employee_id);
+    env.storage().persistent().set(&key, reviews);
+}
+
+/// Storage operations for resignation notice periods
+pub fn read_notice_period(env: &Env, institution_id: u64) -> u64 {
+    let key = DataKey::NoticePeriod(institution_id);
+    env.storage().persistent().get(&key).unwrap_or(DEFAULT_NOTICE_PERIOD)
+}
+
+pub fn write_notice_period(env: &Env, institution_id: u64, ledgers: u64) {
+    let key = DataKey::NoticePeriod(institution_id);
+    env.storage().persistent().set(&key, &ledgers);
+}
+
+/// Storage operations for resignations
+pub fn read_resignation(env: &Env, institution_id: u64, employee_id: u64) -> Option<Resignation> {
+    let key = DataKey::Resignation(institution_id, employee_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_resignation(env: &Env, resignation: &Resignation) {
+    let key = DataKey::Resignation(resignation.institution_id, resignation.employee_id);
+    env.storage().persistent().set(&key, resignation);
+}
+
+pub fn remove_resignation(env: &Env, institution_id: u64, employee_id: u64) {
+    let key = DataKey::Resignation(institution_id, employee_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Storage operations for final salaries owed
+pub fn read_final_pay(env: &Env, institution_id: u64, employee_id: u64) -> Option<FinalPay> {
+    let key = DataKey::FinalPay(institution_id, employee_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn write_final_pay(env: &Env, final_pay: &FinalPay) {
+    let key = DataKey::FinalPay(final_pay.institution_id, final_pay.employee_id);
+    env.storage().persistent().set(&key, final_pay);
+}
+
+pub fn remove_final_pay(env: &Env, institution_id: u64, employee_id: u64) {
+    let key = DataKey::FinalPay(institution_id, employee_id);
+    env.storage().persistent().remove(&key);
+}
+
 /// Storage operations for initialization status
 pub fn is_initialized(env: &Env) -> bool {
     let key = DataKey::Initialized;
@@ -114,12 +417,38 @@ pub fn write_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&key, admin);
 }
 
-/// Helper function to check if employee exists
-pub fn employee_exists(env: &Env, employee_id: u64) -> bool {
-    read_employee(env, employee_id).is_some()
+/// Storage operations for registration deposit configuration
+pub fn read_deposit_token(env: &Env) -> Option<Address> {
+    let key = DataKey::DepositToken;
+    env.storage().instance().get(&key)
+}
+
+pub fn write_deposit_token(env: &Env, token: &Address) {
+    let key = DataKey::DepositToken;
+    env.storage().instance().set(&key, token);
+}
+
+pub fn read_registration_deposit(env: &Env) -> i128 {
+    let key = DataKey::RegistrationDeposit;
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_registration_deposit(env: &Env, amount: i128) {
+    let key = DataKey::RegistrationDeposit;
+    env.storage().instance().set(&key, &amount);
+}
+
+pub fn read_deposits_held(env: &Env) -> i128 {
+    let key = DataKey::DepositsHeld;
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_deposits_held(env: &Env, amount: i128) {
+    let key = DataKey::DepositsHeld;
+    env.storage().instance().set(&key, &amount);
 }
 
 /// Helper function to check if address is already an employee
-pub fn address_is_employee(env: &Env, address: &Address) -> bool {
-    get_employee_id_by_address(env, address).is_some()
+pub fn address_is_employee(env: &Env, institution_id: u64, address: &Address) -> bool {
+    get_employee_id_by_address(env, institution_id, address).is_some()
 }
@@ -1,10 +1,19 @@
 #![cfg(test)]
 
+extern crate std;
+
 use crate::EmployeeManagement;
-use crate::types::{EmployeeRank, EmployeeStatus};
+use crate::types::{
+    average_score, leave_year, ApprovalPolicy, PerformanceReview, EmployeeRank, EmployeeStatus, LeaveStatus, LeaveType, ProposalAction,
+    ProposalStatus, RankConfig, DEFAULT_NOTICE_PERIOD, LEDGERS_PER_DAY, LEDGERS_PER_SALARY_PERIOD, MAX_CANDIDATE_SCAN,
+};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, Env, String,
+    testutils::{
+        Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger, MockAuth,
+        MockAuthInvoke,
+    },
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
 
 // Import the auto-generated client
@@ -21,22 +30,171 @@ fn create_mock_token_contract(env: &Env) -> Address {
     Address::generate(env)
 }
 
+fn create_deposit_token(env: &Env) -> (Address, StellarAssetClient<'_>) {
+    let issuer = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(issuer);
+    let address = token.address();
+    (address.clone(), StellarAssetClient::new(env, &address))
+}
+
+/// Initialize the platform with no registration deposit
+fn initialize_platform(env: &Env, contract: &EmployeeManagementClient) -> Address {
+    let platform_admin = Address::generate(env);
+    let deposit_token = create_mock_token_contract(env);
+    contract.initialize(&platform_admin, &deposit_token, &0);
+    platform_admin
+}
+
+/// Register an institution with one employee and an approval policy of
+/// one manager proposing and HR plus a department lead approving (quorum 2)
+fn setup_approval_institution(
+    env: &Env,
+    contract: &EmployeeManagementClient,
+) -> (u64, u64, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let manager = Address::generate(env);
+    let hr = Address::generate(env);
+    let lead = Address::generate(env);
+    let token_contract = create_mock_token_contract(env);
+
+    initialize_platform(env, contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &Address::generate(env),
+        &String::from_str(env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(env, "Engineering"),
+    );
+
+    contract.set_approval_policy(
+        &institution_id,
+        &vec![env, manager.clone()],
+        &vec![env, hr.clone(), lead.clone()],
+        &2,
+        &50,
+    );
+
+    (institution_id, employee_id, manager, hr, lead)
+}
+
+/// Appoint a reviewer and submit one score per period for an employee
+fn submit_scores(
+    env: &Env,
+    contract: &EmployeeManagementClient,
+    institution_id: u64,
+    employee_id: u64,
+    scores: &[u32],
+) {
+    let reviewer = Address::generate(env);
+    contract.set_reviewers(&institution_id, &vec![env, reviewer.clone()]);
+    for (period, score) in scores.iter().enumerate() {
+        contract.submit_performance_score(
+            &institution_id,
+            &reviewer,
+            &employee_id,
+            &(period as u64 + 1),
+            score,
+        );
+    }
+}
+
+/// Authorize only `signer`'s call of `fn_name` with `args`, so any other
+/// `require_auth` in the transaction fails
+fn mock_single_auth(
+    env: &Env,
+    contract: &EmployeeManagementClient,
+    signer: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) {
+    env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &contract.address,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+/// Authorization recorded for `signer` calling `fn_name` on the contract
+fn contract_auth(
+    env: &Env,
+    contract: &EmployeeManagementClient,
+    signer: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> (Address, AuthorizedInvocation) {
+    (
+        signer.clone(),
+        AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                contract.address.clone(),
+                Symbol::new(env, fn_name),
+                args,
+            )),
+            sub_invocations: std::vec![],
+        },
+    )
+}
+
+fn last_event_name(env: &Env) -> String {
+    let (_, topics, _) = env.events().all().last().unwrap();
+    String::try_from_val(env, &topics.get(0).unwrap()).unwrap()
+}
+
 fn advance_ledger(env: &Env, delta: u32) {
     env.ledger().with_mut(|li| {
         li.sequence_number += delta;
     });
 }
 
+/// Keep entries written from now on live for `ledgers` sequences, for tests
+/// that advance across whole pay periods
+fn keep_entries_live(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = ledgers;
+        li.max_entry_ttl = ledgers;
+    });
+}
+
 #[test]
 fn test_initialize() {
     let env = Env::default();
     env.mock_all_auths();
 
+    let platform_admin = Address::generate(&env);
+    let deposit_token = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    contract.initialize(&platform_admin, &deposit_token, &50_0000000);
+
+    assert!(contract.initialized());
+    assert_eq!(contract.get_admin(), platform_admin);
+    assert_eq!(contract.get_registration_deposit(), 50_0000000);
+    assert_eq!(contract.get_institution_count(), 0);
+}
+
+#[test]
+fn test_register_institution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
     let admin = Address::generate(&env);
     let token_contract = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -44,15 +202,17 @@ fn test_initialize() {
         &100, // 100 ledger sequences between promotions
     );
 
-    assert_eq!(contract.initialized(), true);
-    assert_eq!(contract.get_admin(), admin);
-    
-    let institution = contract.get_institution();
+    assert_eq!(institution_id, 1);
+    assert_eq!(contract.get_institution_count(), 1);
+
+    let institution = contract.get_institution(&institution_id);
+    assert_eq!(institution.id, 1);
     assert_eq!(institution.name, String::from_str(&env, "Tech Corp"));
     assert_eq!(institution.admin, admin);
     assert_eq!(institution.token_contract, token_contract);
     assert_eq!(institution.base_salary_amount, 1000_0000000);
     assert_eq!(institution.min_promotion_interval, 100);
+    assert_eq!(institution.deposit, 0);
 }
 
 #[test]
@@ -62,25 +222,13 @@ fn test_initialize_twice() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let token_contract = create_mock_token_contract(&env);
+    let deposit_token = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
-        &admin,
-        &String::from_str(&env, "Tech Corp"),
-        &token_contract,
-        &1000_0000000,
-        &100,
-    );
+    contract.initialize(&admin, &deposit_token, &0);
 
     // Should panic on second initialization
-    contract.initialize(
-        &admin,
-        &String::from_str(&env, "Tech Corp 2"),
-        &token_contract,
-        &2000_0000000,
-        &200,
-    );
+    contract.initialize(&admin, &deposit_token, &100);
 }
 
 #[test]
@@ -93,7 +241,8 @@ fn test_add_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -102,26 +251,27 @@ fn test_add_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
     assert_eq!(employee_id, 1);
-    assert_eq!(contract.get_employee_count(), 1);
+    assert_eq!(contract.get_employee_count(&institution_id), 1);
 
-    let employee = contract.get_employee(&employee_id);
+    let employee = contract.get_employee(&institution_id, &employee_id);
     assert_eq!(employee.id, 1);
     assert_eq!(employee.address, employee_addr);
     assert_eq!(employee.name, String::from_str(&env, "John Doe"));
-    assert_eq!(employee.rank, EmployeeRank::Junior);
+    assert_eq!(employee.rank_id, EmployeeRank::Junior.default_id());
     assert_eq!(employee.status, EmployeeStatus::Active);
     assert_eq!(employee.department, String::from_str(&env, "Engineering"));
-    assert_eq!(employee.current_salary(), 2000_0000000); // 1000 * 2 (Junior multiplier)
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 2000_0000000); // 1000 * 2 (Junior multiplier)
 
     // Test reverse lookup
-    assert_eq!(contract.get_employee_by_address(&employee_addr), Some(1));
+    assert_eq!(contract.get_employee_by_address(&institution_id, &employee_addr), Some(1));
 }
 
 #[test]
@@ -135,7 +285,8 @@ fn test_add_duplicate_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -144,17 +295,19 @@ fn test_add_duplicate_employee() {
     );
 
     contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
     // Should panic when adding the same address again
     contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "Jane Doe"),
-        &EmployeeRank::Senior,
+        &EmployeeRank::Senior.default_id(),
         &String::from_str(&env, "Marketing"),
     );
 }
@@ -169,7 +322,8 @@ fn test_update_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -178,25 +332,27 @@ fn test_update_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
     // Update employee information
     contract.update_employee(
+        &institution_id,
         &employee_id,
         &Some(String::from_str(&env, "John Smith")),
         &Some(String::from_str(&env, "DevOps")),
         &Some(1500_0000000),
     );
 
-    let employee = contract.get_employee(&employee_id);
+    let employee = contract.get_employee(&institution_id, &employee_id);
     assert_eq!(employee.name, String::from_str(&env, "John Smith"));
     assert_eq!(employee.department, String::from_str(&env, "DevOps"));
     assert_eq!(employee.base_salary, 1500_0000000);
-    assert_eq!(employee.current_salary(), 3000_0000000); // 1500 * 2 (Junior multiplier)
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 3000_0000000); // 1500 * 2 (Junior multiplier)
 }
 
 #[test]
@@ -209,7 +365,8 @@ fn test_promote_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -218,20 +375,22 @@ fn test_promote_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
-    // Advance time to make employee eligible for promotion
+    // Advance time and score above the Junior threshold to make employee eligible
     advance_ledger(&env, 101);
+    submit_scores(&env, &contract, institution_id, employee_id, &[70, 80]);
 
-    contract.promote_employee(&employee_id);
+    contract.promote_employee(&institution_id, &employee_id);
 
-    let employee = contract.get_employee(&employee_id);
-    assert_eq!(employee.rank, EmployeeRank::Mid);
-    assert_eq!(employee.current_salary(), 3000_0000000); // 1000 * 3 (Mid multiplier)
+    let employee = contract.get_employee(&institution_id, &employee_id);
+    assert_eq!(employee.rank_id, EmployeeRank::Mid.default_id());
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 3000_0000000); // 1000 * 3 (Mid multiplier)
     assert!(employee.last_promotion.is_some());
 }
 
@@ -246,7 +405,8 @@ fn test_promote_employee_too_early() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -255,14 +415,17 @@ fn test_promote_employee_too_early() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
+    submit_scores(&env, &contract, institution_id, employee_id, &[90, 90]);
+
     // Try to promote immediately (should fail)
-    contract.promote_employee(&employee_id);
+    contract.promote_employee(&institution_id, &employee_id);
 }
 
 #[test]
@@ -275,7 +438,8 @@ fn test_suspend_and_reactivate_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -284,20 +448,21 @@ fn test_suspend_and_reactivate_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
     // Suspend employee
-    contract.suspend_employee(&employee_id);
-    let employee = contract.get_employee(&employee_id);
+    contract.suspend_employee(&institution_id, &employee_id);
+    let employee = contract.get_employee(&institution_id, &employee_id);
     assert_eq!(employee.status, EmployeeStatus::Suspended);
 
     // Reactivate employee
-    contract.reactivate_employee(&employee_id);
-    let employee = contract.get_employee(&employee_id);
+    contract.reactivate_employee(&institution_id, &employee_id);
+    let employee = contract.get_employee(&institution_id, &employee_id);
     assert_eq!(employee.status, EmployeeStatus::Active);
 }
 
@@ -311,7 +476,8 @@ fn test_remove_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -320,18 +486,19 @@ fn test_remove_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
-    assert_eq!(contract.get_employee_count(), 1);
+    assert_eq!(contract.get_employee_count(&institution_id), 1);
 
-    contract.remove_employee(&employee_id);
+    contract.remove_employee(&institution_id, &employee_id);
 
-    assert_eq!(contract.get_employee_count(), 0);
-    assert_eq!(contract.get_employee_by_address(&employee_addr), None);
+    assert_eq!(contract.get_employee_count(&institution_id), 0);
+    assert_eq!(contract.get_employee_by_address(&institution_id, &employee_addr), None);
 }
 
 #[test]
@@ -368,7 +535,8 @@ fn test_get_employees_pagination() {
     let token_contract = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -381,18 +549,19 @@ fn test_get_employees_pagination() {
     for name in employee_names.iter() {
         let employee_addr = Address::generate(&env);
         contract.add_employee(
+        &institution_id,
             &employee_addr,
             &String::from_str(&env, name),
-            &EmployeeRank::Junior,
+            &EmployeeRank::Junior.default_id(),
             &String::from_str(&env, "Engineering"),
         );
     }
 
     // Test pagination
-    let employees = contract.get_employees(&1, &3);
+    let employees = contract.get_employees(&institution_id, &1, &3);
     assert_eq!(employees.len(), 3);
 
-    let employees = contract.get_employees(&4, &3);
+    let employees = contract.get_employees(&institution_id, &4, &3);
     assert_eq!(employees.len(), 2); // Only 2 remaining employees
 }
 
@@ -406,7 +575,8 @@ fn test_admin_functions() {
     let token_contract = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    let platform_admin = initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -414,20 +584,31 @@ fn test_admin_functions() {
         &100,
     );
 
-    // Test set admin
-    contract.set_admin(&new_admin);
-    assert_eq!(contract.get_admin(), new_admin);
+    // Test set platform admin
+    let new_platform_admin = Address::generate(&env);
+    assert_eq!(contract.get_admin(), platform_admin);
+    contract.set_admin(&new_platform_admin);
+    assert_eq!(contract.get_admin(), new_platform_admin);
+
+    // Test registration deposit update
+    contract.set_registration_deposit(&25_0000000);
+    assert_eq!(contract.get_registration_deposit(), 25_0000000);
+
+    // Test set institution admin
+    contract.set_institution_admin(&institution_id, &new_admin);
+    assert_eq!(contract.get_institution(&institution_id).admin, new_admin);
 
     // Test update institution
     let new_token_contract = create_mock_token_contract(&env);
     contract.update_institution(
+        &institution_id,
         &Some(String::from_str(&env, "New Tech Corp")),
         &Some(new_token_contract.clone()),
         &Some(2000_0000000),
         &Some(200),
     );
 
-    let institution = contract.get_institution();
+    let institution = contract.get_institution(&institution_id);
     assert_eq!(institution.name, String::from_str(&env, "New Tech Corp"));
     assert_eq!(institution.token_contract, new_token_contract);
     assert_eq!(institution.base_salary_amount, 2000_0000000);
@@ -435,16 +616,95 @@ fn test_admin_functions() {
 }
 
 #[test]
-#[should_panic(expected = "employee not found")]
-fn test_operations_on_nonexistent_employee() {
+fn test_institutions_are_isolated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin_a = Address::generate(&env);
+    let admin_b = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_a = contract.register_institution(
+        &admin_a,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+    let institution_b = contract.register_institution(
+        &admin_b,
+        &String::from_str(&env, "Bank Corp"),
+        &token_contract,
+        &3000_0000000,
+        &50,
+    );
+
+    assert_eq!(institution_a, 1);
+    assert_eq!(institution_b, 2);
+    assert_eq!(contract.get_institution_count(), 2);
+
+    // The same address can work for both institutions, with independent IDs and salaries
+    let id_a = contract.add_employee(
+        &institution_a,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+    contract.add_employee(
+        &institution_b,
+        &Address::generate(&env),
+        &String::from_str(&env, "Jane Doe"),
+        &EmployeeRank::Mid.default_id(),
+        &String::from_str(&env, "Finance"),
+    );
+    let id_b = contract.add_employee(
+        &institution_b,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Advisory"),
+    );
+
+    assert_eq!(id_a, 1);
+    assert_eq!(id_b, 2);
+    assert_eq!(contract.get_employee_count(&institution_a), 1);
+    assert_eq!(contract.get_employee_count(&institution_b), 2);
+    assert_eq!(contract.get_employee_by_address(&institution_a, &employee_addr), Some(1));
+    assert_eq!(contract.get_employee_by_address(&institution_b, &employee_addr), Some(2));
+
+    let employee_a = contract.get_employee(&institution_a, &id_a);
+    let employee_b = contract.get_employee(&institution_b, &id_b);
+    assert_eq!(employee_a.institution_id, institution_a);
+    assert_eq!(employee_b.institution_id, institution_b);
+    assert_eq!(contract.get_employee_salary(&institution_a, &id_a), 2000_0000000);
+    assert_eq!(contract.get_employee_salary(&institution_b, &id_b), 6000_0000000);
+
+    // Removing from one institution leaves the other untouched
+    contract.remove_employee(&institution_a, &id_a);
+    assert_eq!(contract.get_employee_by_address(&institution_a, &employee_addr), None);
+    assert_eq!(contract.get_employee_by_address(&institution_b, &employee_addr), Some(2));
+}
+
+#[test]
+fn test_registration_deposit_and_refund() {
     let env = Env::default();
     env.mock_all_auths();
 
+    let platform_admin = Address::generate(&env);
     let admin = Address::generate(&env);
     let token_contract = create_mock_token_contract(&env);
+    let (deposit_token, deposit_admin) = create_deposit_token(&env);
+    let deposit_client = TokenClient::new(&env, &deposit_token);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    contract.initialize(&platform_admin, &deposit_token, &50_0000000);
+    deposit_admin.mint(&admin, &80_0000000);
+
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -452,31 +712,140 @@ fn test_operations_on_nonexistent_employee() {
         &100,
     );
 
-    // Should panic when trying to get non-existent employee
-    contract.get_employee(&999);
+    assert_eq!(contract.get_institution(&institution_id).deposit, 50_0000000);
+    assert_eq!(deposit_client.balance(&admin), 30_0000000);
+    assert_eq!(deposit_client.balance(&contract.address), 50_0000000);
+
+    // A later deposit change does not affect the amount refunded
+    contract.set_registration_deposit(&10_0000000);
+    contract.close_institution(&institution_id);
+
+    assert_eq!(deposit_client.balance(&admin), 80_0000000);
+    assert_eq!(deposit_client.balance(&contract.address), 0);
 }
 
 #[test]
-#[should_panic(expected = "contract not initialized")]
-fn test_operations_before_initialization() {
+#[should_panic]
+fn test_register_institution_without_deposit_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let platform_admin = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let (deposit_token, _) = create_deposit_token(&env);
+    let contract = create_employee_management_contract(&env);
+
+    contract.initialize(&platform_admin, &deposit_token, &50_0000000);
+
+    // Should panic: admin holds no deposit tokens
+    contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+}
+
+#[test]
+#[should_panic(expected = "institution still has employees")]
+fn test_close_institution_with_employees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    contract.close_institution(&institution_id);
+}
+
+#[test]
+#[should_panic(expected = "institution not found")]
+fn test_operations_on_nonexistent_institution() {
     let env = Env::default();
     env.mock_all_auths();
 
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    // Should panic when trying to add employee before initialization
+    initialize_platform(&env, &contract);
+
+    // Should panic when trying to add an employee to an unregistered institution
     contract.add_employee(
+        &7,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 }
 
+#[test]
+#[should_panic(expected = "employee not found")]
+fn test_operations_on_nonexistent_employee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    // Should panic when trying to get non-existent employee
+    contract.get_employee(&institution_id, &999);
+}
+
+#[test]
+#[should_panic(expected = "contract not initialized")]
+fn test_operations_before_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    // Should panic when trying to register an institution before initialization
+    contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+}
+
 #[test]
 #[should_panic(expected = "institution name must be 1-64 characters")]
-fn test_initialize_invalid_institution_name() {
+fn test_register_invalid_institution_name() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -484,7 +853,8 @@ fn test_initialize_invalid_institution_name() {
     let token_contract = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    contract.register_institution(
         &admin,
         &String::from_str(&env, ""), // Empty name
         &token_contract,
@@ -495,7 +865,7 @@ fn test_initialize_invalid_institution_name() {
 
 #[test]
 #[should_panic(expected = "base salary must be positive")]
-fn test_initialize_invalid_base_salary() {
+fn test_register_invalid_base_salary() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -503,7 +873,8 @@ fn test_initialize_invalid_base_salary() {
     let token_contract = create_mock_token_contract(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -523,7 +894,8 @@ fn test_suspend_already_suspended_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -532,15 +904,16 @@ fn test_suspend_already_suspended_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
-    contract.suspend_employee(&employee_id);
+    contract.suspend_employee(&institution_id, &employee_id);
     // Should panic when trying to suspend again
-    contract.suspend_employee(&employee_id);
+    contract.suspend_employee(&institution_id, &employee_id);
 }
 
 #[test]
@@ -554,7 +927,8 @@ fn test_reactivate_already_active_employee() {
     let employee_addr = Address::generate(&env);
     let contract = create_employee_management_contract(&env);
 
-    contract.initialize(
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
         &admin,
         &String::from_str(&env, "Tech Corp"),
         &token_contract,
@@ -563,12 +937,1455 @@ fn test_reactivate_already_active_employee() {
     );
 
     let employee_id = contract.add_employee(
+        &institution_id,
         &employee_addr,
         &String::from_str(&env, "John Doe"),
-        &EmployeeRank::Junior,
+        &EmployeeRank::Junior.default_id(),
         &String::from_str(&env, "Engineering"),
     );
 
     // Should panic when trying to reactivate an already active employee
-    contract.reactivate_employee(&employee_id);
+    contract.reactivate_employee(&institution_id, &employee_id);
+}
+
+#[test]
+fn test_promotion_proposal_executes_at_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) =
+        setup_approval_institution(&env, &contract);
+
+    advance_ledger(&env, 101);
+    submit_scores(&env, &contract, institution_id, employee_id, &[75, 75]);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Promote,
+    );
+    assert_eq!(last_event_name(&env), String::from_str(&env, "proposal_created"));
+
+    let proposal = contract.get_proposal(&institution_id, &proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.deadline, proposal.created_at + 50);
+
+    // First approval only records the vote
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "proposal_approved"));
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).rank_id, EmployeeRank::Junior.default_id());
+
+    // Second approval reaches quorum and executes the promotion, signed by the lead alone
+    contract.approve_proposal(&institution_id, &lead, &proposal_id);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "proposal_executed"));
+    let args = (institution_id, lead.clone(), proposal_id).into_val(&env);
+    assert_eq!(
+        env.auths(),
+        std::vec![contract_auth(&env, &contract, &lead, "approve_proposal", args)]
+    );
+
+    let proposal = contract.get_proposal(&institution_id, &proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.approvals, vec![&env, hr, lead]);
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).rank_id, EmployeeRank::Mid.default_id());
+}
+
+#[test]
+fn test_termination_and_suspension_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) =
+        setup_approval_institution(&env, &contract);
+
+    let suspension = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+    contract.approve_proposal(&institution_id, &hr, &suspension);
+    contract.approve_proposal(&institution_id, &lead, &suspension);
+    assert_eq!(
+        contract.get_employee(&institution_id, &employee_id).status,
+        EmployeeStatus::Suspended
+    );
+
+    let termination = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Terminate,
+    );
+    contract.approve_proposal(&institution_id, &lead, &termination);
+    contract.approve_proposal(&institution_id, &hr, &termination);
+
+    // Terminated employees keep their record
+    let employee = contract.get_employee(&institution_id, &employee_id);
+    assert_eq!(employee.status, EmployeeStatus::Terminated);
+    assert_eq!(contract.get_employee_count(&institution_id), 1);
+}
+
+#[test]
+#[should_panic(expected = "action requires approval")]
+fn test_direct_removal_blocked_by_approval_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, _, _, _) = setup_approval_institution(&env, &contract);
+
+    // The institution admin alone can no longer fire an employee
+    contract.remove_employee(&institution_id, &employee_id);
+}
+
+#[test]
+#[should_panic(expected = "proposal expired")]
+fn test_approve_expired_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, _) = setup_approval_institution(&env, &contract);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+
+    advance_ledger(&env, 51);
+
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "proposal already approved by this approver")]
+fn test_approve_proposal_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, _) = setup_approval_institution(&env, &contract);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "not an authorized proposer")]
+fn test_unauthorized_proposer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, _, hr, _) = setup_approval_institution(&env, &contract);
+
+    contract.propose_action(&institution_id, &hr, &employee_id, &ProposalAction::Terminate);
+}
+
+#[test]
+fn test_proposer_who_approves_needs_other_approvers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    // HR both proposes and approves, so only the lead can approve its proposals
+    let policy = ApprovalPolicy {
+        proposers: vec![&env, manager.clone(), hr.clone()],
+        approvers: vec![&env, hr.clone(), lead.clone()],
+        quorum: 1,
+        approval_window: 50,
+    };
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &0,
+        &ProposalAction::SetPolicy(policy.clone()),
+    );
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    contract.approve_proposal(&institution_id, &lead, &proposal_id);
+    assert_eq!(contract.get_approval_policy(&institution_id), Some(policy));
+
+    let proposal_id = contract.propose_action(&institution_id, &hr, &employee_id, &ProposalAction::Suspend);
+    contract.approve_proposal(&institution_id, &lead, &proposal_id);
+    assert_eq!(contract.get_proposal(&institution_id, &proposal_id).status, ProposalStatus::Executed);
+}
+
+#[test]
+#[should_panic(expected = "quorum must be reachable without the proposer")]
+fn test_quorum_unreachable_without_proposer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, _, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    let policy = ApprovalPolicy {
+        proposers: vec![&env, hr.clone()],
+        approvers: vec![&env, hr, lead],
+        quorum: 2,
+        approval_window: 50,
+    };
+    contract.propose_action(&institution_id, &manager, &0, &ProposalAction::SetPolicy(policy));
+}
+
+#[test]
+#[should_panic(expected = "duplicate approver")]
+fn test_approval_policy_with_duplicate_approvers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &create_mock_token_contract(&env),
+        &1000_0000000,
+        &100,
+    );
+
+    // Should panic: one approver listed twice would meet a quorum of 2 alone
+    contract.set_approval_policy(
+        &institution_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, hr.clone(), hr],
+        &2,
+        &50,
+    );
+}
+
+#[test]
+#[should_panic(expected = "action requires approval")]
+fn test_replace_approval_policy_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, _, manager, hr, _) = setup_approval_institution(&env, &contract);
+
+    // The institution admin alone can't swap in a weaker policy
+    contract.set_approval_policy(
+        &institution_id,
+        &vec![&env, manager],
+        &vec![&env, hr],
+        &1,
+        &50,
+    );
+}
+
+#[test]
+fn test_remove_approval_policy_by_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &0,
+        &ProposalAction::RemovePolicy,
+    );
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    assert!(contract.get_approval_policy(&institution_id).is_some());
+
+    contract.approve_proposal(&institution_id, &lead, &proposal_id);
+    assert_eq!(contract.get_approval_policy(&institution_id), None);
+
+    // HR actions are back with the institution admin
+    contract.remove_employee(&institution_id, &employee_id);
+    assert_eq!(contract.get_employee_count(&institution_id), 0);
+}
+
+#[test]
+fn test_single_approver_cannot_change_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, _, manager, hr, lead) = setup_approval_institution(&env, &contract);
+    let policy = contract.get_approval_policy(&institution_id).unwrap();
+
+    let weaker = ApprovalPolicy {
+        proposers: vec![&env, manager.clone()],
+        approvers: vec![&env, hr.clone()],
+        quorum: 1,
+        approval_window: 50,
+    };
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &0,
+        &ProposalAction::SetPolicy(weaker.clone()),
+    );
+
+    // HR's own approval is recorded but doesn't reach the quorum
+    let args = (institution_id, hr.clone(), proposal_id).into_val(&env);
+    mock_single_auth(&env, &contract, &hr, "approve_proposal", args);
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    assert_eq!(contract.get_approval_policy(&institution_id), Some(policy.clone()));
+
+    // HR can't cast the lead's vote
+    let args = (institution_id, lead.clone(), proposal_id).into_val(&env);
+    mock_single_auth(&env, &contract, &hr, "approve_proposal", args);
+    assert!(contract.try_approve_proposal(&institution_id, &lead, &proposal_id).is_err());
+
+    // The institution admin can't set the policy directly either
+    let admin = contract.get_institution(&institution_id).admin;
+    let args = (
+        institution_id,
+        weaker.proposers.clone(),
+        weaker.approvers.clone(),
+        weaker.quorum,
+        weaker.approval_window,
+    )
+        .into_val(&env);
+    mock_single_auth(&env, &contract, &admin, "set_approval_policy", args);
+    let result = contract.try_set_approval_policy(
+        &institution_id,
+        &weaker.proposers,
+        &weaker.approvers,
+        &weaker.quorum,
+        &weaker.approval_window,
+    );
+    assert!(result.is_err());
+
+    assert_eq!(contract.get_approval_policy(&institution_id), Some(policy));
+    assert_eq!(
+        contract.get_proposal(&institution_id, &proposal_id).status,
+        ProposalStatus::Pending
+    );
+}
+
+#[test]
+fn test_pending_proposal_follows_policy_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+    let ops = Address::generate(&env);
+
+    let suspension = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+    contract.approve_proposal(&institution_id, &hr, &suspension);
+
+    // HR is replaced as an approver while the suspension is pending
+    let policy = ApprovalPolicy {
+        proposers: vec![&env, manager.clone()],
+        approvers: vec![&env, lead.clone(), ops.clone()],
+        quorum: 2,
+        approval_window: 50,
+    };
+    let change = contract.propose_action(&institution_id, &manager, &0, &ProposalAction::SetPolicy(policy));
+    contract.approve_proposal(&institution_id, &hr, &change);
+    contract.approve_proposal(&institution_id, &lead, &change);
+
+    // HR's earlier approval no longer counts towards the quorum
+    contract.approve_proposal(&institution_id, &lead, &suspension);
+    assert_eq!(
+        contract.get_proposal(&institution_id, &suspension).status,
+        ProposalStatus::Pending
+    );
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).status, EmployeeStatus::Active);
+
+    contract.approve_proposal(&institution_id, &ops, &suspension);
+    assert_eq!(
+        contract.get_proposal(&institution_id, &suspension).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).status, EmployeeStatus::Suspended);
+}
+
+#[test]
+fn test_pending_proposal_needs_raised_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+    let ops = Address::generate(&env);
+
+    let termination = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Terminate,
+    );
+
+    // The quorum rises from 2 to 3 while the termination is pending
+    let policy = ApprovalPolicy {
+        proposers: vec![&env, manager.clone()],
+        approvers: vec![&env, hr.clone(), lead.clone(), ops.clone()],
+        quorum: 3,
+        approval_window: 50,
+    };
+    let change = contract.propose_action(&institution_id, &manager, &0, &ProposalAction::SetPolicy(policy));
+    contract.approve_proposal(&institution_id, &hr, &change);
+    contract.approve_proposal(&institution_id, &lead, &change);
+
+    contract.approve_proposal(&institution_id, &hr, &termination);
+    contract.approve_proposal(&institution_id, &lead, &termination);
+    let proposal = contract.get_proposal(&institution_id, &termination);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.quorum, 3);
+
+    contract.approve_proposal(&institution_id, &ops, &termination);
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).status, EmployeeStatus::Terminated);
+}
+
+#[test]
+fn test_single_approver_cannot_reactivate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    let suspension = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+    contract.approve_proposal(&institution_id, &hr, &suspension);
+    contract.approve_proposal(&institution_id, &lead, &suspension);
+
+    let reactivation = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Reactivate,
+    );
+
+    // The lead's own approval is recorded but doesn't reach the quorum
+    let args = (institution_id, lead.clone(), reactivation).into_val(&env);
+    mock_single_auth(&env, &contract, &lead, "approve_proposal", args);
+    contract.approve_proposal(&institution_id, &lead, &reactivation);
+
+    // The lead can't cast HR's vote
+    let args = (institution_id, hr.clone(), reactivation).into_val(&env);
+    mock_single_auth(&env, &contract, &lead, "approve_proposal", args);
+    assert!(contract.try_approve_proposal(&institution_id, &hr, &reactivation).is_err());
+
+    // The institution admin can't reactivate directly either
+    let admin = contract.get_institution(&institution_id).admin;
+    let args = (institution_id, employee_id).into_val(&env);
+    mock_single_auth(&env, &contract, &admin, "reactivate_employee", args);
+    assert!(contract.try_reactivate_employee(&institution_id, &employee_id).is_err());
+
+    assert_eq!(
+        contract.get_employee(&institution_id, &employee_id).status,
+        EmployeeStatus::Suspended
+    );
+}
+
+#[test]
+fn test_reactivate_employee_by_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    for action in [ProposalAction::Suspend, ProposalAction::Reactivate] {
+        let proposal_id = contract.propose_action(&institution_id, &manager, &employee_id, &action);
+        contract.approve_proposal(&institution_id, &hr, &proposal_id);
+        contract.approve_proposal(&institution_id, &lead, &proposal_id);
+    }
+
+    assert_eq!(
+        contract.get_employee(&institution_id, &employee_id).status,
+        EmployeeStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "action requires approval")]
+fn test_direct_reactivation_blocked_by_approval_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, lead) = setup_approval_institution(&env, &contract);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Suspend,
+    );
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+    contract.approve_proposal(&institution_id, &lead, &proposal_id);
+
+    // Should panic: the admin alone can't undo an approved suspension
+    contract.reactivate_employee(&institution_id, &employee_id);
+}
+
+#[test]
+#[should_panic(expected = "proposal is not pending")]
+fn test_cancel_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    let (institution_id, employee_id, manager, hr, _) = setup_approval_institution(&env, &contract);
+
+    let proposal_id = contract.propose_action(
+        &institution_id,
+        &manager,
+        &employee_id,
+        &ProposalAction::Terminate,
+    );
+
+    contract.cancel_proposal(&institution_id, &proposal_id);
+    assert_eq!(
+        contract.get_proposal(&institution_id, &proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+
+    // Should panic when approving a cancelled proposal
+    contract.approve_proposal(&institution_id, &hr, &proposal_id);
+}
+
+#[test]
+fn test_leave_entitlements() {
+    assert_eq!(EmployeeRank::Intern.annual_leave_days(), 10);
+    assert_eq!(EmployeeRank::Senior.annual_leave_days(), 20);
+    assert_eq!(EmployeeRank::CEO.annual_leave_days(), 30);
+    assert_eq!(EmployeeRank::Intern.sick_leave_days(), 5);
+    assert_eq!(EmployeeRank::Manager.sick_leave_days(), 10);
+}
+
+#[test]
+fn test_rolling_average_score() {
+    let env = Env::default();
+    let reviewer = Address::generate(&env);
+    let mut reviews = vec![&env];
+    let review = |period: u64, score: u32| PerformanceReview {
+        period,
+        score,
+        reviewer: reviewer.clone(),
+        submitted_at: 0,
+    };
+
+    assert_eq!(average_score(&reviews), None);
+    reviews.push_back(review(1, 40));
+    assert_eq!(average_score(&reviews), None); // too few reviews
+    reviews.push_back(review(2, 60));
+    assert_eq!(average_score(&reviews), Some(50));
+
+    // Only the most recent PERFORMANCE_WINDOW scores count
+    reviews.push_back(review(3, 80));
+    reviews.push_back(review(4, 80));
+    reviews.push_back(review(5, 100));
+    assert_eq!(average_score(&reviews), Some(80));
+
+    assert_eq!(EmployeeRank::Intern.promotion_score_threshold(), 60);
+    assert_eq!(EmployeeRank::VP.promotion_score_threshold(), 90);
+}
+
+#[test]
+fn test_request_and_approve_leave() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    let start = env.ledger().sequence() as u64 + 10;
+    let year = leave_year(start);
+
+    let sick_start = start + 5 * LEDGERS_PER_DAY;
+    let annual = contract.request_leave(&institution_id, &employee_id, &LeaveType::Annual, &start, &5);
+    let sick = contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &sick_start, &2);
+
+    let request = contract.get_leave_request(&institution_id, &annual);
+    assert_eq!(request.status, LeaveStatus::Pending);
+    assert_eq!(request.year, year);
+
+    // Pending requests do not consume the balance
+    let balance = contract.get_leave_balance(&institution_id, &employee_id, &year);
+    assert_eq!(balance.annual_entitlement, 15);
+    assert_eq!(balance.annual_used, 0);
+
+    contract.approve_leave(&institution_id, &annual);
+    contract.reject_leave(&institution_id, &sick);
+
+    assert_eq!(contract.get_leave_request(&institution_id, &annual).status, LeaveStatus::Approved);
+    assert_eq!(contract.get_leave_request(&institution_id, &sick).status, LeaveStatus::Rejected);
+
+    let balance = contract.get_leave_balance(&institution_id, &employee_id, &year);
+    assert_eq!(balance.annual_used, 5);
+    assert_eq!(balance.remaining(&LeaveType::Annual), Some(10));
+    assert_eq!(balance.sick_used, 0);
+    assert_eq!(balance.remaining(&LeaveType::Sick), Some(10));
+
+    // Usage is tracked per leave year
+    let next_year = contract.get_leave_balance(&institution_id, &employee_id, &(year + 1));
+    assert_eq!(next_year.annual_used, 0);
+}
+
+#[test]
+#[should_panic(expected = "insufficient leave balance")]
+fn test_request_leave_beyond_entitlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Intern.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    let start = env.ledger().sequence() as u64;
+    let first = contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &start, &4);
+    let second_start = start + 4 * LEDGERS_PER_DAY;
+    let second = contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &second_start, &1);
+    let third_start = start + 5 * LEDGERS_PER_DAY;
+    let third = contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &third_start, &1);
+    contract.approve_leave(&institution_id, &first);
+    contract.approve_leave(&institution_id, &second);
+
+    // Intern sick leave (5 days) is exhausted
+    contract.approve_leave(&institution_id, &third);
+}
+
+#[test]
+#[should_panic(expected = "leave overlaps another request")]
+fn test_request_overlapping_leave() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    let start = env.ledger().sequence() as u64;
+    let annual = contract.request_leave(&institution_id, &employee_id, &LeaveType::Annual, &start, &3);
+    contract.approve_leave(&institution_id, &annual);
+
+    // Back-to-back and rejected leave don't overlap
+    let next_start = start + 3 * LEDGERS_PER_DAY;
+    let rejected = contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &next_start, &2);
+    contract.reject_leave(&institution_id, &rejected);
+    contract.request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &next_start, &2);
+
+    // Should panic: the last day falls inside the approved annual leave
+    contract.request_leave(&institution_id, &employee_id, &LeaveType::Sick, &(start + 2 * LEDGERS_PER_DAY), &1);
+}
+
+#[test]
+fn test_unpaid_leave_deducted_from_salary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    keep_entries_live(&env, 4 * LEDGERS_PER_SALARY_PERIOD as u32);
+
+    let admin = Address::generate(&env);
+    let employee_addr = Address::generate(&env);
+    let (salary_token, salary_admin) = create_deposit_token(&env);
+    let salary_client = TokenClient::new(&env, &salary_token);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &salary_token,
+        &1500_0000000,
+        &100,
+    );
+    salary_admin.mint(&admin, &10000_0000000);
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    // 40 days from the start of period 0 run 10 days into period 1
+    let start = env.ledger().sequence() as u64;
+    let request = contract.request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &start, &40);
+    contract.approve_leave(&institution_id, &request);
+    assert_eq!(contract.get_unpaid_leave_days(&institution_id, &employee_id, &0), 30);
+    assert_eq!(contract.get_unpaid_leave_days(&institution_id, &employee_id, &1), 10);
+
+    // Leave in a later period is not deducted before then, whatever its length
+    let start = 3 * LEDGERS_PER_SALARY_PERIOD - 2 * LEDGERS_PER_DAY;
+    let request = contract.request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &start, &5);
+    contract.approve_leave(&institution_id, &request);
+    assert_eq!(contract.get_unpaid_leave_days(&institution_id, &employee_id, &2), 2);
+    assert_eq!(contract.get_unpaid_leave_days(&institution_id, &employee_id, &3), 3);
+
+    // A full period of unpaid leave
+    contract.pay_salary(&institution_id, &employee_id);
+    assert_eq!(salary_client.balance(&employee_addr), 0);
+
+    // 10 of 30 days unpaid: 3000 * 20 / 30
+    advance_ledger(&env, LEDGERS_PER_SALARY_PERIOD as u32);
+    contract.pay_all_salaries(&institution_id);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000);
+
+    // 2 of 30 days unpaid: 3000 * 28 / 30
+    advance_ledger(&env, LEDGERS_PER_SALARY_PERIOD as u32);
+    contract.pay_salary(&institution_id, &employee_id);
+    assert_eq!(salary_client.balance(&employee_addr), 4800_0000000);
+}
+
+#[test]
+fn test_unpaid_leave_in_paid_period_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    keep_entries_live(&env, 2 * LEDGERS_PER_SALARY_PERIOD as u32);
+
+    let admin = Address::generate(&env);
+    let employee_addr = Address::generate(&env);
+    let (salary_token, salary_admin) = create_deposit_token(&env);
+    let salary_client = TokenClient::new(&env, &salary_token);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &salary_token,
+        &1000_0000000,
+        &100,
+    );
+    salary_admin.mint(&admin, &10000_0000000);
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    let start = env.ledger().sequence() as u64;
+    let current = contract.request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &start, &5);
+    let next_start = LEDGERS_PER_SALARY_PERIOD;
+    let next = contract.request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &next_start, &3);
+
+    // The period is paid in full before the leave in it is approved
+    contract.pay_salary(&institution_id, &employee_id);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000);
+
+    assert!(contract.try_approve_leave(&institution_id, &current).is_err());
+    assert_eq!(contract.get_leave_request(&institution_id, &current).status, LeaveStatus::Pending);
+    assert_eq!(contract.get_unpaid_leave_days(&institution_id, &employee_id, &0), 0);
+
+    // Nor can new unpaid leave be requested in it, but other leave can
+    let later = start + 10 * LEDGERS_PER_DAY;
+    assert!(contract.try_request_leave(&institution_id, &employee_id, &LeaveType::Unpaid, &later, &1).is_err());
+    contract.request_leave(&institution_id, &employee_id, &LeaveType::Annual, &later, &1);
+
+    // Leave in the next period is still deducted: 2000 * 27 / 30
+    contract.approve_leave(&institution_id, &next);
+    advance_ledger(&env, LEDGERS_PER_SALARY_PERIOD as u32);
+    contract.pay_salary(&institution_id, &employee_id);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000 + 1800_0000000);
+}
+
+#[test]
+#[should_panic(expected = "employee not eligible for promotion")]
+fn test_promotion_requires_performance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Senior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    // Tenure alone is not enough: 72 is below the Senior threshold of 75
+    advance_ledger(&env, 101);
+    submit_scores(&env, &contract, institution_id, employee_id, &[70, 74]);
+    assert_eq!(contract.get_average_score(&institution_id, &employee_id), Some(72));
+
+    contract.promote_employee(&institution_id, &employee_id);
+}
+
+#[test]
+#[should_panic(expected = "limit exceeds the maximum scan size")]
+fn test_promotion_candidates_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_employee_management_contract(&env);
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &Address::generate(&env),
+        &String::from_str(&env, "Tech Corp"),
+        &create_mock_token_contract(&env),
+        &1000_0000000,
+        &100,
+    );
+
+    contract.promotion_candidates(&institution_id, &1, &(MAX_CANDIDATE_SCAN + 1));
+}
+
+#[test]
+fn test_promotion_candidates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+    contract.set_reviewers(&institution_id, &vec![&env, reviewer.clone()]);
+
+    let mut employee_ids = [0u64; 3];
+    for (i, rank) in [EmployeeRank::Junior, EmployeeRank::Junior, EmployeeRank::Director]
+        .iter()
+        .enumerate()
+    {
+        employee_ids[i] = contract.add_employee(
+            &institution_id,
+            &Address::generate(&env),
+            &String::from_str(&env, "Employee"),
+            &rank.default_id(),
+            &String::from_str(&env, "Engineering"),
+        );
+    }
+
+    // Same scores pass the Junior threshold but not the Director one
+    for employee_id in employee_ids.iter() {
+        contract.submit_performance_score(&institution_id, &reviewer, employee_id, &1, &80);
+        contract.submit_performance_score(&institution_id, &reviewer, employee_id, &2, &80);
+    }
+    contract.suspend_employee(&institution_id, &employee_ids[1]);
+
+    assert_eq!(contract.promotion_candidates(&institution_id, &1, &10).len(), 0);
+
+    advance_ledger(&env, 101);
+
+    let candidates = contract.promotion_candidates(&institution_id, &1, &10);
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates.get(0).unwrap().id, employee_ids[0]);
+
+    // Pages only scan their own ID range
+    assert_eq!(contract.promotion_candidates(&institution_id, &1, &1).len(), 1);
+    assert_eq!(contract.promotion_candidates(&institution_id, &2, &10).len(), 0);
+
+    assert_eq!(contract.get_performance_reviews(&institution_id, &employee_ids[0]).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "not an authorized reviewer")]
+fn test_unauthorized_reviewer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    // Employees are not reviewers unless appointed
+    contract.submit_performance_score(&institution_id, &employee_addr, &employee_id, &1, &100);
+}
+
+fn rank_config(env: &Env, id: u32, name: &str, multiplier: u32, min_tenure: u64) -> RankConfig {
+    RankConfig {
+        id,
+        name: String::from_str(env, name),
+        salary_multiplier: multiplier,
+        min_tenure,
+        promotion_score_threshold: 0,
+        annual_leave_days: 20,
+        sick_leave_days: 10,
+    }
+}
+
+#[test]
+fn test_custom_rank_ladder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Hospital"),
+        &token_contract,
+        &1000_0000000,
+        &10,
+    );
+
+    // New institutions start on the default ladder
+    assert_eq!(contract.get_rank_ladder(&institution_id).len(), 9);
+
+    let ladder = vec![
+        &env,
+        rank_config(&env, 100, "Resident", 2, 0),
+        rank_config(&env, 200, "Attending", 4, 50),
+        rank_config(&env, 300, "Chief", 8, 0),
+    ];
+    contract.set_rank_ladder(&institution_id, &ladder);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "rank_ladder_set"));
+    assert_eq!(contract.get_rank_ladder(&institution_id), ladder);
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "Dr. Doe"),
+        &100,
+        &String::from_str(&env, "Surgery"),
+    );
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 2000_0000000);
+    submit_scores(&env, &contract, institution_id, employee_id, &[70, 70]);
+
+    advance_ledger(&env, 11);
+    contract.promote_employee(&institution_id, &employee_id);
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).rank_id, 200);
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 4000_0000000);
+
+    // Attending requires 50 ledgers in rank, more than the institution's interval
+    advance_ledger(&env, 11);
+    assert_eq!(contract.promotion_candidates(&institution_id, &1, &10).len(), 0);
+
+    advance_ledger(&env, 40);
+    contract.promote_employee(&institution_id, &employee_id);
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).rank_id, 300);
+    assert_eq!(contract.get_employee_salary(&institution_id, &employee_id), 8000_0000000);
+}
+
+#[test]
+fn test_rank_ladder_tracks_ranks_in_use() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let mut employee_ids = [0u64; 2];
+    for employee_id in employee_ids.iter_mut() {
+        *employee_id = contract.add_employee(
+            &institution_id,
+            &Address::generate(&env),
+            &String::from_str(&env, "John Doe"),
+            &EmployeeRank::Junior.default_id(),
+            &String::from_str(&env, "Engineering"),
+        );
+    }
+
+    // One Junior is promoted to Mid and the other removed, so only Mid is in use
+    advance_ledger(&env, 101);
+    submit_scores(&env, &contract, institution_id, employee_ids[0], &[75, 75]);
+    contract.promote_employee(&institution_id, &employee_ids[0]);
+    contract.remove_employee(&institution_id, &employee_ids[1]);
+
+    let ladder = vec![&env, EmployeeRank::Mid.default_config(&env)];
+    contract.set_rank_ladder(&institution_id, &ladder);
+    assert_eq!(contract.get_rank_ladder(&institution_id), ladder);
+}
+
+#[test]
+#[should_panic(expected = "rank in use missing from ladder")]
+fn test_rank_ladder_keeps_ranks_in_use() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    contract.add_employee(
+        &institution_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    contract.set_rank_ladder(&institution_id, &vec![&env, rank_config(&env, 100, "Staff", 2, 0)]);
+}
+
+#[test]
+fn test_set_deposit_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let platform_admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let (old_token, old_admin) = create_deposit_token(&env);
+    let (new_token, new_admin) = create_deposit_token(&env);
+    let contract = create_employee_management_contract(&env);
+
+    contract.initialize(&platform_admin, &old_token, &50_0000000);
+    let admin = Address::generate(&env);
+    old_admin.mint(&admin, &50_0000000);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    // Once the deposit is refunded the token can be replaced
+    contract.close_institution(&institution_id);
+    contract.set_deposit_token(&new_token);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "deposit_token_set"));
+    assert_eq!(contract.get_deposit_token(), Some(new_token.clone()));
+
+    new_admin.mint(&admin, &50_0000000);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+    assert_eq!(contract.get_institution(&institution_id).deposit, 50_0000000);
+    assert_eq!(TokenClient::new(&env, &new_token).balance(&contract.address), 50_0000000);
+}
+
+#[test]
+#[should_panic(expected = "deposits are held in the current token")]
+fn test_set_deposit_token_while_deposits_held() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let platform_admin = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let (deposit_token, deposit_admin) = create_deposit_token(&env);
+    let contract = create_employee_management_contract(&env);
+
+    contract.initialize(&platform_admin, &deposit_token, &50_0000000);
+    deposit_admin.mint(&admin, &50_0000000);
+    contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &create_mock_token_contract(&env),
+        &1000_0000000,
+        &100,
+    );
+
+    // Should panic: the deposit must be refunded in the token it was paid in
+    contract.set_deposit_token(&create_mock_token_contract(&env));
+}
+
+#[test]
+fn test_update_my_profile_and_rotate_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let old_addr = Address::generate(&env);
+    let new_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &old_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    contract.update_my_profile(&institution_id, &old_addr, &String::from_str(&env, "John Smith"));
+    assert_eq!(env.auths()[0].0, old_addr);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "employee_updated"));
+
+    // Both the old and the new key sign the rotation
+    let args: Vec<Val> = (institution_id, old_addr.clone(), new_addr.clone()).into_val(&env);
+    mock_single_auth(&env, &contract, &old_addr, "rotate_address", args.clone());
+    assert!(contract.try_rotate_address(&institution_id, &old_addr, &new_addr).is_err());
+
+    env.mock_all_auths();
+    contract.rotate_address(&institution_id, &old_addr, &new_addr);
+    assert_eq!(
+        env.auths(),
+        std::vec![
+            contract_auth(&env, &contract, &old_addr, "rotate_address", args.clone()),
+            contract_auth(&env, &contract, &new_addr, "rotate_address", args),
+        ]
+    );
+    assert_eq!(last_event_name(&env), String::from_str(&env, "employee_address_rotated"));
+
+    let employee = contract.get_employee(&institution_id, &employee_id);
+    assert_eq!(employee.address, new_addr);
+    assert_eq!(employee.name, String::from_str(&env, "John Smith"));
+    assert_eq!(employee.rank_id, EmployeeRank::Junior.default_id());
+    assert_eq!(contract.get_employee_by_address(&institution_id, &old_addr), None);
+    assert_eq!(contract.get_employee_by_address(&institution_id, &new_addr), Some(employee_id));
+
+    // The old key can be reused for a new hire
+    contract.add_employee(
+        &institution_id,
+        &old_addr,
+        &String::from_str(&env, "Jane Doe"),
+        &EmployeeRank::Intern.default_id(),
+        &String::from_str(&env, "Finance"),
+    );
+    assert_eq!(contract.get_employee_count(&institution_id), 2);
+}
+
+#[test]
+#[should_panic(expected = "address is already an employee")]
+fn test_rotate_address_to_existing_employee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let first_addr = Address::generate(&env);
+    let second_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    for addr in [&first_addr, &second_addr] {
+        contract.add_employee(
+            &institution_id,
+            addr,
+            &String::from_str(&env, "Employee"),
+            &EmployeeRank::Junior.default_id(),
+            &String::from_str(&env, "Engineering"),
+        );
+    }
+
+    contract.rotate_address(&institution_id, &first_addr, &second_addr);
+}
+
+#[test]
+fn test_resignation_settles_final_pay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    keep_entries_live(&env, 2 * LEDGERS_PER_SALARY_PERIOD as u32);
+
+    let admin = Address::generate(&env);
+    let employee_addr = Address::generate(&env);
+    let (salary_token, salary_admin) = create_deposit_token(&env);
+    let salary_client = TokenClient::new(&env, &salary_token);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &salary_token,
+        &1000_0000000,
+        &100,
+    );
+    salary_admin.mint(&admin, &10000_0000000);
+    assert_eq!(contract.get_notice_period(&institution_id), DEFAULT_NOTICE_PERIOD);
+    contract.set_notice_period(&institution_id, &(45 * LEDGERS_PER_DAY));
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    advance_ledger(&env, 10);
+    let effective_at = contract.resign(&institution_id, &employee_addr);
+    assert_eq!(effective_at, 10 + 45 * LEDGERS_PER_DAY);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "resignation_submitted"));
+    assert_eq!(contract.get_resignation(&institution_id, &employee_id).unwrap().submitted_at, 10);
+
+    // Still employed and paid for the first period during the notice period
+    contract.pay_salary(&institution_id, &employee_id);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000);
+
+    // The employee finalizes with no allowance from the admin; the final pay is only recorded
+    advance_ledger(&env, effective_at as u32 - 10);
+    let args: Vec<Val> = (institution_id, employee_addr.clone(), employee_id).into_val(&env);
+    mock_single_auth(&env, &contract, &employee_addr, "finalize_resignation", args.clone());
+    contract.finalize_resignation(&institution_id, &employee_addr, &employee_id);
+    assert_eq!(
+        env.auths(),
+        std::vec![contract_auth(&env, &contract, &employee_addr, "finalize_resignation", args)]
+    );
+    assert_eq!(last_event_name(&env), String::from_str(&env, "employee_status_changed"));
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).status, EmployeeStatus::Terminated);
+    assert_eq!(contract.get_resignation(&institution_id, &employee_id), None);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000);
+
+    // Owed for the 15 days and 10 ledgers worked into the second period
+    let final_pay = contract.get_final_pay(&institution_id, &employee_id).unwrap();
+    assert_eq!(final_pay.amount, 1000_0385802);
+    assert_eq!(final_pay.unpaid_leave_days, 0);
+
+    env.mock_all_auths();
+    contract.pay_final_salary(&institution_id, &employee_id);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(last_event_name(&env), String::from_str(&env, "salary_paid"));
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000 + 1000_0385802);
+    assert_eq!(contract.get_final_pay(&institution_id, &employee_id), None);
+}
+
+#[test]
+fn test_final_pay_skips_paid_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let employee_addr = Address::generate(&env);
+    let (salary_token, salary_admin) = create_deposit_token(&env);
+    let salary_client = TokenClient::new(&env, &salary_token);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &salary_token,
+        &1000_0000000,
+        &100,
+    );
+    salary_admin.mint(&admin, &10000_0000000);
+    contract.set_notice_period(&institution_id, &50);
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    // The resignation takes effect within the period that was already paid
+    contract.resign(&institution_id, &employee_addr);
+    contract.pay_salary(&institution_id, &employee_id);
+    advance_ledger(&env, 50);
+    contract.finalize_resignation(&institution_id, &admin, &employee_id);
+    assert_eq!(env.auths()[0].0, admin);
+
+    assert_eq!(contract.get_final_pay(&institution_id, &employee_id), None);
+    assert_eq!(salary_client.balance(&employee_addr), 2000_0000000);
+    assert_eq!(contract.get_employee(&institution_id, &employee_id).status, EmployeeStatus::Terminated);
+}
+
+#[test]
+#[should_panic(expected = "caller must be the employee or the institution admin")]
+fn test_finalize_resignation_by_stranger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+    contract.set_notice_period(&institution_id, &50);
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    contract.resign(&institution_id, &employee_addr);
+    advance_ledger(&env, 50);
+    contract.finalize_resignation(&institution_id, &stranger, &employee_id);
+}
+
+#[test]
+#[should_panic(expected = "notice period has not ended")]
+fn test_finalize_resignation_during_notice_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_contract = create_mock_token_contract(&env);
+    let employee_addr = Address::generate(&env);
+    let contract = create_employee_management_contract(&env);
+
+    initialize_platform(&env, &contract);
+    let institution_id = contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token_contract,
+        &1000_0000000,
+        &100,
+    );
+
+    let employee_id = contract.add_employee(
+        &institution_id,
+        &employee_addr,
+        &String::from_str(&env, "John Doe"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+
+    contract.resign(&institution_id, &employee_addr);
+    advance_ledger(&env, 100);
+    contract.finalize_resignation(&institution_id, &employee_addr, &employee_id);
 }
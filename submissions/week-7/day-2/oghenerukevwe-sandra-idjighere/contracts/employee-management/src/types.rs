@@ -1,6 +1,38 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
-/// Employee rank hierarchy
+/// Ledger sequences in a leave year (~5 second ledgers)
+pub const LEDGERS_PER_YEAR: u64 = 6_307_200;
+
+/// Ledger sequences in a day (~5 second ledgers)
+pub const LEDGERS_PER_DAY: u64 = 17_280;
+
+/// Days covered by one salary payment, used to prorate unpaid leave
+pub const DAYS_PER_SALARY_PERIOD: u32 = 30;
+
+/// Ledger sequences in a pay period
+pub const LEDGERS_PER_SALARY_PERIOD: u64 = LEDGERS_PER_DAY * DAYS_PER_SALARY_PERIOD as u64;
+
+/// Default resignation notice period in ledger sequences (~30 days)
+pub const DEFAULT_NOTICE_PERIOD: u64 = 518_400;
+
+/// Number of most recent performance scores in the rolling average
+pub const PERFORMANCE_WINDOW: u32 = 4;
+
+/// Minimum number of performance scores before an employee can be promoted
+pub const MIN_PERFORMANCE_REVIEWS: u32 = 2;
+
+/// Highest performance score a reviewer can submit
+pub const MAX_PERFORMANCE_SCORE: u32 = 100;
+
+/// Most employee IDs one `promotion_candidates` call can scan; each ID reads
+/// the employee and their reviews, so a page stays within the transaction's
+/// ledger read limit
+pub const MAX_CANDIDATE_SCAN: u32 = 15;
+
+/// Default employee rank hierarchy
+///
+/// Institutions start with this ladder and can replace it with their own
+/// `RankConfig` ladder. Employees store the rank ID, see `EmployeeRank::default_id`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum EmployeeRank {
@@ -16,6 +48,62 @@ pub enum EmployeeRank {
 }
 
 impl EmployeeRank {
+    /// All ranks of the default ladder, lowest first
+    pub const ALL: [EmployeeRank; 9] = [
+        EmployeeRank::Intern,
+        EmployeeRank::Junior,
+        EmployeeRank::Mid,
+        EmployeeRank::Senior,
+        EmployeeRank::Lead,
+        EmployeeRank::Manager,
+        EmployeeRank::Director,
+        EmployeeRank::VP,
+        EmployeeRank::CEO,
+    ];
+
+    /// Get the rank ID of each rank in the default ladder
+    pub fn default_id(&self) -> u32 {
+        match self {
+            EmployeeRank::Intern => 1,
+            EmployeeRank::Junior => 2,
+            EmployeeRank::Mid => 3,
+            EmployeeRank::Senior => 4,
+            EmployeeRank::Lead => 5,
+            EmployeeRank::Manager => 6,
+            EmployeeRank::Director => 7,
+            EmployeeRank::VP => 8,
+            EmployeeRank::CEO => 9,
+        }
+    }
+
+    /// Get the display name of each rank
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmployeeRank::Intern => "Intern",
+            EmployeeRank::Junior => "Junior",
+            EmployeeRank::Mid => "Mid",
+            EmployeeRank::Senior => "Senior",
+            EmployeeRank::Lead => "Lead",
+            EmployeeRank::Manager => "Manager",
+            EmployeeRank::Director => "Director",
+            EmployeeRank::VP => "VP",
+            EmployeeRank::CEO => "CEO",
+        }
+    }
+
+    /// Get the rank configuration of each rank in the default ladder
+    pub fn default_config(&self, env: &Env) -> RankConfig {
+        RankConfig {
+            id: self.default_id(),
+            name: String::from_str(env, self.name()),
+            salary_multiplier: self.salary_multiplier(),
+            min_tenure: 0,
+            promotion_score_threshold: self.promotion_score_threshold(),
+            annual_leave_days: self.annual_leave_days(),
+            sick_leave_days: self.sick_leave_days(),
+        }
+    }
+
     /// Get the base salary multiplier for each rank
     pub fn salary_multiplier(&self) -> u32 {
         match self {
@@ -45,6 +133,77 @@ impl EmployeeRank {
             EmployeeRank::CEO => None, // CEO is the highest rank
         }
     }
+
+    /// Get the minimum rolling average performance score required to leave each rank
+    pub fn promotion_score_threshold(&self) -> u32 {
+        match self {
+            EmployeeRank::Intern => 60,
+            EmployeeRank::Junior => 65,
+            EmployeeRank::Mid => 70,
+            EmployeeRank::Senior => 75,
+            EmployeeRank::Lead => 80,
+            EmployeeRank::Manager => 80,
+            EmployeeRank::Director => 85,
+            EmployeeRank::VP => 90,
+            EmployeeRank::CEO => MAX_PERFORMANCE_SCORE, // CEO cannot be promoted
+        }
+    }
+
+    /// Get the yearly annual leave entitlement in days for each rank
+    pub fn annual_leave_days(&self) -> u32 {
+        match self {
+            EmployeeRank::Intern => 10,
+            EmployeeRank::Junior => 15,
+            EmployeeRank::Mid => 18,
+            EmployeeRank::Senior => 20,
+            EmployeeRank::Lead => 22,
+            EmployeeRank::Manager => 25,
+            EmployeeRank::Director => 25,
+            EmployeeRank::VP => 28,
+            EmployeeRank::CEO => 30,
+        }
+    }
+
+    /// Get the yearly sick leave entitlement in days for each rank
+    pub fn sick_leave_days(&self) -> u32 {
+        match self {
+            EmployeeRank::Intern => 5,
+            _ => 10,
+        }
+    }
+}
+
+/// One step of an institution's career ladder
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RankConfig {
+    pub id: u32,
+    pub name: String,
+    pub salary_multiplier: u32,
+    pub min_tenure: u64, // ledger sequences in this rank before promotion
+    pub promotion_score_threshold: u32,
+    pub annual_leave_days: u32,
+    pub sick_leave_days: u32,
+}
+
+/// Build the default ladder from `EmployeeRank`
+pub fn default_rank_ladder(env: &Env) -> Vec<RankConfig> {
+    let mut ladder = Vec::new(env);
+    for rank in EmployeeRank::ALL.iter() {
+        ladder.push_back(rank.default_config(env));
+    }
+    ladder
+}
+
+/// Find a rank in a ladder by ID
+pub fn find_rank(ladder: &Vec<RankConfig>, rank_id: u32) -> Option<RankConfig> {
+    ladder.iter().find(|rank| rank.id == rank_id)
+}
+
+/// Get the rank above `rank_id` in a ladder, `None` at the top of the ladder
+pub fn next_rank(ladder: &Vec<RankConfig>, rank_id: u32) -> Option<RankConfig> {
+    let index = ladder.iter().position(|rank| rank.id == rank_id)?;
+    ladder.get(index as u32 + 1)
 }
 
 /// Employee status
@@ -56,14 +215,122 @@ pub enum EmployeeStatus {
     Terminated,
 }
 
+/// Leave type
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum LeaveType {
+    Annual,
+    Sick,
+    Unpaid,
+}
+
+/// Leave request status
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum LeaveStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Resignation submitted by an employee, effective once the notice period ends
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Resignation {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub submitted_at: u64,
+    pub effective_at: u64, // ledger sequence the notice period ends at
+}
+
+/// Final salary owed to an employee whose resignation was finalized
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FinalPay {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub amount: i128,
+    pub unpaid_leave_days: u32,
+}
+
+/// Leave request submitted by an employee
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LeaveRequest {
+    pub id: u64,
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub leave_type: LeaveType,
+    pub start: u64, // ledger sequence the leave starts at
+    pub days: u32,
+    pub year: u32, // leave year the days are charged to
+    pub status: LeaveStatus,
+    pub requested_at: u64,
+}
+
+impl LeaveRequest {
+    /// Ledger sequence the leave ends at (exclusive)
+    pub fn end(&self) -> u64 {
+        self.start + self.days as u64 * LEDGERS_PER_DAY
+    }
+
+    /// Check if the leave shares a day with `[start, end)`
+    pub fn overlaps(&self, start: u64, end: u64) -> bool {
+        self.start < end && start < self.end()
+    }
+}
+
+/// Approved leave days taken by an employee in a leave year
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct LeaveUsage {
+    pub annual: u32,
+    pub sick: u32,
+    pub unpaid: u32,
+}
+
+/// Leave entitlement and usage of an employee for a leave year
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LeaveBalance {
+    pub year: u32,
+    pub annual_entitlement: u32,
+    pub annual_used: u32,
+    pub sick_entitlement: u32,
+    pub sick_used: u32,
+    pub unpaid_used: u32,
+}
+
+impl LeaveBalance {
+    /// Remaining days for a leave type, `None` for unpaid leave which has no entitlement
+    pub fn remaining(&self, leave_type: &LeaveType) -> Option<u32> {
+        match leave_type {
+            LeaveType::Annual => Some(self.annual_entitlement.saturating_sub(self.annual_used)),
+            LeaveType::Sick => Some(self.sick_entitlement.saturating_sub(self.sick_used)),
+            LeaveType::Unpaid => None,
+        }
+    }
+}
+
+/// Get the leave year a ledger sequence falls in
+pub fn leave_year(ledger: u64) -> u32 {
+    (ledger / LEDGERS_PER_YEAR) as u32
+}
+
+/// Get the pay period a ledger sequence falls in
+pub fn pay_period(ledger: u64) -> u64 {
+    ledger / LEDGERS_PER_SALARY_PERIOD
+}
+
 /// Employee information
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct Employee {
     pub id: u64,
+    pub institution_id: u64,
     pub address: Address,
     pub name: String,
-    pub rank: EmployeeRank,
+    pub rank_id: u32,
     pub status: EmployeeStatus,
     pub base_salary: i128,
     pub hire_date: u64,
@@ -73,60 +340,202 @@ pub struct Employee {
 
 impl Employee {
     /// Calculate the current salary based on rank and base salary
-    pub fn current_salary(&self) -> i128 {
-        self.base_salary * (self.rank.salary_multiplier() as i128)
+    pub fn current_salary(&self, rank: &RankConfig) -> i128 {
+        self.base_salary * (rank.salary_multiplier as i128)
     }
 
-    /// Check if employee is eligible for promotion (active and not recently promoted)
-    pub fn is_promotion_eligible(&self, current_time: u64, min_promotion_interval: u64) -> bool {
+    /// Calculate the salary for a period, deducting unpaid leave days
+    pub fn salary_for_period(&self, rank: &RankConfig, unpaid_leave_days: u32) -> i128 {
+        let salary = self.current_salary(rank);
+        let days = unpaid_leave_days.min(DAYS_PER_SALARY_PERIOD) as i128;
+        salary - salary * days / (DAYS_PER_SALARY_PERIOD as i128)
+    }
+
+    /// Check if employee is eligible for promotion (active, past both the
+    /// institution interval and the rank's minimum tenure, not at the top of the
+    /// ladder, and with a rolling average performance score at or above the
+    /// rank threshold)
+    pub fn is_promotion_eligible(
+        &self,
+        ladder: &Vec<RankConfig>,
+        current_time: u64,
+        min_promotion_interval: u64,
+        average_score: Option<u32>,
+    ) -> bool {
         if self.status != EmployeeStatus::Active {
             return false;
         }
 
-        if self.rank == EmployeeRank::CEO {
-            return false; // CEO is the highest rank
+        let rank = match find_rank(ladder, self.rank_id) {
+            Some(rank) => rank,
+            None => return false,
+        };
+
+        if next_rank(ladder, self.rank_id).is_none() {
+            return false; // Already at the top of the ladder
         }
 
-        match self.last_promotion {
-            Some(last_promo) => current_time >= last_promo + min_promotion_interval,
-            None => current_time >= self.hire_date + min_promotion_interval,
+        let min_interval = min_promotion_interval.max(rank.min_tenure);
+        let interval_elapsed = match self.last_promotion {
+            Some(last_promo) => current_time >= last_promo + min_interval,
+            None => current_time >= self.hire_date + min_interval,
+        };
+
+        match average_score {
+            Some(score) => interval_elapsed && score >= rank.promotion_score_threshold,
+            None => false,
         }
     }
 }
 
+/// Performance score submitted by a reviewer for a review period
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PerformanceReview {
+    pub period: u64,
+    pub score: u32,
+    pub reviewer: Address,
+    pub submitted_at: u64,
+}
+
+/// Get the rolling average of the most recent scores, `None` until there
+/// are at least `MIN_PERFORMANCE_REVIEWS` of them
+pub fn average_score(reviews: &Vec<PerformanceReview>) -> Option<u32> {
+    if reviews.len() < MIN_PERFORMANCE_REVIEWS {
+        return None;
+    }
+
+    let window = reviews.len().min(PERFORMANCE_WINDOW);
+    let mut total = 0u32;
+    for review in reviews.iter().skip((reviews.len() - window) as usize) {
+        total += review.score;
+    }
+    Some(total / window)
+}
+
 /// Institution information
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct Institution {
+    pub id: u64,
     pub name: String,
     pub admin: Address,
     pub token_contract: Address,
     pub base_salary_amount: i128,
     pub min_promotion_interval: u64, // in ledger sequences
+    pub deposit: i128, // registration deposit held by the contract
+}
+
+/// HR action that requires multi-party approval
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalAction {
+    Promote,
+    Suspend,
+    Terminate,
+    Reactivate,
+    /// Replace the approval policy; applies to the whole institution
+    SetPolicy(ApprovalPolicy),
+    /// Remove the approval policy; applies to the whole institution
+    RemovePolicy,
+}
+
+impl ProposalAction {
+    /// Whether the action applies to the proposal's employee
+    pub fn targets_employee(&self) -> bool {
+        !matches!(self, ProposalAction::SetPolicy(_) | ProposalAction::RemovePolicy)
+    }
+}
+
+/// Proposal lifecycle status
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+/// Per-institution approval policy for HR actions
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ApprovalPolicy {
+    pub proposers: Vec<Address>,
+    pub approvers: Vec<Address>,
+    pub quorum: u32,
+    pub approval_window: u64, // in ledger sequences
+}
+
+/// Proposed HR action awaiting approval
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub id: u64,
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub action: ProposalAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub quorum: u32,
+    pub created_at: u64,
+    pub deadline: u64,
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+    /// Check if the proposal can no longer be approved
+    pub fn is_expired(&self, current_time: u64) -> bool {
+        current_time > self.deadline
+    }
 }
 
 /// Events emitted by the contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstitutionRegisteredEvent {
+    pub institution_id: u64,
+    pub admin: Address,
+    pub name: String,
+    pub deposit: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmployeeAddedEvent {
+    pub institution_id: u64,
     pub employee_id: u64,
     pub employee_address: Address,
-    pub rank: EmployeeRank,
+    pub name: String,
+    pub rank_id: u32,
     pub salary: i128,
+    pub department: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmployeeUpdatedEvent {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub name: String,
+    pub department: String,
+    pub base_salary: i128,
+    pub salary: i128, // base salary multiplied by the rank multiplier
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmployeePromotedEvent {
+    pub institution_id: u64,
     pub employee_id: u64,
-    pub old_rank: EmployeeRank,
-    pub new_rank: EmployeeRank,
+    pub old_rank_id: u32,
+    pub new_rank_id: u32,
     pub new_salary: i128,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmployeeStatusChangedEvent {
+    pub institution_id: u64,
     pub employee_id: u64,
     pub old_status: EmployeeStatus,
     pub new_status: EmployeeStatus,
@@ -135,7 +544,87 @@ pub struct EmployeeStatusChangedEvent {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SalaryPaidEvent {
+    pub institution_id: u64,
     pub employee_id: u64,
     pub amount: i128,
     pub period: u64,
+    pub unpaid_leave_days: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    pub institution_id: u64,
+    pub proposal_id: u64,
+    pub employee_id: u64,
+    pub action: ProposalAction,
+    pub proposer: Address,
+    pub deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalApprovedEvent {
+    pub institution_id: u64,
+    pub proposal_id: u64,
+    pub approver: Address,
+    pub approvals: u32,
+    pub quorum: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    pub institution_id: u64,
+    pub proposal_id: u64,
+    pub employee_id: u64,
+    pub action: ProposalAction,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaveRequestedEvent {
+    pub institution_id: u64,
+    pub request_id: u64,
+    pub employee_id: u64,
+    pub leave_type: LeaveType,
+    pub start: u64,
+    pub days: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaveDecidedEvent {
+    pub institution_id: u64,
+    pub request_id: u64,
+    pub employee_id: u64,
+    pub status: LeaveStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerformanceScoredEvent {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub reviewer: Address,
+    pub period: u64,
+    pub score: u32,
+    pub average_score: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmployeeAddressRotatedEvent {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub old_address: Address,
+    pub new_address: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResignationSubmittedEvent {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub effective_at: u64,
 }
@@ -84,6 +84,37 @@ deploy_contract() {
 
 # Function to initialize the contract
 initialize_contract() {
+    local contract_id=$1
+    local admin_address=$2
+    local deposit_token=$3
+    local registration_deposit=$4
+    
+    print_status "Initializing contract..."
+    
+    cd "$CONTRACT_DIR"
+    
+    soroban contract invoke \
+        --id "$contract_id" \
+        --source-account "$SOURCE_ACCOUNT" \
+        --network "$NETWORK" \
+        -- \
+        initialize \
+        --admin "$admin_address" \
+        --deposit_token "$deposit_token" \
+        --registration_deposit "$registration_deposit"
+    
+    if [ $? -eq 0 ]; then
+        print_status "Contract initialized successfully!"
+    else
+        print_error "Failed to initialize contract"
+        exit 1
+    fi
+    
+    cd - > /dev/null
+}
+
+# Function to register an institution
+register_institution() {
     local contract_id=$1
     local admin_address=$2
     local institution_name=$3
@@ -91,26 +122,27 @@ initialize_contract() {
     local base_salary=$5
     local promotion_interval=$6
     
-    print_status "Initializing contract..."
+    print_status "Registering institution..."
     
     cd "$CONTRACT_DIR"
     
-    soroban contract invoke \
+    INSTITUTION_ID=$(soroban contract invoke \
         --id "$contract_id" \
         --source-account "$SOURCE_ACCOUNT" \
         --network "$NETWORK" \
         -- \
-        initialize \
+        register_institution \
         --admin "$admin_address" \
         --institution_name "$institution_name" \
         --token_contract "$token_contract" \
         --base_salary_amount "$base_salary" \
-        --min_promotion_interval "$promotion_interval"
+        --min_promotion_interval "$promotion_interval")
     
     if [ $? -eq 0 ]; then
-        print_status "Contract initialized successfully!"
+        print_status "Institution registered successfully!"
+        echo "Institution ID: $INSTITUTION_ID"
     else
-        print_error "Failed to initialize contract"
+        print_error "Failed to register institution"
         exit 1
     fi
     
@@ -125,21 +157,30 @@ show_usage() {
     echo "  -n, --network NETWORK          Network to deploy to (testnet, futurenet, mainnet)"
     echo "  -s, --source-account ACCOUNT   Source account name"
     echo "  -i, --initialize               Initialize contract after deployment"
-    echo "  --admin ADMIN_ADDRESS          Admin address for initialization"
-    echo "  --institution-name NAME        Institution name for initialization"
-    echo "  --token-contract ADDRESS       Token contract address for initialization"
-    echo "  --base-salary AMOUNT           Base salary amount for initialization"
+    echo "  --admin ADMIN_ADDRESS          Platform admin address for initialization"
+    echo "  --deposit-token ADDRESS        Token contract address for registration deposits"
+    echo "  --registration-deposit AMOUNT  Deposit required to register an institution"
+    echo "  -r, --register                 Register an institution after initialization"
+    echo "  --institution-admin ADDRESS    Admin address for the institution"
+    echo "  --institution-name NAME        Institution name for registration"
+    echo "  --token-contract ADDRESS       Salary token contract address for registration"
+    echo "  --base-salary AMOUNT           Base salary amount for registration"
     echo "  --promotion-interval BLOCKS    Minimum blocks between promotions"
     echo "  -h, --help                     Show this help message"
     echo ""
     echo "Examples:"
     echo "  $0 --network testnet"
-    echo "  $0 --initialize --admin GXXXXXXX --institution-name \"Tech Corp\" --token-contract CXXXXXXX --base-salary 1000000000 --promotion-interval 100"
+    echo "  $0 --initialize --admin GXXXXXXX --deposit-token CXXXXXXX --registration-deposit 500000000"
+    echo "  $0 --initialize --admin GXXXXXXX --deposit-token CXXXXXXX --registration-deposit 0 --register --institution-admin GYYYYYYY --institution-name \"Tech Corp\" --token-contract CXXXXXXX --base-salary 1000000000 --promotion-interval 100"
 }
 
 # Parse command line arguments
 INITIALIZE=false
+REGISTER=false
 ADMIN_ADDRESS=""
+DEPOSIT_TOKEN=""
+REGISTRATION_DEPOSIT=""
+INSTITUTION_ADMIN=""
 INSTITUTION_NAME=""
 TOKEN_CONTRACT=""
 BASE_SALARY=""
@@ -163,6 +204,22 @@ while [[ $# -gt 0 ]]; do
             ADMIN_ADDRESS="$2"
             shift 2
             ;;
+        --deposit-token)
+            DEPOSIT_TOKEN="$2"
+            shift 2
+            ;;
+        --registration-deposit)
+            REGISTRATION_DEPOSIT="$2"
+            shift 2
+            ;;
+        -r|--register)
+            REGISTER=true
+            shift
+            ;;
+        --institution-admin)
+            INSTITUTION_ADMIN="$2"
+            shift 2
+            ;;
         --institution-name)
             INSTITUTION_NAME="$2"
             shift 2
@@ -206,13 +263,24 @@ main() {
     
     # Initialize if requested
     if [ "$INITIALIZE" = true ]; then
-        if [ -z "$ADMIN_ADDRESS" ] || [ -z "$INSTITUTION_NAME" ] || [ -z "$TOKEN_CONTRACT" ] || [ -z "$BASE_SALARY" ] || [ -z "$PROMOTION_INTERVAL" ]; then
+        if [ -z "$ADMIN_ADDRESS" ] || [ -z "$DEPOSIT_TOKEN" ] || [ -z "$REGISTRATION_DEPOSIT" ]; then
             print_error "All initialization parameters are required when using --initialize"
-            echo "Required: --admin, --institution-name, --token-contract, --base-salary, --promotion-interval"
+            echo "Required: --admin, --deposit-token, --registration-deposit"
             exit 1
         fi
         
-        initialize_contract "$CONTRACT_ID" "$ADMIN_ADDRESS" "$INSTITUTION_NAME" "$TOKEN_CONTRACT" "$BASE_SALARY" "$PROMOTION_INTERVAL"
+        initialize_contract "$CONTRACT_ID" "$ADMIN_ADDRESS" "$DEPOSIT_TOKEN" "$REGISTRATION_DEPOSIT"
+    fi
+    
+    # Register an institution if requested
+    if [ "$REGISTER" = true ]; then
+        if [ -z "$INSTITUTION_ADMIN" ] || [ -z "$INSTITUTION_NAME" ] || [ -z "$TOKEN_CONTRACT" ] || [ -z "$BASE_SALARY" ] || [ -z "$PROMOTION_INTERVAL" ]; then
+            print_error "All registration parameters are required when using --register"
+            echo "Required: --institution-admin, --institution-name, --token-contract, --base-salary, --promotion-interval"
+            exit 1
+        fi
+        
+        register_institution "$CONTRACT_ID" "$INSTITUTION_ADMIN" "$INSTITUTION_NAME" "$TOKEN_CONTRACT" "$BASE_SALARY" "$PROMOTION_INTERVAL"
     fi
     
     print_status "Deployment completed successfully!"
@@ -222,8 +290,13 @@ main() {
     echo "Source Account: $SOURCE_ACCOUNT"
     
     if [ "$INITIALIZE" = true ]; then
+        echo "Platform Admin: $ADMIN_ADDRESS"
+        echo "Deposit Token: $DEPOSIT_TOKEN"
+    fi
+    
+    if [ "$REGISTER" = true ]; then
         echo "Institution: $INSTITUTION_NAME"
-        echo "Admin: $ADMIN_ADDRESS"
+        echo "Institution Admin: $INSTITUTION_ADMIN"
         echo "Token Contract: $TOKEN_CONTRACT"
     fi
 }
@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// Errors raised while loading or decoding employee management events
+#[derive(Debug)]
+pub enum IndexerError {
+    /// The event export could not be read
+    Io(std::io::Error),
+    /// The event export is not valid JSON or has an unexpected shape
+    Json(serde_json::Error),
+    /// A topic or value is not valid base64 encoded XDR
+    Xdr(String),
+    /// An event with a known name has a missing or mistyped field
+    MalformedEvent { event: String, reason: String },
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Io(err) => write!(f, "failed to read events: {}", err),
+            IndexerError::Json(err) => write!(f, "invalid event export: {}", err),
+            IndexerError::Xdr(reason) => write!(f, "invalid XDR: {}", reason),
+            IndexerError::MalformedEvent { event, reason } => {
+                write!(f, "malformed {} event: {}", event, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+impl From<std::io::Error> for IndexerError {
+    fn from(err: std::io::Error) -> Self {
+        IndexerError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for IndexerError {
+    fn from(err: serde_json::Error) -> Self {
+        IndexerError::Json(err)
+    }
+}
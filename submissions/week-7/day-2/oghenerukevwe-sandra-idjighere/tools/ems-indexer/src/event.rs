@@ -0,0 +1,414 @@
+use serde::{Deserialize, Serialize};
+use soroban_sdk::xdr::{Limits, ReadXdr, ScMap, ScVal, WriteXdr};
+use soroban_sdk::{Address, Env, TryFromVal, Val, Vec as SorobanVec};
+
+use crate::error::IndexerError;
+
+/// A contract event as returned by Soroban RPC `getEvents`
+///
+/// Topics and value are base64 encoded `ScVal` XDR. Fields the indexer does
+/// not need (`id`, `txHash`, `ledgerClosedAt`, ...) are ignored.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEvent {
+    #[serde(default)]
+    pub ledger: Option<u32>,
+    #[serde(default)]
+    pub contract_id: Option<String>,
+    pub topic: Vec<XdrValue>,
+    pub value: XdrValue,
+}
+
+/// Base64 XDR, either inline or wrapped as `{ "xdr": "..." }` by older RPC versions
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum XdrValue {
+    Inline(String),
+    Wrapped { xdr: String },
+}
+
+impl XdrValue {
+    fn decode(&self) -> Result<ScVal, IndexerError> {
+        let b64 = match self {
+            XdrValue::Inline(b64) => b64,
+            XdrValue::Wrapped { xdr } => xdr,
+        };
+        ScVal::from_xdr_base64(b64, Limits::none()).map_err(|err| IndexerError::Xdr(err.to_string()))
+    }
+
+    fn encode(value: &ScVal) -> Result<Self, IndexerError> {
+        value
+            .to_xdr_base64(Limits::none())
+            .map(XdrValue::Inline)
+            .map_err(|err| IndexerError::Xdr(err.to_string()))
+    }
+}
+
+/// An employee management event the indexer understands
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct IndexedEvent {
+    /// Ledger the event was emitted in, when the source records it
+    pub ledger: Option<u32>,
+    pub kind: EventKind,
+}
+
+/// Decoded event data, with addresses as strkeys and strings as UTF-8
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    InstitutionRegistered {
+        institution_id: u64,
+        admin: String,
+        name: String,
+    },
+    EmployeeAdded {
+        institution_id: u64,
+        employee_id: u64,
+        employee_address: String,
+        name: String,
+        rank_id: u32,
+        salary: i128,
+        department: String,
+    },
+    EmployeeUpdated {
+        institution_id: u64,
+        employee_id: u64,
+        name: String,
+        department: String,
+        base_salary: i128,
+        salary: i128,
+    },
+    EmployeePromoted {
+        institution_id: u64,
+        employee_id: u64,
+        old_rank_id: u32,
+        new_rank_id: u32,
+        new_salary: i128,
+    },
+    EmployeeStatusChanged {
+        institution_id: u64,
+        employee_id: u64,
+        old_status: String,
+        new_status: String,
+    },
+    EmployeeRemoved {
+        institution_id: u64,
+        employee_id: u64,
+    },
+    EmployeeAddressRotated {
+        institution_id: u64,
+        employee_id: u64,
+        old_address: String,
+        new_address: String,
+    },
+    ResignationSubmitted {
+        institution_id: u64,
+        employee_id: u64,
+        effective_at: u64,
+    },
+    SalaryPaid {
+        institution_id: u64,
+        employee_id: u64,
+        amount: i128,
+        period: u64,
+        unpaid_leave_days: u32,
+    },
+}
+
+/// Parse a JSON event export
+///
+/// Accepts a bare array of events, a `getEvents` result object with an
+/// `events` array, or the full JSON-RPC response wrapping that result.
+pub fn parse_json_export(json: &str) -> Result<Vec<RawEvent>, IndexerError> {
+    let mut document: serde_json::Value = serde_json::from_str(json)?;
+    if let Some(result) = document.get_mut("result") {
+        document = result.take();
+    }
+    if let Some(events) = document.get_mut("events") {
+        document = events.take();
+    }
+    Ok(serde_json::from_value(document)?)
+}
+
+/// Events decoded from an export, and the ones that failed to decode
+#[derive(Debug, Default)]
+pub struct DecodedEvents {
+    pub events: Vec<IndexedEvent>,
+    pub errors: Vec<SkippedEvent>,
+}
+
+/// An exported event that could not be decoded
+#[derive(Debug)]
+pub struct SkippedEvent {
+    /// Position of the event in the export
+    pub index: usize,
+    pub ledger: Option<u32>,
+    pub error: IndexerError,
+}
+
+/// Decode exported events, keeping only employee management events
+///
+/// When `contract_id` is set, events from other contracts are skipped.
+/// Events that fail to decode are recorded in `errors` and the rest are
+/// still decoded.
+pub fn decode_raw_events(events: &[RawEvent], contract_id: Option<&str>) -> DecodedEvents {
+    let mut decoded = DecodedEvents::default();
+    for (index, event) in events.iter().enumerate() {
+        if let (Some(filter), Some(source)) = (contract_id, event.contract_id.as_deref()) {
+            if filter != source {
+                continue;
+            }
+        }
+
+        match decode_raw_event(event) {
+            Ok(Some(kind)) => decoded.events.push(IndexedEvent { ledger: event.ledger, kind }),
+            Ok(None) => {}
+            Err(error) => decoded.errors.push(SkippedEvent { index, ledger: event.ledger, error }),
+        }
+    }
+    decoded
+}
+
+fn decode_raw_event(event: &RawEvent) -> Result<Option<EventKind>, IndexerError> {
+    let topics = event
+        .topic
+        .iter()
+        .map(XdrValue::decode)
+        .collect::<Result<Vec<_>, _>>()?;
+    let value = event.value.decode()?;
+    decode_event(&topics, &value)
+}
+
+/// Export events recorded by an `Env`, e.g. `env.events().all()` in tests,
+/// in the same shape as the RPC export
+pub fn export_env_events(
+    env: &Env,
+    events: &SorobanVec<(Address, SorobanVec<Val>, Val)>,
+) -> Result<Vec<RawEvent>, IndexerError> {
+    let mut exported = Vec::new();
+    for (contract, topics, data) in events.iter() {
+        let topic = topics
+            .iter()
+            .map(|topic| XdrValue::encode(&to_sc_val(env, &topic)?))
+            .collect::<Result<Vec<_>, _>>()?;
+        exported.push(RawEvent {
+            ledger: None,
+            contract_id: Some(strkey(&contract)),
+            topic,
+            value: XdrValue::encode(&to_sc_val(env, &data)?)?,
+        });
+    }
+    Ok(exported)
+}
+
+/// Decode events recorded by an `Env` emitted by `contract`
+pub fn decode_env_events(
+    env: &Env,
+    contract: &Address,
+    events: &SorobanVec<(Address, SorobanVec<Val>, Val)>,
+) -> Result<DecodedEvents, IndexerError> {
+    let contract_id = strkey(contract);
+    Ok(decode_raw_events(&export_env_events(env, events)?, Some(&contract_id)))
+}
+
+fn strkey(address: &Address) -> String {
+    match ScVal::from(address) {
+        ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+fn to_sc_val(env: &Env, val: &Val) -> Result<ScVal, IndexerError> {
+    ScVal::try_from_val(env, val).map_err(|_| IndexerError::Xdr("value cannot be converted to XDR".into()))
+}
+
+/// Decode one event from its topics and data
+///
+/// Returns `None` for events the indexer does not track.
+pub fn decode_event(topics: &[ScVal], data: &ScVal) -> Result<Option<EventKind>, IndexerError> {
+    let name = match topics.first() {
+        Some(ScVal::String(name)) => name.0.to_utf8_string_lossy(),
+        Some(ScVal::Symbol(name)) => name.0.to_utf8_string_lossy(),
+        _ => return Ok(None),
+    };
+
+    let kind = match name.as_str() {
+        "institution_registered" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::InstitutionRegistered {
+                institution_id: fields.u64("institution_id")?,
+                admin: fields.address("admin")?,
+                name: fields.string("name")?,
+            }
+        }
+        "employee_added" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::EmployeeAdded {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                employee_address: fields.address("employee_address")?,
+                name: fields.string("name")?,
+                rank_id: fields.u32("rank_id")?,
+                salary: fields.i128("salary")?,
+                department: fields.string("department")?,
+            }
+        }
+        // Older deployments emitted updates without data
+        "employee_updated" if *data == ScVal::Void => return Ok(None),
+        "employee_updated" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::EmployeeUpdated {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                name: fields.string("name")?,
+                department: fields.string("department")?,
+                base_salary: fields.i128("base_salary")?,
+                salary: fields.i128("salary")?,
+            }
+        }
+        "employee_promoted" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::EmployeePromoted {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                old_rank_id: fields.u32("old_rank_id")?,
+                new_rank_id: fields.u32("new_rank_id")?,
+                new_salary: fields.i128("new_salary")?,
+            }
+        }
+        "employee_status_changed" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::EmployeeStatusChanged {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                old_status: fields.variant("old_status")?,
+                new_status: fields.variant("new_status")?,
+            }
+        }
+        "employee_removed" => EventKind::EmployeeRemoved {
+            institution_id: topic_u64(&name, topics, 1)?,
+            employee_id: topic_u64(&name, topics, 2)?,
+        },
+        "employee_address_rotated" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::EmployeeAddressRotated {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                old_address: fields.address("old_address")?,
+                new_address: fields.address("new_address")?,
+            }
+        }
+        "resignation_submitted" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::ResignationSubmitted {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                effective_at: fields.u64("effective_at")?,
+            }
+        }
+        "salary_paid" => {
+            let fields = Fields::new(&name, data)?;
+            EventKind::SalaryPaid {
+                institution_id: fields.u64("institution_id")?,
+                employee_id: fields.u64("employee_id")?,
+                amount: fields.i128("amount")?,
+                period: fields.u64("period")?,
+                unpaid_leave_days: fields.u32("unpaid_leave_days")?,
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(kind))
+}
+
+fn malformed(event: &str, reason: impl Into<String>) -> IndexerError {
+    IndexerError::MalformedEvent {
+        event: event.into(),
+        reason: reason.into(),
+    }
+}
+
+fn topic_u64(event: &str, topics: &[ScVal], index: usize) -> Result<u64, IndexerError> {
+    match topics.get(index) {
+        Some(ScVal::U64(value)) => Ok(*value),
+        _ => Err(malformed(event, format!("topic {} is not a u64", index))),
+    }
+}
+
+/// Field access on a `#[contracttype]` struct encoded as an `ScMap`
+struct Fields<'a> {
+    event: &'a str,
+    map: &'a ScMap,
+}
+
+impl<'a> Fields<'a> {
+    fn new(event: &'a str, data: &'a ScVal) -> Result<Self, IndexerError> {
+        match data {
+            ScVal::Map(Some(map)) => Ok(Fields { event, map }),
+            _ => Err(malformed(event, "data is not a struct")),
+        }
+    }
+
+    fn optional(&self, name: &str) -> Option<&'a ScVal> {
+        self.map.0.iter().find_map(|entry| match &entry.key {
+            ScVal::Symbol(key) if key.0.as_slice() == name.as_bytes() => Some(&entry.val),
+            _ => None,
+        })
+    }
+
+    fn get(&self, name: &str) -> Result<&'a ScVal, IndexerError> {
+        self.optional(name)
+            .ok_or_else(|| malformed(self.event, format!("missing field `{}`", name)))
+    }
+
+    fn mistyped(&self, name: &str, expected: &str) -> IndexerError {
+        malformed(self.event, format!("field `{}` is not a {}", name, expected))
+    }
+
+    fn u64(&self, name: &str) -> Result<u64, IndexerError> {
+        match self.get(name)? {
+            ScVal::U64(value) => Ok(*value),
+            _ => Err(self.mistyped(name, "u64")),
+        }
+    }
+
+    fn u32(&self, name: &str) -> Result<u32, IndexerError> {
+        match self.get(name)? {
+            ScVal::U32(value) => Ok(*value),
+            _ => Err(self.mistyped(name, "u32")),
+        }
+    }
+
+    fn i128(&self, name: &str) -> Result<i128, IndexerError> {
+        match self.get(name)? {
+            ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+            _ => Err(self.mistyped(name, "i128")),
+        }
+    }
+
+    fn string(&self, name: &str) -> Result<String, IndexerError> {
+        match self.get(name)? {
+            ScVal::String(value) => Ok(value.0.to_utf8_string_lossy()),
+            _ => Err(self.mistyped(name, "string")),
+        }
+    }
+
+    fn address(&self, name: &str) -> Result<String, IndexerError> {
+        match self.get(name)? {
+            ScVal::Address(address) => Ok(address.to_string()),
+            _ => Err(self.mistyped(name, "address")),
+        }
+    }
+
+    /// Unit enum variants are encoded as a vector holding the variant name
+    fn variant(&self, name: &str) -> Result<String, IndexerError> {
+        match self.get(name)? {
+            ScVal::Vec(Some(vec)) => match vec.0.first() {
+                Some(ScVal::Symbol(variant)) => Ok(variant.0.to_utf8_string_lossy()),
+                _ => Err(self.mistyped(name, "enum")),
+            },
+            _ => Err(self.mistyped(name, "enum")),
+        }
+    }
+}
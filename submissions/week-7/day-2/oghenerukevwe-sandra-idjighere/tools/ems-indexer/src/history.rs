@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::event::{EventKind, IndexedEvent};
+
+/// Change recorded in an employee's history
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum HistoryChange {
+    Added { rank_id: u32, salary: i128 },
+    Updated { name: String, department: String, base_salary: i128, salary: i128 },
+    Promoted { old_rank_id: u32, new_rank_id: u32, new_salary: i128 },
+    StatusChanged { old_status: String, new_status: String },
+    SalaryPaid { amount: i128, period: u64, unpaid_leave_days: u32 },
+    AddressRotated { old_address: String, new_address: String },
+    ResignationSubmitted { effective_at: u64 },
+    Removed,
+}
+
+/// One entry of an employee's history
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub ledger: Option<u32>,
+    #[serde(flatten)]
+    pub change: HistoryChange,
+}
+
+/// Employee state rebuilt from events, with every change in order
+///
+/// Fields are `None` when the events that set them are not part of the
+/// ingested range.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct EmployeeHistory {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub address: Option<String>,
+    pub name: Option<String>,
+    pub department: Option<String>,
+    pub rank_id: Option<u32>,
+    pub salary: Option<i128>,
+    pub status: String,
+    pub removed: bool,
+    pub total_paid: i128,
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl EmployeeHistory {
+    fn new(institution_id: u64, employee_id: u64) -> Self {
+        EmployeeHistory {
+            institution_id,
+            employee_id,
+            address: None,
+            name: None,
+            department: None,
+            rank_id: None,
+            salary: None,
+            status: "Active".into(),
+            removed: false,
+            total_paid: 0,
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// A salary payment with the employee's department at the time it was paid
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct PaymentRecord {
+    pub institution_id: u64,
+    pub employee_id: u64,
+    pub employee_address: Option<String>,
+    pub department: Option<String>,
+    pub amount: i128,
+    pub period: u64, // ledger sequence the salary was paid at
+    pub unpaid_leave_days: u32,
+}
+
+/// Rebuilds institutions, employee histories and payments from events
+///
+/// Events must be ingested in the order they were emitted.
+#[derive(Clone, Debug, Default)]
+pub struct Indexer {
+    institutions: BTreeMap<u64, String>,
+    employees: BTreeMap<(u64, u64), EmployeeHistory>,
+    payments: Vec<PaymentRecord>,
+}
+
+impl Indexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an indexer from a sequence of events
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a IndexedEvent>) -> Self {
+        let mut indexer = Self::new();
+        for event in events {
+            indexer.ingest(event);
+        }
+        indexer
+    }
+
+    /// Apply one event
+    pub fn ingest(&mut self, event: &IndexedEvent) {
+        let (institution_id, employee_id, change) = match &event.kind {
+            EventKind::InstitutionRegistered { institution_id, name, .. } => {
+                self.institutions.insert(*institution_id, name.clone());
+                return;
+            }
+            EventKind::EmployeeAdded {
+                institution_id,
+                employee_id,
+                employee_address,
+                rank_id,
+                salary,
+                name,
+                department,
+            } => {
+                let employee = self.employee_mut(*institution_id, *employee_id);
+                employee.address = Some(employee_address.clone());
+                employee.name = Some(name.clone());
+                employee.department = Some(department.clone());
+                employee.rank_id = Some(*rank_id);
+                employee.salary = Some(*salary);
+                let change = HistoryChange::Added { rank_id: *rank_id, salary: *salary };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::EmployeeUpdated {
+                institution_id,
+                employee_id,
+                name,
+                department,
+                base_salary,
+                salary,
+            } => {
+                let employee = self.employee_mut(*institution_id, *employee_id);
+                employee.name = Some(name.clone());
+                employee.department = Some(department.clone());
+                employee.salary = Some(*salary);
+                let change = HistoryChange::Updated {
+                    name: name.clone(),
+                    department: department.clone(),
+                    base_salary: *base_salary,
+                    salary: *salary,
+                };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::EmployeePromoted {
+                institution_id,
+                employee_id,
+                old_rank_id,
+                new_rank_id,
+                new_salary,
+            } => {
+                let employee = self.employee_mut(*institution_id, *employee_id);
+                employee.rank_id = Some(*new_rank_id);
+                employee.salary = Some(*new_salary);
+                let change = HistoryChange::Promoted {
+                    old_rank_id: *old_rank_id,
+                    new_rank_id: *new_rank_id,
+                    new_salary: *new_salary,
+                };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::EmployeeStatusChanged {
+                institution_id,
+                employee_id,
+                old_status,
+                new_status,
+            } => {
+                let employee = self.employee_mut(*institution_id, *employee_id);
+                employee.status = new_status.clone();
+                let change = HistoryChange::StatusChanged {
+                    old_status: old_status.clone(),
+                    new_status: new_status.clone(),
+                };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::EmployeeRemoved { institution_id, employee_id } => {
+                self.employee_mut(*institution_id, *employee_id).removed = true;
+                (*institution_id, *employee_id, HistoryChange::Removed)
+            }
+            EventKind::EmployeeAddressRotated {
+                institution_id,
+                employee_id,
+                old_address,
+                new_address,
+            } => {
+                self.employee_mut(*institution_id, *employee_id).address = Some(new_address.clone());
+                let change = HistoryChange::AddressRotated {
+                    old_address: old_address.clone(),
+                    new_address: new_address.clone(),
+                };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::ResignationSubmitted {
+                institution_id,
+                employee_id,
+                effective_at,
+            } => {
+                let change = HistoryChange::ResignationSubmitted { effective_at: *effective_at };
+                (*institution_id, *employee_id, change)
+            }
+            EventKind::SalaryPaid {
+                institution_id,
+                employee_id,
+                amount,
+                period,
+                unpaid_leave_days,
+            } => {
+                let employee = self.employee_mut(*institution_id, *employee_id);
+                employee.total_paid += amount;
+                let payment = PaymentRecord {
+                    institution_id: *institution_id,
+                    employee_id: *employee_id,
+                    employee_address: employee.address.clone(),
+                    department: employee.department.clone(),
+                    amount: *amount,
+                    period: *period,
+                    unpaid_leave_days: *unpaid_leave_days,
+                };
+                self.payments.push(payment);
+                let change = HistoryChange::SalaryPaid {
+                    amount: *amount,
+                    period: *period,
+                    unpaid_leave_days: *unpaid_leave_days,
+                };
+                (*institution_id, *employee_id, change)
+            }
+        };
+
+        self.employee_mut(institution_id, employee_id)
+            .entries
+            .push(HistoryEntry { ledger: event.ledger, change });
+    }
+
+    fn employee_mut(&mut self, institution_id: u64, employee_id: u64) -> &mut EmployeeHistory {
+        self.employees
+            .entry((institution_id, employee_id))
+            .or_insert_with(|| EmployeeHistory::new(institution_id, employee_id))
+    }
+
+    /// Get the name an institution registered with
+    pub fn institution_name(&self, institution_id: u64) -> Option<&str> {
+        self.institutions.get(&institution_id).map(String::as_str)
+    }
+
+    /// Get an employee's history
+    pub fn employee(&self, institution_id: u64, employee_id: u64) -> Option<&EmployeeHistory> {
+        self.employees.get(&(institution_id, employee_id))
+    }
+
+    /// All employee histories, ordered by institution and employee ID
+    pub fn employees(&self) -> impl Iterator<Item = &EmployeeHistory> {
+        self.employees.values()
+    }
+
+    /// All salary payments in the order they were made
+    pub fn payments(&self) -> &[PaymentRecord] {
+        &self.payments
+    }
+}
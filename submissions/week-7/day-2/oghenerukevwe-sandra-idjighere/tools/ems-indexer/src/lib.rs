@@ -0,0 +1,41 @@
+//! # Employee Management Event Indexer
+//!
+//! Off-chain consumer of the events emitted by the employee management
+//! contract. It rebuilds per-employee history and payroll reports from events
+//! alone, so finance can reconcile on-chain payouts without querying contract
+//! storage.
+//!
+//! ## Sources
+//!
+//! - JSON exports of Soroban RPC `getEvents` (topics and value as base64 XDR)
+//! - Events recorded by a test `Env`, via `env.events().all()`
+//!
+//! ## Outputs
+//!
+//! - Employee history: additions, updates, promotions, status changes,
+//!   address rotations, resignations, removals and salary payments in
+//!   emission order
+//! - Payroll report per institution, period and department, as CSV or JSON
+//!
+//! ## Usage
+//!
+//! 1. Load events with `parse_json_export` + `decode_raw_events`, or `decode_env_events`;
+//!    events that fail to decode are skipped and listed in `DecodedEvents::errors`
+//! 2. Feed them in emission order to an `Indexer`
+//! 3. Query histories with `Indexer::employee`, or build a `PayrollReport`
+
+mod error;
+mod event;
+mod history;
+mod report;
+
+pub use error::IndexerError;
+pub use event::{
+    decode_env_events, decode_event, decode_raw_events, export_env_events, parse_json_export,
+    DecodedEvents, EventKind, IndexedEvent, RawEvent, SkippedEvent, XdrValue,
+};
+pub use history::{EmployeeHistory, HistoryChange, HistoryEntry, Indexer, PaymentRecord};
+pub use report::{PayrollReport, PayrollRow, DEFAULT_PERIOD_LEDGERS, UNASSIGNED_DEPARTMENT};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,118 @@
+//! Command line payroll reports from an employee management event export
+
+use std::process;
+
+use ems_indexer::{
+    decode_raw_events, parse_json_export, EmployeeHistory, Indexer, IndexerError, PayrollReport,
+    DEFAULT_PERIOD_LEDGERS,
+};
+
+const USAGE: &str = "Usage: ems-indexer <events.json> [options]
+
+Options:
+  --contract <id>         Only index events from this contract
+  --period-ledgers <n>    Ledgers per report period (default 518400, ~30 days)
+  --format <csv|json>     Report format (default csv)
+  --history               Print employee histories as JSON instead of a report
+  -h, --help              Show this help";
+
+enum Format {
+    Csv,
+    Json,
+}
+
+struct Options {
+    path: String,
+    contract: Option<String>,
+    period_ledgers: u64,
+    format: Format,
+    history: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut path = None;
+    let mut contract = None;
+    let mut period_ledgers = DEFAULT_PERIOD_LEDGERS;
+    let mut format = Format::Csv;
+    let mut history = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(String::new()),
+            "--contract" => {
+                contract = Some(args.next().ok_or("--contract needs a contract ID")?.clone());
+            }
+            "--period-ledgers" => {
+                let value = args.next().ok_or("--period-ledgers needs a value")?;
+                period_ledgers = value
+                    .parse()
+                    .ok()
+                    .filter(|ledgers| *ledgers > 0)
+                    .ok_or("--period-ledgers must be a positive integer")?;
+            }
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format must be csv or json".into()),
+                };
+            }
+            "--history" => history = true,
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ if path.is_none() => path = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    Ok(Options {
+        path: path.ok_or("missing event export path")?,
+        contract,
+        period_ledgers,
+        format,
+        history,
+    })
+}
+
+fn run(options: &Options) -> Result<String, IndexerError> {
+    let json = std::fs::read_to_string(&options.path)?;
+    let decoded = decode_raw_events(&parse_json_export(&json)?, options.contract.as_deref());
+    for skipped in &decoded.errors {
+        let ledger = skipped.ledger.map(|ledger| ledger.to_string()).unwrap_or("?".into());
+        eprintln!("warning: skipped event {} (ledger {}): {}", skipped.index, ledger, skipped.error);
+    }
+    let indexer = Indexer::from_events(&decoded.events);
+
+    if options.history {
+        let histories: Vec<&EmployeeHistory> = indexer.employees().collect();
+        return Ok(serde_json::to_string_pretty(&histories)?);
+    }
+
+    let report = PayrollReport::build(&indexer, options.period_ledgers);
+    match options.format {
+        Format::Csv => Ok(report.to_csv()),
+        Format::Json => report.to_json(),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("error: {}\n", message);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(output) => println!("{}", output.trim_end()),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
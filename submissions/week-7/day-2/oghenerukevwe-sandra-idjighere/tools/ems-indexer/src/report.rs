@@ -0,0 +1,123 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::error::IndexerError;
+use crate::history::{Indexer, PaymentRecord};
+
+/// Ledger sequences in one report period (~30 days of 5 second ledgers)
+pub const DEFAULT_PERIOD_LEDGERS: u64 = 518_400;
+
+/// Department used for payments to employees whose department is unknown
+pub const UNASSIGNED_DEPARTMENT: &str = "unassigned";
+
+/// Payroll totals for one department of one institution in one period
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct PayrollRow {
+    pub institution_id: u64,
+    pub institution: Option<String>,
+    pub period: u64,
+    pub period_start_ledger: u64,
+    pub department: String,
+    pub employees: u32,
+    pub payments: u32,
+    pub total_amount: i128,
+    pub unpaid_leave_days: u32,
+}
+
+/// Payroll report grouped by institution, period and department
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct PayrollReport {
+    pub period_ledgers: u64,
+    pub rows: Vec<PayrollRow>,
+    pub payments: Vec<PaymentRecord>,
+}
+
+#[derive(Default)]
+struct Totals {
+    employees: BTreeSet<u64>,
+    payments: u32,
+    total_amount: i128,
+    unpaid_leave_days: u32,
+}
+
+impl PayrollReport {
+    /// Build a report from the payments seen by an indexer
+    ///
+    /// Payments fall into period `ledger / period_ledgers`.
+    pub fn build(indexer: &Indexer, period_ledgers: u64) -> Self {
+        let period_ledgers = period_ledgers.max(1);
+
+        let mut groups: BTreeMap<(u64, u64, String), Totals> = BTreeMap::new();
+        for payment in indexer.payments() {
+            let department = payment
+                .department
+                .clone()
+                .unwrap_or_else(|| UNASSIGNED_DEPARTMENT.into());
+            let key = (payment.institution_id, payment.period / period_ledgers, department);
+            let totals = groups.entry(key).or_default();
+            totals.employees.insert(payment.employee_id);
+            totals.payments += 1;
+            totals.total_amount += payment.amount;
+            totals.unpaid_leave_days += payment.unpaid_leave_days;
+        }
+
+        let rows = groups
+            .into_iter()
+            .map(|((institution_id, period, department), totals)| PayrollRow {
+                institution_id,
+                institution: indexer.institution_name(institution_id).map(String::from),
+                period,
+                period_start_ledger: period * period_ledgers,
+                department,
+                employees: totals.employees.len() as u32,
+                payments: totals.payments,
+                total_amount: totals.total_amount,
+                unpaid_leave_days: totals.unpaid_leave_days,
+            })
+            .collect();
+
+        PayrollReport {
+            period_ledgers,
+            rows,
+            payments: indexer.payments().to_vec(),
+        }
+    }
+
+    /// Render the summary rows as CSV with a header line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "institution_id,institution,period,period_start_ledger,department,employees,payments,total_amount,unpaid_leave_days\n",
+        );
+        for row in &self.rows {
+            let fields = [
+                row.institution_id.to_string(),
+                csv_field(row.institution.as_deref().unwrap_or("")),
+                row.period.to_string(),
+                row.period_start_ledger.to_string(),
+                csv_field(&row.department),
+                row.employees.to_string(),
+                row.payments.to_string(),
+                row.total_amount.to_string(),
+                row.unpaid_leave_days.to_string(),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Render the summary rows and individual payments as JSON
+    pub fn to_json(&self) -> Result<String, IndexerError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
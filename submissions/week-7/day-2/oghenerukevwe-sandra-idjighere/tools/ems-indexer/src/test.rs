@@ -0,0 +1,351 @@
+#![cfg(test)]
+
+use crate::{
+    decode_env_events, decode_event, decode_raw_events, export_env_events, parse_json_export,
+    EventKind, HistoryChange, Indexer, IndexerError, PayrollReport, RawEvent, XdrValue,
+    UNASSIGNED_DEPARTMENT,
+};
+use employee_management::{EmployeeManagement, EmployeeManagementClient, EmployeeRank};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    vec,
+    xdr::{Limits, ScVal, WriteXdr},
+    Address, Env, String,
+};
+
+struct Payroll<'a> {
+    env: Env,
+    contract: EmployeeManagementClient<'a>,
+    institution_id: u64,
+    alice: u64,
+    bob: u64,
+    // Test environments only keep the events of the last invocation
+    log: Vec<RawEvent>,
+}
+
+impl Payroll<'_> {
+    fn record(&mut self) {
+        let events = export_env_events(&self.env, &self.env.events().all()).unwrap();
+        self.log.extend(events);
+    }
+
+    fn set_ledger(&self, sequence: u32) {
+        self.env.ledger().with_mut(|li| {
+            li.sequence_number = sequence;
+        });
+    }
+
+    fn contract_id(&self) -> std::string::String {
+        match ScVal::from(&self.contract.address) {
+            ScVal::Address(address) => address.to_string(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Two employees paid over two periods, with a promotion, a department
+/// change, a suspension and a removal in between
+fn run_payroll<'a>() -> Payroll<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    StellarAssetClient::new(&env, &token).mint(&admin, &100000_0000000);
+
+    let contract_address = env.register(EmployeeManagement {}, ());
+    let contract = EmployeeManagementClient::new(&env, &contract_address);
+    contract.initialize(&Address::generate(&env), &Address::generate(&env), &0);
+
+    let mut payroll = Payroll {
+        env: env.clone(),
+        contract,
+        institution_id: 0,
+        alice: 0,
+        bob: 0,
+        log: Vec::new(),
+    };
+
+    payroll.institution_id = payroll.contract.register_institution(
+        &admin,
+        &String::from_str(&env, "Tech Corp"),
+        &token,
+        &1000_0000000,
+        &100,
+    );
+    payroll.record();
+    let institution_id = payroll.institution_id;
+
+    payroll.alice = payroll.contract.add_employee(
+        &institution_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "Alice"),
+        &EmployeeRank::Junior.default_id(),
+        &String::from_str(&env, "Engineering"),
+    );
+    payroll.record();
+    payroll.bob = payroll.contract.add_employee(
+        &institution_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "Bob"),
+        &EmployeeRank::Mid.default_id(),
+        &String::from_str(&env, "Finance"),
+    );
+    payroll.record();
+
+    payroll.set_ledger(10);
+    payroll.contract.pay_all_salaries(&institution_id);
+    payroll.record();
+
+    payroll.contract.update_employee(
+        &institution_id,
+        &payroll.bob,
+        &None,
+        &Some(String::from_str(&env, "Engineering")),
+        &None,
+    );
+    payroll.record();
+
+    let reviewer = Address::generate(&env);
+    payroll.contract.set_reviewers(&institution_id, &vec![&env, reviewer.clone()]);
+    payroll.contract.submit_performance_score(&institution_id, &reviewer, &payroll.alice, &1, &80);
+    payroll.contract.submit_performance_score(&institution_id, &reviewer, &payroll.alice, &2, &80);
+
+    payroll.set_ledger(1010);
+    payroll.contract.promote_employee(&institution_id, &payroll.alice);
+    payroll.record();
+    payroll.contract.pay_all_salaries(&institution_id);
+    payroll.record();
+
+    payroll.contract.suspend_employee(&institution_id, &payroll.bob);
+    payroll.record();
+    payroll.contract.remove_employee(&institution_id, &payroll.bob);
+    payroll.record();
+
+    payroll
+}
+
+fn index(payroll: &Payroll) -> Indexer {
+    let decoded = decode_raw_events(&payroll.log, Some(&payroll.contract_id()));
+    assert!(decoded.errors.is_empty());
+    Indexer::from_events(&decoded.events)
+}
+
+#[test]
+fn test_employee_history() {
+    let payroll = run_payroll();
+    let indexer = index(&payroll);
+
+    assert_eq!(indexer.institution_name(payroll.institution_id), Some("Tech Corp"));
+
+    let alice = indexer.employee(payroll.institution_id, payroll.alice).unwrap();
+    let changes: Vec<&HistoryChange> = alice.entries.iter().map(|entry| &entry.change).collect();
+    assert_eq!(
+        changes,
+        [
+            &HistoryChange::Added { rank_id: 2, salary: 2000_0000000 },
+            &HistoryChange::SalaryPaid { amount: 2000_0000000, period: 10, unpaid_leave_days: 0 },
+            &HistoryChange::Promoted { old_rank_id: 2, new_rank_id: 3, new_salary: 3000_0000000 },
+            &HistoryChange::SalaryPaid { amount: 3000_0000000, period: 1010, unpaid_leave_days: 0 },
+        ]
+    );
+    assert_eq!(alice.rank_id, Some(EmployeeRank::Mid.default_id()));
+    assert_eq!(alice.salary, Some(3000_0000000));
+    assert_eq!(alice.total_paid, 5000_0000000);
+    assert_eq!(alice.name.as_deref(), Some("Alice"));
+    assert_eq!(alice.department.as_deref(), Some("Engineering"));
+
+    let bob = indexer.employee(payroll.institution_id, payroll.bob).unwrap();
+    assert_eq!(bob.entries.len(), 6);
+    assert_eq!(
+        bob.entries[2].change,
+        HistoryChange::Updated {
+            name: "Bob".into(),
+            department: "Engineering".into(),
+            base_salary: 1000_0000000,
+            salary: 3000_0000000,
+        }
+    );
+    assert_eq!(bob.name.as_deref(), Some("Bob"));
+    assert_eq!(bob.department.as_deref(), Some("Engineering"));
+    assert_eq!(bob.status, "Suspended");
+    assert!(bob.removed);
+    assert_eq!(bob.entries.last().unwrap().change, HistoryChange::Removed);
+}
+
+#[test]
+fn test_payroll_report() {
+    let payroll = run_payroll();
+    let report = PayrollReport::build(&index(&payroll), 1000);
+
+    // Bob is paid under Finance before his transfer and Engineering after
+    let rows: Vec<(u64, &str, u32, i128)> = report
+        .rows
+        .iter()
+        .map(|row| (row.period, row.department.as_str(), row.employees, row.total_amount))
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            (0, "Engineering", 1, 2000_0000000),
+            (0, "Finance", 1, 3000_0000000),
+            (1, "Engineering", 2, 6000_0000000),
+        ]
+    );
+    assert_eq!(report.payments.len(), 4);
+
+    let csv = report.to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("institution_id,institution,period,period_start_ledger,department,employees,payments,total_amount,unpaid_leave_days")
+    );
+    assert_eq!(lines.next(), Some("1,Tech Corp,0,0,Engineering,1,1,20000000000,0"));
+    assert_eq!(lines.last(), Some("1,Tech Corp,1,1000,Engineering,2,2,60000000000,0"));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["period_ledgers"], 1000);
+    assert_eq!(json["rows"].as_array().unwrap().len(), 3);
+    assert_eq!(json["payments"][3]["department"], "Engineering");
+}
+
+#[test]
+fn test_rpc_json_export() {
+    let payroll = run_payroll();
+
+    // Wrap the events the way `getEvents` returns them over JSON-RPC
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {
+            "events": payroll.log,
+            "latestLedger": 1010,
+        },
+    });
+    let parsed = parse_json_export(&response.to_string()).unwrap();
+    assert_eq!(parsed, payroll.log);
+
+    // Salary transfers also emit token events, which the contract filter drops
+    let all = decode_raw_events(&parsed, None).events;
+    let filtered = decode_raw_events(&parsed, Some(&payroll.contract_id())).events;
+    assert_eq!(all, filtered);
+    assert!(parsed.len() > filtered.len());
+
+    // A bare array of events is accepted too
+    let bare = parse_json_export(&serde_json::to_string(&payroll.log).unwrap()).unwrap();
+    assert_eq!(bare, payroll.log);
+}
+
+#[test]
+fn test_decode_env_events() {
+    let payroll = run_payroll();
+    let env = &payroll.env;
+
+    // Events the indexer does not track are skipped
+    payroll.contract.set_reviewers(&payroll.institution_id, &vec![env]);
+    let events = decode_env_events(env, &payroll.contract.address, &env.events().all());
+    assert!(events.unwrap().events.is_empty());
+
+    payroll.contract.suspend_employee(&payroll.institution_id, &payroll.alice);
+    let events = decode_env_events(env, &payroll.contract.address, &env.events().all()).unwrap().events;
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].kind,
+        EventKind::EmployeeStatusChanged {
+            institution_id: payroll.institution_id,
+            employee_id: payroll.alice,
+            old_status: "Active".into(),
+            new_status: "Suspended".into(),
+        }
+    );
+
+    let new_address = Address::generate(env);
+    let old_address = payroll.contract.get_employee(&payroll.institution_id, &payroll.alice).address;
+    payroll.contract.rotate_address(&payroll.institution_id, &old_address, &new_address);
+    let events = decode_env_events(env, &payroll.contract.address, &env.events().all()).unwrap().events;
+
+    let mut indexer = index(&payroll);
+    indexer.ingest(&events[0]);
+    let alice = indexer.employee(payroll.institution_id, payroll.alice).unwrap();
+    assert_eq!(alice.address, Some(new_address.to_string().to_string()));
+    assert!(matches!(alice.entries.last().unwrap().change, HistoryChange::AddressRotated { .. }));
+}
+
+#[test]
+fn test_unassigned_department_and_malformed_events() {
+    let indexer = Indexer::from_events(&[crate::IndexedEvent {
+        ledger: Some(42),
+        kind: EventKind::SalaryPaid {
+            institution_id: 1,
+            employee_id: 7,
+            amount: 500,
+            period: 42,
+            unpaid_leave_days: 3,
+        },
+    }]);
+    let report = PayrollReport::build(&indexer, 1000);
+    assert_eq!(report.rows[0].department, UNASSIGNED_DEPARTMENT);
+    assert_eq!(report.rows[0].unpaid_leave_days, 3);
+    assert!(report.to_csv().ends_with("1,,0,0,unassigned,1,1,500,3\n"));
+
+    let topics = [ScVal::Symbol("salary_paid".try_into().unwrap())];
+    let error = decode_event(&topics, &ScVal::Void).unwrap_err();
+    assert!(matches!(error, IndexerError::MalformedEvent { .. }));
+
+    let topics = [ScVal::Symbol("transfer".try_into().unwrap())];
+    assert_eq!(decode_event(&topics, &ScVal::Void).unwrap(), None);
+}
+
+#[test]
+fn test_salary_update() {
+    let mut payroll = run_payroll();
+    payroll.contract.update_employee(
+        &payroll.institution_id,
+        &payroll.alice,
+        &None,
+        &None,
+        &Some(1500_0000000),
+    );
+    payroll.record();
+
+    // Alice is a Mid now, at 3x the new base salary
+    let indexer = index(&payroll);
+    let alice = indexer.employee(payroll.institution_id, payroll.alice).unwrap();
+    assert_eq!(alice.salary, Some(4500_0000000));
+    assert!(matches!(
+        alice.entries.last().unwrap().change,
+        HistoryChange::Updated { base_salary: 1500_0000000, salary: 4500_0000000, .. }
+    ));
+}
+
+#[test]
+fn test_bad_events_are_skipped() {
+    let payroll = run_payroll();
+    let mut log = payroll.log.clone();
+
+    // An event that is not valid XDR, and a salary payment missing its fields
+    log[1].value = XdrValue::Inline("not xdr".into());
+    let salary_paid = log
+        .iter()
+        .position(|event| {
+            decode_raw_events(std::slice::from_ref(event), None)
+                .events
+                .first()
+                .is_some_and(|event| matches!(event.kind, EventKind::SalaryPaid { .. }))
+        })
+        .unwrap();
+    log[salary_paid].value = XdrValue::Inline(ScVal::Void.to_xdr_base64(Limits::none()).unwrap());
+
+    let decoded = decode_raw_events(&log, Some(&payroll.contract_id()));
+    let skipped: Vec<usize> = decoded.errors.iter().map(|skipped| skipped.index).collect();
+    assert_eq!(skipped, [1, salary_paid]);
+    assert!(matches!(decoded.errors[0].error, IndexerError::Xdr(_)));
+    assert!(matches!(decoded.errors[1].error, IndexerError::MalformedEvent { .. }));
+
+    // Everything else is still indexed
+    let all = decode_raw_events(&payroll.log, Some(&payroll.contract_id())).events;
+    assert_eq!(decoded.events.len(), all.len() - 2);
+    let indexer = Indexer::from_events(&decoded.events);
+    assert!(indexer.employee(payroll.institution_id, payroll.bob).unwrap().removed);
+}